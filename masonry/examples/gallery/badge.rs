@@ -93,7 +93,7 @@ impl DemoPage for BadgeDemo {
         let outline_badge = NewWidget::new(Badge::with_text("99+")).with_props(
             PropertySet::new()
                 .with(Background::Color(Color::TRANSPARENT))
-                .with(BorderWidth { width: 1.px() })
+                .with(BorderWidth::all(1.px()))
                 .with(BorderColor {
                     color: Color::from_rgb8(0x71, 0x71, 0x7a),
                 }),
@@ -168,7 +168,7 @@ impl DemoPage for BadgeDemo {
             PropertySet::new()
                 .with(Padding::ZERO)
                 .with(CornerRadius { radius: 999.px() })
-                .with(BorderWidth { width: 0.px() })
+                .with(BorderWidth::all(0.px()))
                 .with(Background::Color(Color::from_rgb8(0x22, 0xc5, 0x5e))),
         );
 
@@ -0,0 +1,44 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::core::{ArcStr, Property};
+use crate::peniko::ImageBrush;
+
+/// A hook producing alt text for an [`Image`] which has none set explicitly.
+///
+/// This is opt-in: by default, no hook is set and images without alt text are hidden from screen readers.
+/// Apps will usually set it for all images through [`DefaultProperties`], e.g. to derive alt text
+/// from metadata they associate with the image data.
+///
+/// The hook isn't called for images with explicit alt text, including empty alt text,
+/// nor for [decorative](crate::widgets::Image::decorative) images.
+///
+/// [`Image`]: crate::widgets::Image
+/// [`DefaultProperties`]: crate::core::DefaultProperties
+#[derive(Clone, Copy, Debug)]
+pub struct AltTextFallback {
+    /// Returns the alt text for the given image data, if any.
+    pub hook: Option<fn(&ImageBrush) -> Option<ArcStr>>,
+}
+
+impl Property for AltTextFallback {
+    fn static_default() -> &'static Self {
+        static DEFAULT: AltTextFallback = AltTextFallback { hook: None };
+        &DEFAULT
+    }
+}
+
+impl AltTextFallback {
+    /// Creates a new `AltTextFallback` with the given hook.
+    pub const fn new(hook: fn(&ImageBrush) -> Option<ArcStr>) -> Self {
+        Self { hook: Some(hook) }
+    }
+}
+
+// ---
+
+impl Default for AltTextFallback {
+    fn default() -> Self {
+        *Self::static_default()
+    }
+}
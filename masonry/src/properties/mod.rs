@@ -5,6 +5,7 @@
 //!
 //! See [properties documentation](crate::doc::widget_properties) for details.
 
+mod alt_text_fallback;
 mod checkmark;
 mod content_color;
 mod gap;
@@ -20,6 +21,7 @@ mod step_input;
 
 pub mod types;
 
+pub use alt_text_fallback::*;
 pub use checkmark::*;
 pub use content_color::*;
 pub use gap::*;
@@ -3,7 +3,7 @@
 
 use crate::core::Property;
 use crate::kurbo::{Affine, Axis, Rect, Size};
-use crate::layout::{LenReq, Length};
+use crate::layout::{LenReq, Length, UnitPoint};
 use crate::util::Sanitize;
 
 // These are based on https://developer.mozilla.org/en-US/docs/Web/CSS/object-fit
@@ -80,6 +80,50 @@ impl ObjectFit {
     /// Panics if either `content` or `container` has non-finite or negative size
     /// and debug assertions are enabled.
     pub fn affine(self, container: Rect, content: Rect) -> Affine {
+        self.affine_aligned(container, content, UnitPoint::CENTER)
+    }
+
+    /// Calculates the [`Affine`] transform which draws content of size `source`
+    /// into a box of size `destination`, both with their origin at `(0, 0)`.
+    ///
+    /// The returned transform maps the content's coordinate space to the box's.
+    /// The content is centered, like [`affine`](Self::affine) does.
+    ///
+    /// This is what the [`Image`] widget uses by default, with the size of the image data
+    /// as `source` and its content-box size as `destination`.
+    /// Custom widgets which draw images directly can use it to match `Image`.
+    ///
+    /// ```
+    /// use masonry::kurbo::{Affine, Size};
+    /// use masonry::properties::ObjectFit;
+    ///
+    /// // A 200x200 image contained in a 100x50 box is scaled down to 50x50 and centered.
+    /// let transform = ObjectFit::Contain.affine_to_fill(Size::new(100., 50.), Size::new(200., 200.));
+    /// assert_eq!(transform, Affine::new([0.25, 0., 0., 0.25, 25., 0.]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if either size is non-finite or negative and debug assertions are enabled.
+    ///
+    /// [`Image`]: crate::widgets::Image
+    pub fn affine_to_fill(self, destination: Size, source: Size) -> Affine {
+        self.affine(destination.to_rect(), source.to_rect())
+    }
+
+    /// Calculates an [`Affine`] transform to fit `content` inside `container`,
+    /// placing it according to `alignment`.
+    ///
+    /// This is like [`affine`](Self::affine), except that the leftover space (or the overflow)
+    /// is distributed according to `alignment` instead of being split evenly.
+    /// For instance, with [`UnitPoint::TOP_LEFT`] a contained image sticks to the
+    /// top-left corner of the container.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `content` or `container` has non-finite or negative size
+    /// and debug assertions are enabled.
+    pub fn affine_aligned(self, container: Rect, content: Rect, alignment: UnitPoint) -> Affine {
         // Guard against invalid input
         let container_width = container.width().sanitize("container width");
         let container_height = container.height().sanitize("container height");
@@ -113,8 +157,15 @@ impl ObjectFit {
             Self::Stretch => (raw_scalex, raw_scaley),
         };
 
-        let origin_x = container.x0 + (container_width - (content_width * scalex)) * 0.5;
-        let origin_y = container.y0 + (container_height - (content_height * scaley)) * 0.5;
+        let leftover = Rect::new(
+            0.,
+            0.,
+            container_width - (content_width * scalex),
+            container_height - (content_height * scaley),
+        );
+        let offset = alignment.resolve(leftover);
+        let origin_x = container.x0 + offset.x;
+        let origin_y = container.y0 + offset.y;
 
         Affine::new([
             scalex,
@@ -23,6 +23,8 @@ pub enum MainAxisAlignment {
     ///
     /// The space between each pair of widgets, the start edge and the first widget,
     /// and the end edge and the last widget, are all exactly the same.
+    ///
+    /// Whether space is placed at the edges can be changed with [`EdgeSpacing`].
     SpaceEvenly,
     /// Widgets are evenly distributed on the container's main axis.
     ///
@@ -30,9 +32,57 @@ pub enum MainAxisAlignment {
     /// The space before the first and after the last widget
     /// equals half of the space between each pair of widgets.
     /// If there is only one widget, it will be centered.
+    ///
+    /// Whether space is placed at the edges can be changed with [`EdgeSpacing`].
     SpaceAround,
 }
 
+/// Placement of the space at the edges of a container's main axis,
+/// for [`MainAxisAlignment::SpaceEvenly`] and [`MainAxisAlignment::SpaceAround`].
+///
+/// Design systems disagree about whether evenly distributed widgets should also
+/// be spaced from the container's edges, so this makes the choice explicit.
+/// It has no effect on the other alignments.
+///
+/// The diagrams below show three widgets, with `|` as the container's edges,
+/// and each space between them sized in units of the same amount.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeSpacing {
+    /// Space is placed at both edges.
+    ///
+    /// This is the usual interpretation of these alignments.
+    ///
+    /// ```text
+    /// SpaceEvenly: | 1 [a] 1 [b] 1 [c] 1 |
+    /// SpaceAround: | 1 [a] 2 [b] 2 [c] 1 |
+    /// ```
+    #[default]
+    Both,
+    /// Space is placed at the start edge, and the last widget is flush with the end edge.
+    ///
+    /// ```text
+    /// SpaceEvenly: | 1 [a] 1 [b] 1 [c] |
+    /// SpaceAround: | 1 [a] 2 [b] 2 [c] |
+    /// ```
+    Leading,
+    /// Space is placed at the end edge, and the first widget is flush with the start edge.
+    ///
+    /// ```text
+    /// SpaceEvenly: | [a] 1 [b] 1 [c] 1 |
+    /// SpaceAround: | [a] 2 [b] 2 [c] 1 |
+    /// ```
+    Trailing,
+    /// No space is placed at the edges, so widgets are flush with both edges.
+    ///
+    /// This is the same as [`MainAxisAlignment::SpaceBetween`].
+    ///
+    /// ```text
+    /// SpaceEvenly: | [a] 1 [b] 1 [c] |
+    /// SpaceAround: | [a] 2 [b] 2 [c] |
+    /// ```
+    Neither,
+}
+
 /// Alignment of widgets on a container's cross axis.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CrossAxisAlignment {
@@ -3,11 +3,18 @@
 
 use accesskit_consumer::NodeId;
 use assert_matches::assert_matches;
-use masonry_testing::{ModularWidget, Record, TestHarness, TestWidgetExt, assert_any, assert_none};
+use masonry_testing::{
+    ModularWidget, Record, TestHarness, TestWidgetExt, assert_any, assert_debug_panics, assert_none,
+};
 
-use crate::core::{NewWidget, Widget, WidgetTag};
+use crate::accesskit::{Action, ActionRequest, HasPopup, Role, TreeId};
+use crate::core::{
+    CollectionWidget, NewWidget, Widget, WidgetTag, declare_context_menu, set_numeric_range,
+};
+use crate::kurbo::{Affine, Rect};
+use crate::layout::AsUnit;
 use crate::theme::test_property_set;
-use crate::widgets::SizedBox;
+use crate::widgets::{Button, CollapsePanel, Flex, Label, SizedBox};
 
 #[test]
 fn request_accessibility() {
@@ -85,7 +92,230 @@ fn access_node_children() {
     );
 }
 
+#[test]
+fn access_node_children_reading_order() {
+    let parent_tag = WidgetTag::named("parent");
+
+    let child_1 = NewWidget::new(SizedBox::empty());
+    let child_2 = NewWidget::new(SizedBox::empty());
+    let child_3 = NewWidget::new(SizedBox::empty());
+
+    // The parent paints its children in order, but reports them in reverse reading order.
+    let parent = ModularWidget::new_multi_parent(vec![child_1, child_2, child_3])
+        .access_children_fn(|children| children.iter().rev().map(|child| child.id()).collect());
+    let parent = NewWidget::new(parent).with_tag(parent_tag);
+
+    let mut harness = TestHarness::create(test_property_set(), parent);
+    let _ = harness.render();
+
+    let parent_ref = harness.get_widget(parent_tag);
+    let parent_node_id = parent_ref.id();
+    let [id_1, id_2, id_3] = parent_ref.inner().children_ids()[..] else {
+        unreachable!()
+    };
+
+    let parent_node = harness.access_node(parent_node_id).unwrap();
+    assert_eq!(
+        Vec::<u64>::from_iter(parent_node.child_ids().map(node_local_id_to_u64)),
+        vec![id_3.to_raw(), id_2.to_raw(), id_1.to_raw()]
+    );
+}
+
 fn node_local_id_to_u64(node_id: NodeId) -> u64 {
     let node_id: u128 = node_id.into();
     (node_id >> 64) as u64
 }
+
+#[test]
+fn numeric_range_accessibility() {
+    let widget = ModularWidget::new(0.25_f64)
+        .role_fn(|_| Role::SpinButton)
+        .access_fn(|value, _ctx, _props, node| {
+            set_numeric_range(node, Some(*value), -1., 1.);
+            node.set_numeric_value_step(0.05);
+        });
+
+    let harness = TestHarness::create(test_property_set(), NewWidget::new(widget));
+    let node = harness.access_node(harness.root_id()).unwrap();
+
+    assert_eq!(node.role(), Role::SpinButton);
+    assert_eq!(node.numeric_value(), Some(0.25));
+    assert_eq!(node.min_numeric_value(), Some(-1.));
+    assert_eq!(node.max_numeric_value(), Some(1.));
+    assert_eq!(node.numeric_value_step(), Some(0.05));
+}
+
+#[test]
+fn numeric_range_invalid_inputs() {
+    let widget = ModularWidget::new(f64::NAN)
+        .role_fn(|_| Role::SpinButton)
+        .access_fn(|value, _ctx, _props, node| {
+            set_numeric_range(node, Some(*value), 0., 1.);
+        });
+
+    // A NaN value isn't reported, but the range is.
+    let harness = TestHarness::create(test_property_set(), NewWidget::new(widget));
+    let node = harness.access_node(harness.root_id()).unwrap();
+    assert_eq!(node.numeric_value(), None);
+    assert_eq!(node.min_numeric_value(), Some(0.));
+    assert_eq!(node.max_numeric_value(), Some(1.));
+
+    let widget = ModularWidget::new(0.5_f64)
+        .role_fn(|_| Role::SpinButton)
+        .access_fn(|value, _ctx, _props, node| {
+            set_numeric_range(node, Some(*value), 1., 0.);
+        });
+    assert_debug_panics!(
+        TestHarness::create(test_property_set(), NewWidget::new(widget)),
+        "invalid range"
+    );
+}
+
+#[test]
+fn programmatic_focus_updates_tree() {
+    let first_tag = WidgetTag::named("first");
+    let second_tag = WidgetTag::named("second");
+    let flex_tag = WidgetTag::named("flex");
+    let flex = Flex::column()
+        .with_fixed(NewWidget::new(Button::with_text("First")).with_tag(first_tag))
+        .with_fixed(NewWidget::new(Button::with_text("Second")).with_tag(second_tag));
+    let flex = NewWidget::new(flex).with_tag(flex_tag);
+
+    let mut harness = TestHarness::create(test_property_set(), flex);
+    let first_id = harness.get_widget(first_tag).id();
+    let second_id = harness.get_widget(second_tag).id();
+    let _ = harness.redraw();
+
+    harness.focus_on(Some(first_id));
+    let (_, tree_update) = harness.redraw();
+    assert_eq!(tree_update.focus, first_id);
+
+    harness.focus_on(Some(second_id));
+    let (_, tree_update) = harness.redraw();
+    assert_eq!(tree_update.focus, second_id);
+
+    // Removing the focused widget moves the focus back to the window.
+    harness.edit_widget(flex_tag, |mut flex| {
+        Flex::remove(&mut flex, 1);
+    });
+    let (_, tree_update) = harness.redraw();
+    assert_eq!(tree_update.focus, tree_update.tree.unwrap().root);
+}
+
+#[test]
+fn context_menu_accessibility() {
+    let widget = ModularWidget::new(false)
+        .access_fn(|menu_open, _ctx, _props, node| {
+            declare_context_menu(node);
+            node.set_expanded(*menu_open);
+        })
+        .access_event_fn(|menu_open, ctx, _props, event| {
+            if event.action == Action::ShowContextMenu {
+                *menu_open = true;
+                ctx.request_accessibility_update();
+                ctx.set_handled();
+            }
+        });
+
+    let mut harness = TestHarness::create(test_property_set(), NewWidget::new(widget));
+    let root_id = harness.root_id();
+    let node = harness.access_node(root_id).unwrap();
+    assert_eq!(node.data().has_popup(), Some(HasPopup::Menu));
+    assert!(node.data().supports_action(Action::ShowContextMenu));
+    assert_eq!(node.data().is_expanded(), Some(false));
+
+    harness.process_access_event(ActionRequest {
+        action: Action::ShowContextMenu,
+        target_tree: TreeId::ROOT,
+        target_node: root_id.into(),
+        data: None,
+    });
+    assert!(harness.root_widget().state);
+
+    let _ = harness.render();
+    let node = harness.access_node(root_id).unwrap();
+    assert_eq!(node.data().is_expanded(), Some(true));
+}
+
+#[test]
+fn collapse_panel_expanded_state() {
+    let panel_tag = WidgetTag::named("panel");
+    let panel = CollapsePanel::new(true, "Header", Label::new("Content").prepare())
+        .prepare()
+        .with_tag(panel_tag);
+
+    let mut harness = TestHarness::create(test_property_set(), panel);
+    let [button_id, _, child_id] = harness.get_widget(panel_tag).children_ids()[..] else {
+        unreachable!()
+    };
+
+    let node = harness.access_node(button_id).unwrap();
+    assert_eq!(node.data().is_expanded(), Some(false));
+    assert!(node.data().supports_action(Action::Expand));
+    assert!(!node.data().supports_action(Action::Collapse));
+    assert!(harness.get_widget_with_id(child_id).ctx().is_stashed());
+
+    harness.process_access_event(ActionRequest {
+        action: Action::Expand,
+        target_tree: TreeId::ROOT,
+        target_node: button_id.into(),
+        data: None,
+    });
+    let _ = harness.render();
+
+    let node = harness.access_node(button_id).unwrap();
+    assert_eq!(node.data().is_expanded(), Some(true));
+    assert!(node.data().supports_action(Action::Collapse));
+    assert!(!harness.get_widget_with_id(child_id).ctx().is_stashed());
+
+    // Expanding again is a no-op.
+    harness.process_access_event(ActionRequest {
+        action: Action::Expand,
+        target_tree: TreeId::ROOT,
+        target_node: button_id.into(),
+        data: None,
+    });
+    let _ = harness.render();
+    let node = harness.access_node(button_id).unwrap();
+    assert_eq!(node.data().is_expanded(), Some(true));
+}
+
+#[test]
+fn transformed_node_bounds() {
+    let target_tag = WidgetTag::named("target");
+    let target = NewWidget::new(SizedBox::empty().width(40.px()).height(20.px()))
+        .with_tag(target_tag)
+        .with_transform(Affine::scale(2.).then_translate((30., 5.).into()));
+    let parent = Flex::column().with_fixed(target).prepare();
+
+    let mut harness = TestHarness::create(test_property_set(), parent);
+
+    // The node's bounds in the window must match the widget's transformed border-box.
+    let window_bounds = |harness: &TestHarness<_>| {
+        let target = harness.get_widget(target_tag);
+        let target_id = target.id();
+        let ctx = target.ctx();
+        let expected = ctx.window_transform().transform_rect_bbox(ctx.border_box());
+        let bounds = harness
+            .access_node(target_id)
+            .unwrap()
+            .bounding_box()
+            .unwrap();
+        let actual = Rect::new(bounds.x0, bounds.y0, bounds.x1, bounds.y1);
+        (actual, expected)
+    };
+
+    let (actual, expected) = window_bounds(&harness);
+    assert_eq!(actual, expected);
+    assert_eq!(actual.size(), (80., 40.).into());
+
+    // Changing the transform updates the bounds.
+    harness.edit_widget(target_tag, |mut target| {
+        target.ctx.set_transform(Affine::translate((7., 11.)));
+    });
+    let _ = harness.render();
+
+    let (actual, expected) = window_bounds(&harness);
+    assert_eq!(actual, expected);
+    assert_eq!(actual.size(), (40., 20.).into());
+}
@@ -36,3 +36,23 @@ fn needs_anim_flag() {
     // We didn't re-request an animation, so nothing should happen.
     assert_matches!(harness.take_records_of(parent_tag)[..], []);
 }
+
+#[test]
+fn anim_request_is_cleared() {
+    let target_tag = WidgetTag::named("target");
+    let child = NewWidget::new(SizedBox::empty().record()).with_tag(target_tag);
+    let parent = NewWidget::new(ModularWidget::new_parent(child));
+
+    let mut harness = TestHarness::create(test_property_set(), parent);
+    harness.animate_ms(16);
+    assert!(!harness.needs_anim());
+
+    harness.edit_widget(target_tag, |mut widget| {
+        widget.ctx.request_anim_frame();
+    });
+    assert!(harness.needs_anim());
+
+    // The widget doesn't re-request a frame from `on_anim_frame`, so the tree goes idle.
+    harness.animate_ms(16);
+    assert!(!harness.needs_anim());
+}
@@ -4,19 +4,23 @@
 use accesskit::{ActionRequest, TreeId};
 use assert_matches::assert_matches;
 
-use crate::core::keyboard::{Key, NamedKey};
-use crate::core::pointer::{PointerButton, PointerEvent, PointerInfo, PointerType};
+use crate::core::keyboard::{Key, KeyState, NamedKey};
+use crate::core::pointer::{
+    PointerButton, PointerEvent, PointerInfo, PointerOrientation, PointerType,
+};
 use crate::core::{
-    AccessEvent, NewWidget, PointerButtonEvent, PointerId, PointerState, PointerUpdate, TextEvent,
-    Update, Widget, WidgetId, WidgetTag,
+    AccessEvent, InputEvent, Modifiers, NewWidget, PointerButtonEvent, PointerId,
+    PointerScrollEvent, PointerState, PointerUpdate, ScrollDelta, TextEvent, Update, Widget,
+    WidgetId, WidgetTag,
 };
 use crate::dpi::PhysicalPosition;
-use crate::kurbo::Point;
+use crate::kurbo::{Point, Vec2};
 use crate::layout::AsUnit;
 use crate::testing::{
     ModularWidget, Record, TestHarness, TestWidgetExt, assert_any, assert_debug_panics, assert_none,
 };
 use crate::theme::test_property_set;
+use crate::util::Duration;
 use crate::widgets::{Button, ButtonPress, Flex, SizedBox, TextArea};
 
 // POINTER EVENTS
@@ -49,6 +53,42 @@ fn pointer_event() {
     });
 }
 
+#[test]
+fn pointer_scroll() {
+    let target_tag = WidgetTag::named("target");
+    let target =
+        NewWidget::new(SizedBox::empty().size(50.px(), 50.px()).record()).with_tag(target_tag);
+
+    let mut harness = TestHarness::create(test_property_set(), target);
+    let target_id = harness.get_widget(target_tag).id();
+    harness.mouse_move_to(target_id);
+    harness.flush_records_of(target_tag);
+
+    harness.mouse_scroll(ScrollDelta::LineDelta(0., -3.));
+    let records = harness.take_records_of(target_tag);
+    assert_any(records, |r| {
+        matches!(
+            r,
+            Record::PointerEvent(PointerEvent::Scroll(PointerScrollEvent {
+                delta: ScrollDelta::LineDelta(x, y),
+                ..
+            })) if x == 0. && y == -3.
+        )
+    });
+
+    harness.mouse_wheel(Vec2::new(10., 0.));
+    let records = harness.take_records_of(target_tag);
+    assert_any(records, |r| {
+        matches!(
+            r,
+            Record::PointerEvent(PointerEvent::Scroll(PointerScrollEvent {
+                delta: ScrollDelta::PixelDelta(PhysicalPosition { x, y }),
+                ..
+            })) if x == 10. && y == 0.
+        )
+    });
+}
+
 #[test]
 fn pointer_event_bubbling() {
     let button_tag = WidgetTag::named("button");
@@ -314,6 +354,65 @@ fn pointer_release(pos: impl Into<Point>, id: u64) -> PointerEvent {
     })
 }
 
+#[test]
+fn pointer_pressure_and_tilt() {
+    let widget = ModularWidget::new(None)
+        .pointer_event_fn(|state, _, _, event| {
+            if let PointerEvent::Move(PointerUpdate { current, .. }) = event {
+                *state = Some((current.pressure, current.orientation));
+            }
+        })
+        .measure_fn(|_, _, _, _, _, _| 10.px())
+        .prepare();
+
+    let mut harness = TestHarness::create(test_property_set(), widget);
+
+    let orientation = PointerOrientation {
+        altitude: 1.,
+        azimuth: 0.5,
+    };
+    harness.set_pointer_pressure(0.75);
+    harness.set_pointer_orientation(orientation);
+    harness.mouse_move((5., 5.));
+
+    assert_eq!(harness.root_widget().state, Some((0.75, orientation)));
+}
+
+#[test]
+fn pointer_velocity() {
+    let widget = ModularWidget::new(Vec2::ZERO)
+        .pointer_event_fn(|state, ctx, _, event| match event {
+            PointerEvent::Down { .. } => ctx.capture_pointer(),
+            PointerEvent::Move(..) => *state = ctx.pointer_velocity(),
+            _ => {}
+        })
+        .measure_fn(|_, _, _, _, _, _| 10.px())
+        .prepare();
+
+    let mut harness = TestHarness::create(test_property_set(), widget);
+
+    harness.mouse_move_at_time((5., 5.), Duration::ZERO);
+    harness.mouse_button_press(Some(PointerButton::Primary));
+    harness.mouse_move_at_time((15., 5.), Duration::from_millis(10));
+    harness.mouse_move_at_time((25., 5.), Duration::from_millis(20));
+
+    // 20 pixels in 20 milliseconds.
+    assert_eq!(harness.root_widget().state, Vec2::new(1000., 0.));
+
+    // Samples older than the tracking window are ignored.
+    harness.mouse_move_at_time((25., 5.), Duration::from_millis(500));
+    harness.mouse_move_at_time((25., 55.), Duration::from_millis(550));
+    assert_eq!(harness.root_widget().state, Vec2::new(0., 1000.));
+
+    // Samples from another pointer aren't mixed with those of the mouse.
+    let mut event = pointer_move((5., 5.), 2);
+    if let PointerEvent::Move(PointerUpdate { current, .. }) = &mut event {
+        current.time = 560_000_000;
+    }
+    harness.process_pointer_event(event);
+    assert_eq!(harness.root_widget().state, Vec2::ZERO);
+}
+
 // TODO - Implement multi-pointer hover
 #[test]
 #[ignore]
@@ -603,6 +702,44 @@ fn tab_focus() {
     assert_eq!(harness.focused_widget_id(), Some(child_5_id));
 }
 
+#[test]
+fn tab_stop_false_is_skipped() {
+    let child_1 = WidgetTag::named("child_1");
+    let child_2 = WidgetTag::named("child_2");
+    let child_3 = WidgetTag::named("child_3");
+
+    let parent = Flex::column()
+        .with_fixed(NewWidget::new(Button::with_text("")).with_tag(child_1))
+        .with_fixed(
+            NewWidget::new(Button::with_text(""))
+                .with_tag(child_2)
+                .with_tab_stop(false),
+        )
+        .with_fixed(NewWidget::new(Button::with_text("")).with_tag(child_3))
+        .prepare();
+
+    let mut harness = TestHarness::create(test_property_set(), parent);
+
+    let child_1_id = harness.get_widget(child_1).id();
+    let child_2_id = harness.get_widget(child_2).id();
+    let child_3_id = harness.get_widget(child_3).id();
+
+    // Tab skips over the widget in both directions.
+    harness.focus_on(Some(child_1_id));
+    harness.press_tab_key(false);
+    assert_eq!(harness.focused_widget_id(), Some(child_3_id));
+    harness.press_tab_key(true);
+    assert_eq!(harness.focused_widget_id(), Some(child_1_id));
+
+    // It can still be focused by clicking on it.
+    harness.mouse_click_on(child_2_id, None);
+    assert_eq!(harness.focused_widget_id(), Some(child_2_id));
+
+    // Tab then moves on from it as usual.
+    harness.press_tab_key(false);
+    assert_eq!(harness.focused_widget_id(), Some(child_3_id));
+}
+
 // ACCESS EVENTS
 
 #[test]
@@ -709,3 +846,82 @@ fn downcast_untyped_action() {
         Some((ArbitraryAction, _))
     );
 }
+
+#[test]
+fn pending_input_events() {
+    let widget = NewWidget::new(SizedBox::empty());
+    let mut harness = TestHarness::create(test_property_set(), widget);
+    assert!(harness.pending_input_events().is_empty());
+
+    // Events are recorded as soon as they're processed, without running a frame.
+    harness.process_text_event(TextEvent::key_down(Key::Character("a".into())));
+    harness.process_text_event(TextEvent::key_up(Key::Character("b".into())));
+    let keys: Vec<_> = harness
+        .pending_input_events()
+        .iter()
+        .map(|event| match event {
+            InputEvent::Text(TextEvent::Keyboard(event)) => (event.state, event.key.clone()),
+            event => panic!("unexpected event {event:?}"),
+        })
+        .collect();
+    assert_eq!(
+        keys,
+        [
+            (KeyState::Down, Key::Character("a".into())),
+            (KeyState::Up, Key::Character("b".into())),
+        ]
+    );
+    assert_eq!(harness.handled_input_events_last_frame(), 0);
+
+    let _ = harness.redraw();
+    assert!(harness.pending_input_events().is_empty());
+    assert_eq!(harness.handled_input_events_last_frame(), 2);
+
+    let _ = harness.redraw();
+    assert_eq!(harness.handled_input_events_last_frame(), 0);
+}
+
+#[test]
+fn shift_click_range_selection() {
+    // A list of five 20px tall items, which extends its selection when shift-clicked.
+    #[derive(Default)]
+    struct ListState {
+        anchor: usize,
+        selection: std::ops::Range<usize>,
+    }
+    let list = ModularWidget::new(ListState::default())
+        .pointer_event_fn(|list, ctx, _, event| {
+            if let PointerEvent::Down(PointerButtonEvent { state, .. }) = event {
+                let item = (ctx.local_position(state.position).y / 20.) as usize;
+                if state.modifiers.shift() {
+                    list.selection = list.anchor.min(item)..list.anchor.max(item) + 1;
+                } else {
+                    list.anchor = item;
+                    list.selection = item..item + 1;
+                }
+            }
+        })
+        .measure_fn(|_, _, _, _, _, _| 100.px())
+        .prepare();
+
+    let mut harness = TestHarness::create_with_size(test_property_set(), list, (100, 100));
+    let click_item = |harness: &mut TestHarness<_>, item: f64| {
+        harness.mouse_move((50., item * 20. + 10.));
+        harness.mouse_button_press(None);
+        harness.mouse_button_release(None);
+    };
+
+    click_item(&mut harness, 1.);
+    assert_eq!(harness.root_widget().state.selection, 1..2);
+
+    // Shift stays held across clicks, which extend the range from the first item clicked.
+    harness.set_modifiers(Modifiers::SHIFT);
+    click_item(&mut harness, 3.);
+    assert_eq!(harness.root_widget().state.selection, 1..4);
+    click_item(&mut harness, 0.);
+    assert_eq!(harness.root_widget().state.selection, 0..2);
+
+    harness.set_modifiers(Modifiers::empty());
+    click_item(&mut harness, 4.);
+    assert_eq!(harness.root_widget().state.selection, 4..5);
+}
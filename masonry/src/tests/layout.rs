@@ -6,14 +6,15 @@ use std::rc::Rc;
 
 use assert_matches::assert_matches;
 
-use crate::core::{NewWidget, Widget, WidgetTag};
+use crate::core::{FromDynWidget, NewWidget, StyleProperty, StyleSet, Widget, WidgetTag};
 use crate::kurbo::{Insets, Point, Rect, Size, Vec2};
 use crate::layout::{AsUnit, Length, SizeDef};
-use crate::properties::{BorderWidth, Dimensions, Padding};
+use crate::properties::types::CrossAxisAlignment;
+use crate::properties::{BorderWidth, Dimensions, Gap, Padding};
 use crate::testing::{ModularWidget, TestHarness, TestWidgetExt, assert_debug_panics};
 use crate::tests::{assert_point_approx_eq, assert_rect_approx_eq, assert_vec2_approx_eq};
-use crate::theme::test_property_set;
-use crate::widgets::{Button, ChildAlignment, Flex, Portal, SizedBox, ZStack};
+use crate::theme::{self, default_text_styles, test_property_set};
+use crate::widgets::{Button, ChildAlignment, Flex, Label, Marquee, Portal, SizedBox, ZStack};
 
 #[test]
 fn layout_simple() {
@@ -359,6 +360,75 @@ fn content_box() {
     assert_eq!(content_box, expected_content_box);
 }
 
+#[test]
+fn padding_insets_child_inside_border() {
+    // Top, right, bottom, left.
+    let container_props = (
+        Dimensions::fixed(100.px(), 100.px()),
+        BorderWidth::all(2.px()),
+        Padding::new(1.px(), 2.px(), 3.px(), 4.px()),
+    );
+    // The border comes first, then the padding, adding up to the child's offset.
+    let expected_offset = Vec2::new(2. + 4., 2. + 1.);
+
+    fn child_offset(
+        harness: &TestHarness<impl Widget>,
+        parent: WidgetTag<impl Widget + FromDynWidget>,
+        child: WidgetTag<SizedBox>,
+    ) -> Vec2 {
+        let parent = harness.get_widget(parent);
+        let child = harness.get_widget(child);
+        child.ctx().to_window(child.ctx().border_box().origin())
+            - parent.ctx().to_window(parent.ctx().border_box().origin())
+    }
+
+    // SizedBox gives its whole content box to a stretching child.
+    let parent_tag = WidgetTag::named("parent");
+    let child_tag = WidgetTag::named("child");
+    let child = SizedBox::empty()
+        .prepare()
+        .with_tag(child_tag)
+        .with_props(Dimensions::STRETCH);
+    let parent = SizedBox::new(child)
+        .prepare()
+        .with_tag(parent_tag)
+        .with_props(container_props);
+    let harness = TestHarness::create_with_size(test_property_set(), parent, (200, 200));
+
+    assert_eq!(
+        child_offset(&harness, parent_tag, child_tag),
+        expected_offset
+    );
+    assert_eq!(
+        harness.get_widget(child_tag).ctx().border_box().size(),
+        Size::new(100. - 4. - 6., 100. - 4. - 4.)
+    );
+
+    // Flex places its children from the start of its content box.
+    let parent_tag = WidgetTag::named("parent");
+    let child_tag = WidgetTag::named("child");
+    let child = SizedBox::empty()
+        .size(10.px(), 10.px())
+        .prepare()
+        .with_tag(child_tag);
+    let parent = Flex::row()
+        .cross_axis_alignment(CrossAxisAlignment::Start)
+        .with_fixed(child)
+        .prepare()
+        .with_tag(parent_tag)
+        .with_props(container_props);
+    let harness = TestHarness::create_with_size(test_property_set(), parent, (200, 200));
+
+    assert_eq!(
+        child_offset(&harness, parent_tag, child_tag),
+        expected_offset
+    );
+    assert_eq!(
+        harness.get_widget(child_tag).ctx().border_box().size(),
+        Size::new(10., 10.)
+    );
+}
+
 #[test]
 fn boxes_match_without_insets_or_snapping() {
     let tag = WidgetTag::unique();
@@ -513,3 +583,95 @@ fn content_box_clamps_when_insets_exceed_size() {
     );
     assert_rect_approx_eq("content_box", ctx.content_box(), Rect::ZERO);
 }
+
+#[test]
+fn measure_text_matches_label() {
+    const TEXT: &str = "Axis label";
+
+    let label_tag = WidgetTag::named("label");
+    let measurer_tag = WidgetTag::named("measurer");
+    let measurer = ModularWidget::new(Size::ZERO).layout_fn(|state, ctx, _, _| {
+        let mut styles = StyleSet::new(theme::TEXT_SIZE_NORMAL);
+        default_text_styles(&mut styles);
+        *state = ctx.measure_text(TEXT, &styles, None);
+    });
+
+    let widget = Flex::column()
+        .cross_axis_alignment(CrossAxisAlignment::Start)
+        .with_fixed(NewWidget::new(Label::new(TEXT)).with_tag(label_tag))
+        .with_fixed(NewWidget::new(measurer).with_tag(measurer_tag))
+        .prepare();
+    let harness = TestHarness::create(test_property_set(), widget);
+
+    let label_size = harness.get_widget(label_tag).ctx().content_box().size();
+    let measured = harness.get_widget(measurer_tag).state;
+    assert!(!measured.is_zero_area());
+    // The label sits at the origin, so its edges are rounded to whole pixels.
+    assert_eq!(measured.round(), label_size);
+}
+
+#[test]
+fn text_baselines_line_up_in_row() {
+    let big_tag = WidgetTag::named("big");
+    let button_tag = WidgetTag::named("button");
+    let marquee_tag = WidgetTag::named("marquee");
+
+    let widget = Flex::row()
+        .cross_axis_alignment(CrossAxisAlignment::FirstBaseline)
+        .with_fixed(
+            NewWidget::new(Label::new("Big").with_style(StyleProperty::FontSize(40.)))
+                .with_tag(big_tag),
+        )
+        .with_fixed(NewWidget::new(Button::with_text("Button")).with_tag(button_tag))
+        .with_fixed(NewWidget::new(Marquee::new("Marquee")).with_tag(marquee_tag))
+        .prepare();
+    let harness = TestHarness::create(test_property_set(), widget);
+
+    let window_baseline = |id| {
+        let widget = harness.get_widget_with_id(id);
+        let ctx = widget.ctx();
+        ctx.to_window(Point::new(0., ctx.first_baseline())).y
+    };
+    let big = window_baseline(harness.get_widget(big_tag).id());
+    let button = window_baseline(harness.get_widget(button_tag).id());
+    let marquee = window_baseline(harness.get_widget(marquee_tag).id());
+
+    // The children have different heights, so they're only aligned through their baselines.
+    let big_height = harness.get_widget(big_tag).ctx().border_box().height();
+    let button_height = harness.get_widget(button_tag).ctx().border_box().height();
+    assert_ne!(big_height, button_height);
+
+    // Pixel snapping may shift each child by less than a pixel.
+    assert!((big - button).abs() < 1., "{big} != {button}");
+    assert!((big - marquee).abs() < 1., "{big} != {marquee}");
+}
+
+#[test]
+fn widget_layout_rect_in_window_coords() {
+    let tag = WidgetTag::named("child");
+
+    let widget = Flex::row()
+        .cross_axis_alignment(CrossAxisAlignment::Start)
+        .with_fixed(SizedBox::empty().size(30.px(), 10.px()).prepare())
+        .with_fixed(
+            SizedBox::empty()
+                .size(40.px(), 20.px())
+                .prepare()
+                .with_tag(tag),
+        )
+        .prepare()
+        .with_props((Gap::ZERO, Padding::all(5.px())));
+    let harness = TestHarness::create_with_size(test_property_set(), widget, (200, 100));
+
+    let id = harness.get_widget(tag).id();
+    assert_eq!(harness.widget_layout_rect(id), Rect::new(35., 5., 75., 25.));
+}
+
+#[test]
+#[should_panic(expected = "could not find widget")]
+fn widget_layout_rect_unknown_id() {
+    let harness = TestHarness::create(test_property_set(), SizedBox::empty().prepare());
+    // This widget is never added to the tree.
+    let orphan = SizedBox::empty().prepare();
+    harness.widget_layout_rect(orphan.id());
+}
@@ -6,6 +6,7 @@
 //! widget/property set in our tests if needed.
 
 use crate::kurbo::{Point, Rect, Vec2};
+use crate::peniko::{ImageAlphaType, ImageData, ImageFormat};
 
 mod accessibility;
 mod action;
@@ -16,6 +17,7 @@ mod layout;
 mod mutate;
 mod paint;
 mod properties;
+mod snapshots;
 mod transforms;
 mod update;
 mod widget_tag;
@@ -47,3 +49,37 @@ pub(crate) fn assert_rect_approx_eq(name: &str, actual: Rect, expected: Rect) {
     assert_approx_eq(&format!("{name}.x1"), actual.x1, expected.x1);
     assert_approx_eq(&format!("{name}.y1"), actual.y1, expected.y1);
 }
+
+/// Returns the color of pixel `(x, y)` in a one-pixel black and white checkerboard.
+pub(crate) fn checkerboard_pixel(x: u32, y: u32) -> [u8; 4] {
+    if (x + y).is_multiple_of(2) {
+        [255, 255, 255, 255]
+    } else {
+        [0, 0, 0, 255]
+    }
+}
+
+/// Returns a `side` by `side` one-pixel checkerboard, which would turn gray if resampled.
+pub(crate) fn checkerboard_image(side: u32) -> ImageData {
+    ImageData {
+        data: (0..side * side)
+            .flat_map(|i| checkerboard_pixel(i % side, i / side))
+            .collect::<Vec<_>>()
+            .into(),
+        format: ImageFormat::Rgba8,
+        alpha_type: ImageAlphaType::Alpha,
+        width: side,
+        height: side,
+    }
+}
+
+/// Returns whether every pixel of `render` maps to exactly one checkerboard pixel.
+pub(crate) fn matches_checkerboard(render: &image::RgbaImage) -> bool {
+    render.enumerate_pixels().all(|(x, y, rendered)| {
+        rendered
+            .0
+            .iter()
+            .zip(checkerboard_pixel(x, y))
+            .all(|(a, b)| a.abs_diff(b) <= 8)
+    })
+}
@@ -3,11 +3,12 @@
 
 use std::sync::mpsc;
 
-use masonry_testing::{ModularWidget, TestHarness};
+use masonry_testing::{ModularWidget, Record, TestHarness, TestWidgetExt};
 
-use crate::core::{NewWidget, WidgetTag};
+use crate::app::TreeOp;
+use crate::core::{NewWidget, Widget, WidgetTag};
 use crate::theme::test_property_set;
-use crate::widgets::SizedBox;
+use crate::widgets::{Flex, FlexParams, Label, SizedBox};
 
 #[test]
 fn mutate_order() {
@@ -59,3 +60,110 @@ fn cancel_mutate() {
         SizedBox::remove_child(&mut parent);
     });
 }
+
+#[test]
+fn mutate_removed_widget() {
+    let parent_tag = WidgetTag::named("parent");
+    let child_tag = WidgetTag::named("child");
+    let child = NewWidget::new(SizedBox::empty()).with_tag(child_tag);
+    let parent = NewWidget::new(SizedBox::new(child)).with_tag(parent_tag);
+
+    let mut harness = TestHarness::create(test_property_set(), parent);
+    let child_id = harness.get_widget(child_tag).id();
+    harness.edit_widget(parent_tag, move |mut parent| {
+        // The first callback removes the child, so the second one is skipped.
+        parent.ctx.mutate_self_later(|mut parent| {
+            SizedBox::remove_child(&mut parent.downcast());
+        });
+        parent.ctx.mutate_later(child_id, |_| {
+            panic!("This function should never get called");
+        });
+    });
+    assert!(harness.try_get_widget(child_id).is_none());
+}
+
+#[test]
+fn mutations_are_batched() {
+    let label_tag = WidgetTag::named("label");
+    let parent_tag = WidgetTag::named("parent");
+    let label = NewWidget::new(Label::new("0")).with_tag(label_tag);
+    let parent = NewWidget::new(ModularWidget::new_parent(label).record()).with_tag(parent_tag);
+
+    let mut harness = TestHarness::create(test_property_set(), parent);
+    harness.flush_records_of(parent_tag);
+
+    // Each edit requests a new layout, but passes only run once the callback returns.
+    harness.edit_widget(label_tag, |mut label| {
+        for i in 1..=10 {
+            Label::set_text(&mut label, i.to_string());
+        }
+    });
+
+    let records = harness.take_records_of(parent_tag);
+    let layouts = records
+        .iter()
+        .filter(|record| matches!(record, Record::Layout(_)))
+        .count();
+    assert_eq!(layouts, 1);
+    assert_eq!(harness.get_widget(label_tag).text().as_ref(), "10");
+}
+
+#[test]
+fn tree_ops_are_batched() {
+    let flex_tag = WidgetTag::named("flex");
+    let parent_tag = WidgetTag::named("parent");
+    let flex = Flex::row()
+        .with_fixed(Label::new("a").prepare())
+        .with_fixed(Label::new("b").prepare())
+        .with_fixed(Label::new("c").prepare());
+    let flex = NewWidget::new(flex).with_tag(flex_tag);
+    let parent = NewWidget::new(ModularWidget::new_parent(flex).record()).with_tag(parent_tag);
+
+    let mut harness = TestHarness::create(test_property_set(), parent);
+    harness.flush_records_of(parent_tag);
+
+    let flex_id = harness.get_widget(flex_tag).id();
+    let label = |text: &str| Label::new(text.to_string()).prepare().erased();
+    harness.apply_tree_ops::<Flex, FlexParams>([
+        // a b c -> a d b c
+        TreeOp::Insert {
+            parent: flex_id,
+            idx: 1,
+            child: label("d"),
+            params: FlexParams::default(),
+        },
+        // a d b c -> a b c
+        TreeOp::Remove {
+            parent: flex_id,
+            idx: 1,
+        },
+        // a b c -> c a b
+        TreeOp::Move {
+            parent: flex_id,
+            from: 2,
+            to: 0,
+        },
+        // c a b -> c a e
+        TreeOp::Replace {
+            parent: flex_id,
+            idx: 2,
+            child: label("e"),
+            params: FlexParams::default(),
+        },
+    ]);
+
+    let records = harness.take_records_of(parent_tag);
+    let layouts = records
+        .iter()
+        .filter(|record| matches!(record, Record::Layout(_)))
+        .count();
+    assert_eq!(layouts, 1);
+
+    let texts: Vec<String> = harness
+        .get_widget(flex_tag)
+        .children()
+        .iter()
+        .map(|child| child.downcast::<Label>().unwrap().text().to_string())
+        .collect();
+    assert_eq!(texts, ["c", "a", "e"]);
+}
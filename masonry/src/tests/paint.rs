@@ -6,20 +6,24 @@ use std::sync::Arc;
 use assert_matches::assert_matches;
 
 use crate::app::{RenderRoot, RenderRootOptions, WindowSizePolicy};
-use crate::core::{NewWidget, PaintLayerMode, PropertySet, Widget, WidgetTag};
+use crate::core::{NewWidget, OffscreenFilter, PaintLayerMode, PropertySet, Widget, WidgetTag};
 use crate::dpi::PhysicalSize;
-use crate::kurbo::{Circle, Dashes, Point, Stroke, Vec2};
+use crate::kurbo::{Circle, Dashes, Point, Size, Stroke, Vec2};
 use crate::layout::{AsUnit, Length, SizeDef, UnitPoint};
 use crate::palette::css::{BLUE, GREEN, RED};
 use crate::peniko::color::{AlphaColor, Srgb};
 use crate::peniko::{Blob, Color};
 use crate::properties::types::MainAxisAlignment;
-use crate::properties::{Background, Dimensions, Gap, Padding};
+use crate::properties::{Background, BoxShadow, BoxShadows, Dimensions, Gap, Opacity, Padding};
 use crate::testing::{
     ModularWidget, ROBOTO, Record, TestHarness, TestWidgetExt, assert_render_snapshot,
 };
+use crate::tests::{checkerboard_image, matches_checkerboard};
 use crate::theme::test_property_set;
-use crate::widgets::{Align, ChildAlignment, Flex, Grid, GridParams, Label, SizedBox, ZStack};
+use crate::widgets::{
+    Align, Checkbox, ChildAlignment, Flex, Grid, GridParams, Image, Label, ProgressBar,
+    RadioButton, SizedBox, Slider, TextInput, ZStack,
+};
 
 #[test]
 fn request_paint() {
@@ -63,6 +67,70 @@ fn request_paint() {
     assert_matches!(harness.take_records_of(target_tag)[..], []);
 }
 
+#[test]
+fn invalidate_all() {
+    let target_tag = WidgetTag::named("target");
+    let parent_tag = WidgetTag::named("parent");
+    let child = NewWidget::new(SizedBox::empty().record()).with_tag(target_tag);
+    let parent = NewWidget::new(ModularWidget::new_parent(child).record()).with_tag(parent_tag);
+    let grandparent = NewWidget::new(ModularWidget::new_parent(parent));
+
+    let mut harness = TestHarness::create(test_property_set(), grandparent);
+    let _ = harness.render();
+    harness.flush_records_of(target_tag);
+    harness.flush_records_of(parent_tag);
+
+    // Nothing was requested, so nothing should run.
+    let _ = harness.render();
+    assert_matches!(harness.take_records_of(target_tag)[..], []);
+    assert_matches!(harness.take_records_of(parent_tag)[..], []);
+
+    harness.invalidate_all();
+    let _ = harness.render();
+
+    for records in [
+        harness.take_records_of(target_tag),
+        harness.take_records_of(parent_tag),
+    ] {
+        assert!(records.iter().any(|r| matches!(r, Record::Layout(_))));
+        assert!(records.iter().any(|r| matches!(r, Record::Paint)));
+    }
+}
+
+#[test]
+fn paint_counts() {
+    let first_tag = WidgetTag::named("first");
+    let second_tag = WidgetTag::named("second");
+    let first = NewWidget::new(SizedBox::empty()).with_tag(first_tag);
+    let second = NewWidget::new(SizedBox::empty()).with_tag(second_tag);
+    let parent = NewWidget::new(
+        Flex::row()
+            .with_fixed(first.with_props(Dimensions::fixed(20.px(), 20.px())))
+            .with_fixed(second.with_props(Dimensions::fixed(20.px(), 20.px()))),
+    );
+
+    let mut harness = TestHarness::create(test_property_set(), parent);
+    let _ = harness.render();
+    let first_id = harness.get_widget(first_tag).id();
+    let second_id = harness.get_widget(second_tag).id();
+    let first_paints = harness.paint_count(first_id);
+    let second_paints = harness.paint_count(second_id);
+    assert!(first_paints > 0);
+    let first_layouts = harness.layout_count(first_id);
+    let second_layouts = harness.layout_count(second_id);
+
+    harness.edit_widget(first_tag, |mut widget| {
+        widget.ctx.request_paint_only();
+    });
+    let _ = harness.render();
+
+    // Only the mutated widget is repainted, and nothing is laid out again.
+    assert_eq!(harness.paint_count(first_id), first_paints + 1);
+    assert_eq!(harness.paint_count(second_id), second_paints);
+    assert_eq!(harness.layout_count(first_id), first_layouts);
+    assert_eq!(harness.layout_count(second_id), second_layouts);
+}
+
 #[test]
 fn paint_order() {
     const SQUARE_SIZE: f64 = 30.;
@@ -342,3 +410,156 @@ fn paint_transparency() {
 
     assert_render_snapshot!(harness, "paint_transparency");
 }
+
+#[test]
+fn paint_offscreen_filter() {
+    const SQUARE_SIZE: f64 = 80.;
+
+    let widget = NewWidget::new(
+        ModularWidget::new(())
+            .measure_fn(|_, _, _, _, _, _| SQUARE_SIZE.px())
+            .paint_fn(move |_, ctx, _, painter| {
+                let bounds = ctx.content_box();
+                let inner = bounds.inset(-SQUARE_SIZE / 4.);
+                let offscreen = ctx.with_offscreen(|painter| {
+                    painter.fill(inner, Color::WHITE).draw();
+                });
+                offscreen.draw(painter, OffscreenFilter::Tint(RED));
+            }),
+    );
+    let root = NewWidget::new(
+        Flex::column()
+            .main_axis_alignment(MainAxisAlignment::Center)
+            .with_fixed(widget),
+    );
+
+    let mut harness = TestHarness::create_with_size(
+        test_property_set(),
+        root,
+        (SQUARE_SIZE as u32 * 2, SQUARE_SIZE as u32 * 2),
+    );
+
+    // The white box should be tinted red, and the area around it left untouched.
+    assert_render_snapshot!(harness, "paint_offscreen_filter");
+}
+
+#[test]
+fn paint_opacity_property() {
+    let label_tag = WidgetTag::named("label");
+    let label = NewWidget::new(Label::new("Faded").record())
+        .with_tag(label_tag)
+        .with_props((Background::Color(BLUE), Opacity::new(0.5)));
+    let root = NewWidget::new(SizedBox::new(label))
+        .with_props((Background::Color(RED), Padding::all(10.px())));
+
+    let mut harness = TestHarness::create_with_size(test_property_set(), root, (100, 50));
+
+    // The label and its blue background should blend with the red background behind them.
+    assert_render_snapshot!(harness, "paint_opacity_property");
+
+    // Out of range values are clamped, and changes only repaint.
+    harness.flush_records_of(label_tag);
+    harness.edit_widget(label_tag, |mut label| {
+        label.insert_prop(Opacity(2.));
+    });
+    let _ = harness.render();
+    let records = harness.take_records_of(label_tag);
+    assert!(records.iter().any(|r| matches!(r, Record::Paint)));
+    assert!(
+        !records
+            .iter()
+            .any(|r| matches!(r, Record::Measure(_) | Record::Layout(_)))
+    );
+    assert_eq!(Opacity(2.).alpha(), 1.);
+    assert_eq!(Opacity::new(-1.), Opacity(0.));
+}
+
+#[test]
+fn scale_factor_change() {
+    let target_tag = WidgetTag::named("target");
+    let target = NewWidget::new(SizedBox::empty().size(20.px(), 10.px()))
+        .with_tag(target_tag)
+        .with_props(Background::Color(RED));
+    let root = Align::new(UnitPoint::TOP_LEFT, target).prepare();
+
+    let mut harness = TestHarness::create_with_size(test_property_set(), root, (40, 20));
+    let render = harness.render();
+    let logical_box = harness.get_widget(target_tag).ctx().border_box();
+
+    harness.set_scale_factor(2.0);
+    assert_eq!(harness.window_size(), PhysicalSize::new(80, 40));
+
+    // Layout stays in logical pixels.
+    assert_eq!(
+        harness.get_widget(target_tag).ctx().border_box(),
+        logical_box
+    );
+
+    let scaled_render = harness.render();
+    // Hack: If we are using `SKIP_RENDER_TESTS`, the output image is a 1x1 white pixel.
+    if std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+        return;
+    }
+    assert_eq!(render.dimensions(), (40, 20));
+    assert_eq!(scaled_render.dimensions(), (80, 40));
+
+    // The box covers twice as many physical pixels in each direction.
+    let red = RED.to_rgba8();
+    let red = [red.r, red.g, red.b, red.a];
+    assert_eq!(render.get_pixel(19, 9).0, red);
+    assert_ne!(render.get_pixel(21, 11).0, red);
+    assert_eq!(scaled_render.get_pixel(39, 19).0, red);
+    assert_ne!(scaled_render.get_pixel(41, 21).0, red);
+}
+
+#[test]
+fn scale_factor_image_sampling() {
+    const SIDE: u32 = 100;
+    let image_data = checkerboard_image(SIDE);
+    let root = NewWidget::new(Image::new(image_data));
+
+    // The image is laid out at 50x50 logical pixels, which are 100x100 physical pixels.
+    let mut harness = TestHarness::create_with_size(test_property_set(), root, (50, 50));
+    harness.set_scale_factor(2.0);
+    assert_eq!(harness.window_size(), PhysicalSize::new(SIDE, SIDE));
+    assert_eq!(
+        harness.root_widget().ctx().content_box().size(),
+        Size::new(50., 50.)
+    );
+
+    let render = harness.render();
+    // Hack: If we are using `SKIP_RENDER_TESTS`, the output image is a 1x1 white pixel.
+    if std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+        return;
+    }
+    assert!(matches_checkerboard(&render));
+}
+
+#[test]
+fn box_shadows_on_custom_pre_paint() {
+    // These widgets paint their own box instead of using the default pre-paint,
+    // and should still paint the layered shadows.
+    fn assert_shadows_painted<W: Widget>(name: &str, make_widget: impl Fn() -> W) {
+        let render = |shadows: Option<BoxShadows>| {
+            let mut widget = NewWidget::new(make_widget());
+            if let Some(shadows) = shadows {
+                widget = widget.with_props(shadows);
+            }
+            let root = NewWidget::new(Align::centered(widget));
+            TestHarness::create_with_size(test_property_set(), root, (160, 80)).render()
+        };
+        let shadows = BoxShadows::new([BoxShadow::new(RED, (4., 4.)).blur(2.px())]);
+        assert_ne!(render(None), render(Some(shadows)), "{name}");
+    }
+
+    // Hack: If we are using `SKIP_RENDER_TESTS`, the output image is a 1x1 white pixel.
+    if std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+        return;
+    }
+
+    assert_shadows_painted("checkbox", || Checkbox::new(true, "Shadow"));
+    assert_shadows_painted("radio button", || RadioButton::new(true, "Shadow"));
+    assert_shadows_painted("progress bar", || ProgressBar::new(Some(0.5)));
+    assert_shadows_painted("slider", || Slider::new(0., 1., 0.5));
+    assert_shadows_painted("text input", || TextInput::new("Shadow"));
+}
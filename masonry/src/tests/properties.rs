@@ -1,13 +1,15 @@
 // Copyright 2026 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::core::Widget as _;
+use crate::core::{Widget as _, WidgetTag};
 use crate::kurbo::Rect;
 use crate::layout::AsUnit;
-use crate::palette::css::BLUE;
-use crate::properties::{ContentColor, Dimensions, Gap, ObjectFit};
+use crate::palette::css::{BLUE, RED};
+use crate::properties::{Background, ContentColor, Dimensions, Gap, ObjectFit, Padding};
+use crate::testing::{TestHarness, assert_render_snapshot};
 use crate::tests::assert_rect_approx_eq;
-use crate::widgets::Button;
+use crate::theme::test_property_set;
+use crate::widgets::{Button, Flex, SizedBox};
 
 #[test]
 fn widget_new_properties() {
@@ -60,3 +62,36 @@ fn object_fit_affine_contain_handles_negative_origins() {
         Rect::new(-5., -20., 45., 30.),
     );
 }
+
+#[test]
+fn generic_widget_mut_properties() {
+    let target = WidgetTag::named("target");
+    let child = SizedBox::empty()
+        .size(40.px(), 40.px())
+        .prepare()
+        .with_tag(target);
+    let widget = Flex::row().with_fixed(child).prepare();
+
+    let mut harness = TestHarness::create_with_size(test_property_set(), widget, (100, 60));
+    let target_id = harness.get_widget(target).id();
+
+    // Edit the widget without knowing its concrete type.
+    harness.edit_widget_with_id(target_id, |mut widget| {
+        assert!(!widget.contains_prop::<Background>());
+        assert_eq!(widget.insert_prop(Background::Color(RED)), None);
+        widget.edit_prop::<Padding>(|padding| padding.left = 10.px());
+        assert_eq!(widget.get_prop::<Padding>(), &Padding::left(10.px()));
+    });
+    // The padding change was picked up by layout.
+    assert_eq!(harness.get_widget(target).ctx().border_box().width(), 40.);
+    assert_eq!(harness.get_widget(target).ctx().content_box().width(), 30.);
+    assert_render_snapshot!(harness, "generic_widget_mut_background");
+
+    harness.edit_widget_with_id(target_id, |mut widget| {
+        assert_eq!(
+            widget.remove_prop::<Background>(),
+            Some(Background::Color(RED))
+        );
+        assert!(!widget.contains_prop::<Background>());
+    });
+}
@@ -0,0 +1,48 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::core::NewWidget;
+use crate::palette::css::RED;
+use crate::properties::Background;
+use crate::testing::{REFERENCED_SCREENSHOTS_LOG, TestHarness, unreferenced_screenshots};
+use crate::theme::test_property_set;
+use crate::widgets::SizedBox;
+
+#[test]
+fn bless_writes_new_snapshot() {
+    let screenshots_folder =
+        std::env::temp_dir().join(format!("masonry_bless_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&screenshots_folder);
+
+    let widget = NewWidget::new(SizedBox::empty()).with_props(Background::Color(RED));
+    let mut harness = TestHarness::create_with_size(test_property_set(), widget, (20, 20));
+
+    // Blessing writes the missing reference instead of failing.
+    harness.check_render_snapshot_in(&screenshots_folder, "blessed", false, true);
+    assert!(screenshots_folder.join("blessed.png").exists());
+    assert!(!screenshots_folder.join("blessed.new.png").exists());
+
+    // The reference now matches without blessing.
+    harness.check_render_snapshot_in(&screenshots_folder, "blessed", false, false);
+
+    std::fs::write(screenshots_folder.join("stale.png"), b"").unwrap();
+    std::fs::write(screenshots_folder.join("pending.new.png"), b"").unwrap();
+    let sequence_folder = screenshots_folder.join("sequence");
+    std::fs::create_dir_all(&sequence_folder).unwrap();
+    std::fs::write(sequence_folder.join("frame_0.png"), b"").unwrap();
+    std::fs::write(sequence_folder.join("frame_1.png"), b"").unwrap();
+    std::fs::write(
+        screenshots_folder.join(REFERENCED_SCREENSHOTS_LOG),
+        "blessed\nsequence/frame_0\n",
+    )
+    .unwrap();
+    assert_eq!(
+        unreferenced_screenshots(&screenshots_folder).unwrap(),
+        [
+            sequence_folder.join("frame_1.png"),
+            screenshots_folder.join("stale.png"),
+        ],
+    );
+
+    std::fs::remove_dir_all(&screenshots_folder).unwrap();
+}
@@ -13,7 +13,7 @@ use crate::properties::{Background, BorderColor, BorderWidth, Dimensions, Paddin
 use crate::testing::{ModularWidget, TestHarness, WrapperWidget, assert_render_snapshot};
 use crate::tests::{assert_point_approx_eq, assert_vec2_approx_eq};
 use crate::theme::test_property_set;
-use crate::widgets::{Button, ChildAlignment, Label, SizedBox, ZStack};
+use crate::widgets::{Align, Button, ChildAlignment, Label, SizedBox, ZStack};
 
 fn blue_box(inner: impl Widget) -> impl Widget {
     let mut box_props = PropertySet::new();
@@ -35,9 +35,8 @@ fn blue_box(inner: impl Widget) -> impl Widget {
 fn transforms_translation_rotation() {
     let translation = Vec2::new(100.0, 50.0);
     let transformed_widget = NewWidget::new(blue_box(Label::new("Background"))).with_transform(
-        // Currently there's no support for changing the transform-origin,
-        // which is currently at the top left.
-        // This rotates around the center of the widget
+        // This rotates around (100, 50) with the default top-left transform origin.
+        // See `transforms_origin_rotation` for a simpler way to pivot a transform.
         Affine::translate(-translation)
             .then_rotate(PI * 0.25)
             .then_translate(translation),
@@ -51,6 +50,29 @@ fn transforms_translation_rotation() {
     assert_render_snapshot!(harness, "transforms_translation_rotation");
 }
 
+#[test]
+fn transforms_origin_rotation() {
+    let tag = WidgetTag::unique();
+    let transformed_widget = NewWidget::new(blue_box(Label::new("Background")))
+        .with_tag(tag)
+        .with_transform(Affine::rotate(PI * 0.25))
+        .with_transform_origin(UnitPoint::CENTER);
+    let widget = Align::new(UnitPoint::CENTER, transformed_widget).prepare();
+
+    let mut harness = TestHarness::create_with_size(test_property_set(), widget, (200, 200));
+
+    // The center of the box stays in place, at the center of the window.
+    let widget = harness.get_widget(tag);
+    let ctx = widget.ctx();
+    assert_point_approx_eq(
+        "rotated center",
+        ctx.to_window(ctx.border_box().center()),
+        Point::new(100., 100.),
+    );
+
+    assert_render_snapshot!(harness, "transforms_origin_rotation");
+}
+
 #[test]
 fn transforms_pointer_events() {
     let transformed_widget = NewWidget::new(blue_box(
@@ -135,3 +157,38 @@ fn transforms_handle_content_box_space_translation() {
         Point::new(12., 17.5),
     );
 }
+
+#[test]
+fn transforms_to_window_to_local_round_trip() {
+    let tag = WidgetTag::unique();
+    let inner = NewWidget::new(SizedBox::empty().size(20.px(), 10.px()))
+        .with_tag(tag)
+        .with_transform(Affine::scale_non_uniform(1.5, 0.5).then_translate(Vec2::new(3., 4.)))
+        .with_props(Padding::all(2.px()));
+    let outer = NewWidget::new(SizedBox::new(inner).size(60.px(), 60.px()))
+        .with_transform(Affine::rotate(PI * 0.2).then_translate(Vec2::new(30., 10.)));
+    let widget = ZStack::new()
+        .with(outer, ChildAlignment::ParentAligned)
+        .prepare();
+
+    let harness = TestHarness::create(test_property_set(), widget);
+    let child = harness.get_widget(tag);
+    let ctx = child.ctx();
+
+    for point in [Point::ORIGIN, Point::new(7., -3.), Point::new(12.5, 9.25)] {
+        assert_point_approx_eq(
+            "to_local(to_window(point))",
+            ctx.to_local(ctx.to_window(point)),
+            point,
+        );
+        assert_point_approx_eq(
+            "to_window(to_local(point))",
+            ctx.to_window(ctx.to_local(point)),
+            point,
+        );
+    }
+
+    // The round trip must not be trivially satisfied by an identity mapping.
+    let window_point = ctx.to_window(Point::new(7., -3.));
+    assert!((window_point - Point::new(7., -3.)).hypot() > 1.);
+}
@@ -13,7 +13,7 @@ use crate::core::{
 use crate::layout::{AsUnit, Length};
 use crate::testing::{
     DebugName, ModularWidget, PRIMARY_MOUSE, Record, TestHarness, TestWidgetExt, assert_any,
-    assert_debug_panics,
+    assert_debug_panics, assert_none,
 };
 use crate::theme::test_property_set;
 use crate::widgets::{Button, Flex, Label, SizedBox, TextArea};
@@ -115,6 +115,7 @@ fn disabled_widget_gets_no_event() {
             Record::Update(Update::DisabledChanged(true)),
             Record::Update(Update::ChildFocusChanged(false)),
             Record::Update(Update::FocusChanged(false)),
+            Record::FocusChange(false),
         ]
     );
 
@@ -192,6 +193,7 @@ fn stashed_widget_loses_focus() {
             Record::Update(Update::StashedChanged(true)),
             Record::Update(Update::ChildFocusChanged(false)),
             Record::Update(Update::FocusChanged(false)),
+            Record::FocusChange(false),
         ]
     );
 
@@ -482,6 +484,46 @@ fn remove_focusable() {
 
 // FOCUS
 
+#[test]
+fn focus_change_hook() {
+    let (sender, receiver) = mpsc::channel::<(&'static str, bool)>();
+    let focusable = |name: &'static str| {
+        let sender = sender.clone();
+        NewWidget::new(ModularWidget::new(()).accepts_focus(true).focus_change_fn(
+            move |_, _, _, focused| {
+                sender.send((name, focused)).unwrap();
+            },
+        ))
+    };
+    let tag_a = WidgetTag::named("a");
+    let tag_b = WidgetTag::named("b");
+    let parent = ModularWidget::new_multi_parent(vec![
+        focusable("a").with_tag(tag_a).erased(),
+        focusable("b").with_tag(tag_b).erased(),
+    ])
+    .prepare();
+
+    let mut harness = TestHarness::create(test_property_set(), parent);
+    let id_a = harness.get_widget(tag_a).id();
+    let id_b = harness.get_widget(tag_b).id();
+
+    harness.focus_on(Some(id_a));
+    assert_eq!(receiver.try_iter().collect::<Vec<_>>(), [("a", true)]);
+
+    // Focusing the same widget again isn't a transition.
+    harness.focus_on(Some(id_a));
+    assert!(receiver.try_recv().is_err());
+
+    harness.focus_on(Some(id_b));
+    assert_eq!(
+        receiver.try_iter().collect::<Vec<_>>(),
+        [("a", false), ("b", true)]
+    );
+
+    harness.focus_on(None);
+    assert_eq!(receiver.try_iter().collect::<Vec<_>>(), [("b", false)]);
+}
+
 #[test]
 fn ime_commit() {
     let textbox_tag = WidgetTag::named("textbox");
@@ -680,6 +722,71 @@ fn change_hovered_when_widget_changes() {
     assert!(!harness.get_widget(parent_tag).ctx().is_hovered());
 }
 
+#[test]
+fn hovered_changed_events() {
+    const BOX_SIZE: Length = Length::const_px(50.);
+    const PARENT_SIZE: Length = Length::const_px(100.);
+
+    let child_tag = WidgetTag::named("child");
+
+    let child = NewWidget::new(
+        ModularWidget::new(BOX_SIZE)
+            .measure_fn(|size, _, _, _, _, _| *size)
+            .record(),
+    )
+    .with_tag(child_tag);
+    let parent =
+        NewWidget::new(ModularWidget::new_parent(child).measure_fn(|_, _, _, _, _, _| PARENT_SIZE));
+
+    let mut harness = TestHarness::create(test_property_set(), parent);
+    harness.flush_records_of(child_tag);
+
+    // Move the mouse onto the child
+    harness.mouse_move((25., 25.));
+    assert!(harness.get_widget(child_tag).ctx().is_hovered());
+    let records = harness.take_records_of(child_tag);
+    assert_any(records, |r| {
+        matches!(r, Record::Update(Update::HoveredChanged(true)))
+    });
+
+    // Moving within the child doesn't change its hovered status
+    harness.mouse_move((30., 30.));
+    let records = harness.take_records_of(child_tag);
+    assert_none(records, |r| {
+        matches!(r, Record::Update(Update::HoveredChanged(_)))
+    });
+
+    // Move the mouse off the child
+    harness.mouse_move((75., 75.));
+    assert!(!harness.get_widget(child_tag).ctx().is_hovered());
+    let records = harness.take_records_of(child_tag);
+    assert_any(records, |r| {
+        matches!(r, Record::Update(Update::HoveredChanged(false)))
+    });
+
+    // Grow the child under the stationary mouse
+    harness.edit_widget(child_tag, |mut child| {
+        child.widget.inner_mut().state = PARENT_SIZE;
+        child.ctx.request_layout();
+    });
+    assert!(harness.get_widget(child_tag).ctx().is_hovered());
+    let records = harness.take_records_of(child_tag);
+    assert_any(records, |r| {
+        matches!(r, Record::Update(Update::HoveredChanged(true)))
+    });
+
+    // Shrink it back
+    harness.edit_widget(child_tag, |mut child| {
+        child.widget.inner_mut().state = BOX_SIZE;
+        child.ctx.request_layout();
+    });
+    assert!(!harness.get_widget(child_tag).ctx().is_hovered());
+    let records = harness.take_records_of(child_tag);
+    assert_any(records, |r| {
+        matches!(r, Record::Update(Update::HoveredChanged(false)))
+    });
+}
+
 // STATUS FLAGS
 
 fn make_reporter_parent(
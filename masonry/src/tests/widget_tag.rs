@@ -3,9 +3,9 @@
 
 use masonry_testing::{TestHarness, assert_debug_panics};
 
-use crate::core::{NewWidget, WidgetTag};
+use crate::core::{NewWidget, Widget, WidgetId, WidgetRef, WidgetTag};
 use crate::theme::test_property_set;
-use crate::widgets::{Flex, SizedBox};
+use crate::widgets::{Flex, Label, SizedBox};
 
 #[test]
 fn duplicate_widget_tag() {
@@ -24,3 +24,32 @@ fn duplicate_widget_tag() {
         "already exists in the widget tree"
     );
 }
+
+#[test]
+fn deterministic_widget_ids() {
+    fn collect_ids(widget: WidgetRef<'_, dyn Widget>, ids: &mut Vec<WidgetId>) {
+        ids.push(widget.id());
+        for child in widget.children() {
+            collect_ids(child, ids);
+        }
+    }
+    fn tree_ids() -> Vec<WidgetId> {
+        let harness = TestHarness::create_with_deterministic_ids(test_property_set(), || {
+            Flex::row()
+                .with_fixed(SizedBox::empty().prepare())
+                .with_fixed(Label::new("hello").prepare())
+                .prepare()
+        });
+        let mut ids = Vec::new();
+        collect_ids(harness.get_widget_with_id(harness.root_id()), &mut ids);
+        ids
+    }
+
+    let first = tree_ids();
+    // Allocate a regular id in between, which mustn't shift the scoped ones.
+    let _ = SizedBox::empty().prepare();
+    let second = tree_ids();
+
+    assert_eq!(first.len(), 3);
+    assert_eq!(first, second);
+}
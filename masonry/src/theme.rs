@@ -61,7 +61,7 @@ pub fn default_property_set() -> DefaultProperties {
     // Badge
     properties.insert::<Badge, _>(Padding::from_vh(3.px(), 5.px()));
     properties.insert::<Badge, _>(CornerRadius { radius: 999.px() });
-    properties.insert::<Badge, _>(BorderWidth { width: 0.px() });
+    properties.insert::<Badge, _>(BorderWidth::all(0.px()));
     properties.insert::<Badge, _>(Background::Color(ACCENT_COLOR));
     properties.insert::<Badge, _>(BorderColor { color: ZYNC_700 });
     {
@@ -76,9 +76,7 @@ pub fn default_property_set() -> DefaultProperties {
     // Button
     properties.insert::<Button, _>(Padding::from_vh(6.px(), 16.px()));
     properties.insert::<Button, _>(CornerRadius { radius: 6.px() });
-    properties.insert::<Button, _>(BorderWidth {
-        width: BORDER_WIDTH,
-    });
+    properties.insert::<Button, _>(BorderWidth::all(BORDER_WIDTH));
     properties.insert::<Button, _>(Background::Color(ZYNC_800));
     properties.insert::<Button, _>(BorderColor { color: ZYNC_700 });
     {
@@ -104,9 +102,7 @@ pub fn default_property_set() -> DefaultProperties {
 
     // Checkbox
     properties.insert::<Checkbox, _>(CornerRadius { radius: 4.px() });
-    properties.insert::<Checkbox, _>(BorderWidth {
-        width: BORDER_WIDTH,
-    });
+    properties.insert::<Checkbox, _>(BorderWidth::all(BORDER_WIDTH));
     properties.insert::<Checkbox, _>(Background::Color(ZYNC_800));
     properties.insert::<Checkbox, _>(BorderColor { color: ZYNC_700 });
     properties.insert::<Checkbox, _>(CheckmarkStrokeWidth { width: 2.0 });
@@ -149,9 +145,7 @@ pub fn default_property_set() -> DefaultProperties {
 
     // Switch
     properties.insert::<Switch, _>(CornerRadius { radius: 10.px() }); // Full pill shape
-    properties.insert::<Switch, _>(BorderWidth {
-        width: BORDER_WIDTH,
-    });
+    properties.insert::<Switch, _>(BorderWidth::all(BORDER_WIDTH));
     properties.insert::<Switch, _>(Background::Color(ZYNC_700));
     properties.insert::<Switch, _>(BorderColor { color: ZYNC_700 });
     properties.insert::<Switch, _>(ThumbColor(Color::WHITE));
@@ -188,9 +182,7 @@ pub fn default_property_set() -> DefaultProperties {
     // Selector
     properties.insert::<SelectorButton, _>(Padding::from_vh(6.px(), 16.px()));
     properties.insert::<SelectorButton, _>(CornerRadius { radius: 2.px() });
-    properties.insert::<SelectorButton, _>(BorderWidth {
-        width: BORDER_WIDTH,
-    });
+    properties.insert::<SelectorButton, _>(BorderWidth::all(BORDER_WIDTH));
 
     properties.insert::<SelectorButton, _>(Background::Color(ZYNC_800));
     properties.insert::<SelectorButton, _>(BorderColor { color: ZYNC_700 });
@@ -240,9 +232,7 @@ pub fn default_property_set() -> DefaultProperties {
     // TextInput
     properties.insert::<TextInput, _>(Padding::from_vh(6.px(), 12.px()));
     properties.insert::<TextInput, _>(CornerRadius { radius: 4.px() });
-    properties.insert::<TextInput, _>(BorderWidth {
-        width: BORDER_WIDTH,
-    });
+    properties.insert::<TextInput, _>(BorderWidth::all(BORDER_WIDTH));
     properties.insert::<TextInput, _>(BorderColor { color: ZYNC_600 });
     properties.insert::<TextInput, _>(PlaceholderColor::new(PLACEHOLDER_COLOR));
     properties.insert::<TextInput, _>(CaretColor { color: TEXT_COLOR });
@@ -299,6 +289,9 @@ pub fn default_property_set() -> DefaultProperties {
 
     // Label
     properties.insert::<Label, _>(ContentColor::new(TEXT_COLOR));
+    properties.insert::<Label, _>(SelectionColor {
+        color: ACCENT_COLOR,
+    });
     {
         let mut stack = PropertyStack::new();
         stack.push(
@@ -310,18 +303,14 @@ pub fn default_property_set() -> DefaultProperties {
 
     // ProgressBar
     properties.insert::<ProgressBar, _>(CornerRadius { radius: 2.px() });
-    properties.insert::<ProgressBar, _>(BorderWidth {
-        width: BORDER_WIDTH,
-    });
+    properties.insert::<ProgressBar, _>(BorderWidth::all(BORDER_WIDTH));
 
     properties.insert::<ProgressBar, _>(Background::Color(ZYNC_900));
     properties.insert::<ProgressBar, _>(BorderColor { color: ZYNC_800 });
     properties.insert::<ProgressBar, _>(BarColor(ACCENT_COLOR));
 
     // RadioButton
-    properties.insert::<RadioButton, _>(BorderWidth {
-        width: BORDER_WIDTH,
-    });
+    properties.insert::<RadioButton, _>(BorderWidth::all(BORDER_WIDTH));
 
     properties.insert::<RadioButton, _>(Background::Color(ZYNC_800));
     properties.insert::<RadioButton, _>(BorderColor { color: ZYNC_700 });
@@ -400,9 +389,7 @@ pub fn default_text_styles(styles: &mut StyleSet) {
 fn default_step_input_style<T: Steppable>(properties: &mut DefaultProperties) {
     properties.insert::<StepInput<T>, _>(Padding::from_vh(6.px(), 0.px()));
     properties.insert::<StepInput<T>, _>(CornerRadius { radius: 6.px() });
-    properties.insert::<StepInput<T>, _>(BorderWidth {
-        width: BORDER_WIDTH,
-    });
+    properties.insert::<StepInput<T>, _>(BorderWidth::all(BORDER_WIDTH));
 
     properties.insert::<StepInput<T>, _>(ContentColor::new(TEXT_COLOR));
     properties.insert::<StepInput<T>, _>(Background::Color(ZYNC_800));
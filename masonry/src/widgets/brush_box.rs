@@ -0,0 +1,186 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::{
+    AccessCtx, ChildrenIds, LayoutCtx, MeasureCtx, NoAction, PaintCtx, PropertiesRef, Property,
+    RegisterCtx, UpdateCtx, UsesProperty, Widget, WidgetId, WidgetMut,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Size};
+use crate::layout::{LenReq, Length, UnitPoint};
+use crate::peniko::Brush;
+use crate::properties::ObjectFit;
+
+/// A widget that paints a procedural [`Brush`], such as a gradient, as its content.
+///
+/// The brush is defined in a coordinate space of the given natural size,
+/// e.g. a radial gradient centered at `(50, 50)` with a natural size of 100x100.
+/// That space is fit to the content box with the [`ObjectFit`] property and
+/// [alignment](Self::with_alignment), like the data of an [`Image`](super::Image),
+/// so the brush scales along with the widget.
+///
+/// The natural size is also what the widget reports as its preferred size.
+///
+/// The widget is decorative, so it is hidden from assistive technologies.
+pub struct BrushBox {
+    brush: Brush,
+    natural_size: Size,
+    alignment: UnitPoint,
+}
+
+// --- MARK: BUILDERS
+impl BrushBox {
+    /// Creates a widget painting `brush`, which is defined in a space of `natural_size`.
+    pub fn new(brush: impl Into<Brush>, natural_size: Size) -> Self {
+        Self {
+            brush: brush.into(),
+            natural_size,
+            alignment: UnitPoint::CENTER,
+        }
+    }
+
+    /// Builder-style method to set the alignment of the brush within the content box.
+    ///
+    /// This applies when the [`ObjectFit`] leaves some space around the brush,
+    /// or makes it overflow.
+    /// The default is [`UnitPoint::CENTER`].
+    pub fn with_alignment(mut self, alignment: UnitPoint) -> Self {
+        self.alignment = alignment;
+        self
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl BrushBox {
+    /// Sets the painted brush.
+    pub fn set_brush(this: &mut WidgetMut<'_, Self>, brush: impl Into<Brush>) {
+        this.widget.brush = brush.into();
+        this.ctx.request_paint_only();
+    }
+
+    /// Sets the natural size, which the brush is defined in.
+    pub fn set_natural_size(this: &mut WidgetMut<'_, Self>, natural_size: Size) {
+        this.widget.natural_size = natural_size;
+        this.ctx.request_layout();
+    }
+
+    /// Sets the alignment of the brush within the content box.
+    ///
+    /// See [`with_alignment`](Self::with_alignment) for details.
+    pub fn set_alignment(this: &mut WidgetMut<'_, Self>, alignment: UnitPoint) {
+        this.widget.alignment = alignment;
+        this.ctx.request_paint_only();
+    }
+}
+
+impl UsesProperty<ObjectFit> for BrushBox {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for BrushBox {
+    type Action = NoAction;
+
+    fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {}
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        if ObjectFit::matches(property_type) {
+            ctx.request_layout();
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        let cache = ctx.property_cache();
+        let object_fit = props.get::<ObjectFit>(cache);
+
+        object_fit.measure(axis, len_req, cross_length, self.natural_size)
+    }
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, _size: Size) {}
+
+    fn paint(
+        &mut self,
+        ctx: &mut PaintCtx<'_>,
+        props: &PropertiesRef<'_>,
+        painter: &mut Painter<'_>,
+    ) {
+        let content_box = ctx.content_box();
+        if content_box.is_zero_area() {
+            return;
+        }
+
+        let cache = ctx.property_cache();
+        let object_fit = props.get::<ObjectFit>(cache);
+        let natural_rect = self.natural_size.to_rect();
+        let transform = object_fit.affine_aligned(content_box, natural_rect, self.alignment);
+
+        painter.with_fill_clip(content_box, |painter| {
+            painter
+                .fill(natural_rect, &self.brush)
+                .transform(transform)
+                .draw();
+        });
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Image
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_hidden();
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::new()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("BrushBox", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::NewWidget;
+    use crate::palette;
+    use crate::peniko::Gradient;
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+
+    #[test]
+    fn radial_gradient_stretched() {
+        let gradient = Gradient::new_radial((50., 50.), 50.).with_stops([
+            palette::css::WHITE,
+            palette::css::ORANGE,
+            palette::css::DARK_BLUE,
+        ]);
+        let widget = NewWidget::new(BrushBox::new(gradient, Size::new(100., 100.)))
+            .with_props(ObjectFit::Stretch);
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (200, 100));
+        assert_render_snapshot!(harness, "brush_box_radial_stretched");
+
+        // With the default fit, the gradient keeps its aspect ratio and is centered.
+        harness.edit_root_widget(|mut brush_box| {
+            brush_box.insert_prop(ObjectFit::Contain);
+        });
+        assert_render_snapshot!(harness, "brush_box_radial_contain");
+    }
+}
@@ -310,6 +310,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn disabled_button() {
+        let widget = Button::with_text("Hello").prepare().disabled(true);
+        let mut harness = TestHarness::create(test_property_set(), widget);
+        let button_id = harness.root_id();
+
+        let node = harness.access_node(button_id).unwrap();
+        assert!(node.data().is_disabled());
+
+        // Neither pointer nor accessibility clicks reach the button.
+        harness.mouse_click_on(button_id, Some(PointerButton::Primary));
+        harness.accessibility_click_on(button_id);
+        assert!(harness.pop_action_erased().is_none());
+
+        harness.edit_root_widget(|mut button| {
+            button.ctx.set_disabled(false);
+        });
+        let _ = harness.render();
+        let node = harness.access_node(button_id).unwrap();
+        assert!(!node.data().is_disabled());
+
+        harness.accessibility_click_on(button_id);
+        assert_eq!(
+            harness.pop_action(),
+            Some((ButtonPress { button: None }, button_id))
+        );
+    }
+
     #[test]
     fn mouse_down_requests_focus() {
         let widget = NewWidget::new(Button::with_text("Hello"));
@@ -369,7 +397,7 @@ mod tests {
 
         harness.edit_root_widget(|mut button| {
             button.insert_prop(BorderColor { color: red });
-            button.insert_prop(BorderWidth { width: 5.px() });
+            button.insert_prop(BorderWidth::all(5.px()));
             button.insert_prop(CornerRadius { radius: 20.px() });
             button.insert_prop(Padding::from_vh(3.px(), 8.px()));
 
@@ -454,6 +482,36 @@ mod tests {
         assert_failing_render_snapshot!(harness, "button_shadows");
     }
 
+    #[test]
+    fn with_soft_and_inset_shadows() {
+        use crate::palette::css::BLACK;
+
+        let root_widget = Flex::row()
+            .with_fixed(
+                Button::with_text("Drop")
+                    .prepare()
+                    .with_props(BoxShadow::new(BLACK.with_alpha(0.6), (4., 4.)).blur(6.px())),
+            )
+            .with_fixed(
+                Button::with_text("Inset").prepare().with_props(
+                    BoxShadow::new(BLACK.with_alpha(0.6), (3., 3.))
+                        .blur(4.px())
+                        .inset(true),
+                ),
+            )
+            .prepare()
+            .with_props(
+                PropertySet::new()
+                    .with(Padding::all(20.px()))
+                    .with(Gap::new(40.px())),
+            );
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), root_widget, (300, 100));
+
+        assert_render_snapshot!(harness, "button_soft_and_inset_shadows");
+    }
+
     /// We document that several other non-interactive widgets in Masonry can be correctly used as children in buttons.
     ///
     /// We validate that each of these actually are correctly supported.
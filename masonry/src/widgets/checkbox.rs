@@ -7,12 +7,12 @@ use accesskit::{Node, Role, Toggled};
 use include_doc_path::include_doc_path;
 use tracing::{Span, trace, trace_span};
 
-use crate::core::keyboard::Key;
+use crate::core::keyboard::{Key, NamedKey};
 use crate::core::{
     AccessCtx, AccessEvent, ArcStr, ChildrenIds, EventCtx, LayoutCtx, MeasureCtx, NewWidget,
     PaintCtx, PointerEvent, PrePaintProps, PropertiesMut, PropertiesRef, Property, RegisterCtx,
     TextEvent, Update, UpdateCtx, UsesProperty, Widget, WidgetId, WidgetMut, WidgetPod,
-    paint_background, paint_box_shadow,
+    paint_background, paint_box_shadow, paint_inset_shadow,
 };
 use crate::imaging::Painter;
 use crate::kurbo::{Axis, BezPath, Cap, Dashes, Join, Point, Size, Stroke};
@@ -124,10 +124,12 @@ impl Widget for Checkbox {
         event: &TextEvent,
     ) {
         match event {
-            TextEvent::Keyboard(event) if event.state.is_up() => {
-                if matches!(&event.key, Key::Character(c) if c == " ") {
-                    ctx.submit_action::<Self::Action>(CheckboxToggled(!self.checked));
-                }
+            TextEvent::Keyboard(event)
+                if event.state.is_up()
+                    && (matches!(&event.key, Key::Character(c) if c == " ")
+                        || event.key == Key::Named(NamedKey::Enter)) =>
+            {
+                ctx.submit_action::<Self::Action>(CheckboxToggled(!self.checked));
             }
             _ => (),
         }
@@ -249,8 +251,9 @@ impl Widget for Checkbox {
         let cache = ctx.property_cache();
         let p = PrePaintProps::fetch(props, cache);
 
-        paint_box_shadow(painter, bbox, p.box_shadow, p.corner_radius);
+        paint_box_shadow(painter, bbox, p.box_shadow, p.box_shadows, p.corner_radius);
         paint_background(painter, bbox, p.background, p.border_width, p.corner_radius);
+        paint_inset_shadow(painter, bbox, p.box_shadow, p.box_shadows, p.corner_radius);
 
         // Paint focus indicator around the entire widget (box + label)
         if ctx.is_focus_target() || ctx.is_hovered() {
@@ -297,7 +300,7 @@ impl Widget for Checkbox {
         let border_color = *props.get::<BorderColor>(cache);
 
         // Paint the checkbox box border
-        let border_stroke = Stroke::new(border_width.width.get()).with_join(Join::Miter);
+        let border_stroke = Stroke::new(border_width.max_width().get()).with_join(Join::Miter);
         painter
             .stroke(border_rect, &border_stroke, border_color.color)
             .draw();
@@ -406,6 +409,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn checkbox_keyboard_and_accessibility() {
+        let widget = NewWidget::new(Checkbox::new(false, "Hello"));
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (100, 40));
+        let checkbox_id = harness.root_id();
+
+        let node = harness.access_node(checkbox_id).unwrap();
+        assert_eq!(node.role(), Role::CheckBox);
+        assert_eq!(node.data().toggled(), Some(Toggled::False));
+
+        harness.focus_on(Some(checkbox_id));
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::Enter)));
+        harness.process_text_event(TextEvent::key_up(Key::Named(NamedKey::Enter)));
+        assert_eq!(
+            harness.pop_action::<CheckboxToggled>(),
+            Some((CheckboxToggled(true), checkbox_id))
+        );
+
+        harness.edit_root_widget(|mut checkbox| Checkbox::set_checked(&mut checkbox, true));
+        let _ = harness.render();
+        let node = harness.access_node(checkbox_id).unwrap();
+        assert_eq!(node.data().toggled(), Some(Toggled::True));
+
+        harness.accessibility_click_on(checkbox_id);
+        assert_eq!(
+            harness.pop_action::<CheckboxToggled>(),
+            Some((CheckboxToggled(false), checkbox_id))
+        );
+    }
+
     #[test]
     fn checkbox_focus_indicator() {
         use crate::properties::types::MainAxisAlignment;
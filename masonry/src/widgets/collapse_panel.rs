@@ -144,7 +144,7 @@ impl Widget for CollapsePanel {
         let btn_length = BUTTON_LENGTH;
 
         let separator_height = border
-            .width
+            .max_width()
             .saturating_add(SEPARATOR_PAD.length(Axis::Vertical));
 
         let space: LenDef = len_req.into();
@@ -229,7 +229,7 @@ impl Widget for CollapsePanel {
         let border = props.get::<BorderWidth>(cache);
         let header_x_padding = theme::WIDGET_CONTROL_COMPONENT_PADDING;
 
-        let border_width = border.width.get();
+        let border_width = border.max_width().get();
         let separator_height = border_width + SEPARATOR_PAD.length(Axis::Vertical).get();
 
         let button_width = BUTTON_LENGTH.get();
@@ -315,7 +315,7 @@ impl Widget for CollapsePanel {
                 painter
                     .stroke(
                         line,
-                        &Stroke::new(border_width.width.get()),
+                        &Stroke::new(border_width.max_width().get()),
                         border_color.color,
                     )
                     .draw();
@@ -9,7 +9,7 @@ use crate::core::keyboard::{Key, NamedKey};
 use crate::core::{
     AccessCtx, AccessEvent, ChildrenIds, EventCtx, LayoutCtx, MeasureCtx, NoAction, PaintCtx,
     PointerEvent, PropertiesMut, PropertiesRef, RegisterCtx, TextEvent, Update, UpdateCtx, Widget,
-    WidgetMut,
+    WidgetMut, set_expanded,
 };
 use crate::imaging::Painter;
 use crate::kurbo::{Affine, Axis, BezPath, Join, Size, Stroke};
@@ -50,6 +50,7 @@ impl DisclosureButton {
         this.widget.is_disclosed = is_disclosed;
 
         this.ctx.request_layout();
+        this.ctx.request_accessibility_update();
     }
 
     #[inline]
@@ -81,6 +82,7 @@ impl Widget for DisclosureButton {
             {
                 self.switch_disclosed_state();
                 ctx.request_layout();
+                ctx.request_accessibility_update();
 
                 // TODO: Submit actions?
             }
@@ -102,6 +104,7 @@ impl Widget for DisclosureButton {
             {
                 self.switch_disclosed_state();
                 ctx.request_layout();
+                ctx.request_accessibility_update();
 
                 // TODO: Submit actions?
             }
@@ -119,11 +122,19 @@ impl Widget for DisclosureButton {
             return;
         }
 
-        if event.action == accesskit::Action::Click {
-            self.switch_disclosed_state();
+        let is_disclosed = match event.action {
+            accesskit::Action::Click => !self.is_disclosed,
+            accesskit::Action::Expand => true,
+            accesskit::Action::Collapse => false,
+            _ => return,
+        };
+        if is_disclosed != self.is_disclosed {
+            self.is_disclosed = is_disclosed;
             ctx.request_layout();
+            ctx.request_accessibility_update();
             // TODO: Submit actions?
         }
+        ctx.set_handled();
     }
 
     fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {}
@@ -207,8 +218,9 @@ impl Widget for DisclosureButton {
         &mut self,
         _ctx: &mut AccessCtx<'_>,
         _props: &PropertiesRef<'_>,
-        _node: &mut accesskit::Node,
+        node: &mut accesskit::Node,
     ) {
+        set_expanded(node, self.is_disclosed);
     }
 
     fn children_ids(&self) -> ChildrenIds {
@@ -0,0 +1,236 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::{
+    AccessCtx, ChildrenIds, CollectionWidget, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
+    PropertiesRef, RegisterCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Point, Size};
+use crate::layout::{LenReq, Length};
+use crate::widgets::IndexedStack;
+
+/// Which child of an [`Either`] widget is shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EitherChild {
+    /// Show the first child.
+    #[default]
+    First,
+    /// Show the second child.
+    Second,
+}
+
+/// A widget that displays one of two children.
+///
+/// This is an [`IndexedStack`] with exactly two children, selected with [`EitherChild`]
+/// instead of an index.
+///
+/// Only the selected child is laid out, painted, and receives events.
+/// The other child is [stashed], which means it keeps its state and its place in the
+/// widget tree, so switching between them is cheaper than removing and re-adding widgets.
+///
+/// [stashed]: crate::doc::masonry_concepts#stashed
+pub struct Either {
+    stack: WidgetPod<IndexedStack>,
+    selected: EitherChild,
+}
+
+// --- MARK: BUILDERS
+impl Either {
+    /// Creates a new `Either` showing the `first` child.
+    pub fn new(
+        first: NewWidget<impl Widget + ?Sized>,
+        second: NewWidget<impl Widget + ?Sized>,
+    ) -> Self {
+        Self::new_selected(first, second, EitherChild::First)
+    }
+
+    /// Creates a new `Either` showing the `selected` child.
+    pub fn new_selected(
+        first: NewWidget<impl Widget + ?Sized>,
+        second: NewWidget<impl Widget + ?Sized>,
+        selected: EitherChild,
+    ) -> Self {
+        let stack = IndexedStack::new()
+            .with(first)
+            .with(second)
+            .with_active_child(selected.index());
+        Self {
+            stack: WidgetPod::new(stack),
+            selected,
+        }
+    }
+}
+
+// --- MARK: METHODS
+impl Either {
+    /// Returns which child is currently shown.
+    pub fn selected(&self) -> EitherChild {
+        self.selected
+    }
+}
+
+impl EitherChild {
+    /// Returns the index of this child in the underlying [`IndexedStack`].
+    fn index(self) -> usize {
+        match self {
+            Self::First => 0,
+            Self::Second => 1,
+        }
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Either {
+    /// Sets which child is shown.
+    pub fn set_selected(this: &mut WidgetMut<'_, Self>, selected: EitherChild) {
+        if this.widget.selected != selected {
+            this.widget.selected = selected;
+            IndexedStack::set_active_child(&mut Self::stack_mut(this), selected.index());
+        }
+    }
+
+    /// Replaces the first child with a new one.
+    pub fn set_first(this: &mut WidgetMut<'_, Self>, child: NewWidget<impl Widget + ?Sized>) {
+        IndexedStack::set(&mut Self::stack_mut(this), 0, child, ());
+    }
+
+    /// Replaces the second child with a new one.
+    pub fn set_second(this: &mut WidgetMut<'_, Self>, child: NewWidget<impl Widget + ?Sized>) {
+        IndexedStack::set(&mut Self::stack_mut(this), 1, child, ());
+    }
+
+    fn stack_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, IndexedStack> {
+        this.ctx.get_mut(&mut this.widget.stack)
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Either {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.stack);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        ctx.redirect_measurement(&mut self.stack, axis, cross_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.run_layout(&mut self.stack, size);
+        ctx.place_child(&mut self.stack, Point::ORIGIN);
+        ctx.derive_baselines(&self.stack);
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.stack.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Either", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::WidgetTag;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+    use crate::widgets::Label;
+
+    #[test]
+    fn toggle_preserves_inactive_state() {
+        let first_tag = WidgetTag::unique();
+        let second_tag = WidgetTag::unique();
+
+        let widget = Either::new(
+            Label::new("First").prepare().with_tag(first_tag),
+            Label::new("Second").prepare().with_tag(second_tag),
+        )
+        .prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (100, 40));
+        let first_id = harness.get_widget(first_tag).id();
+
+        assert!(!harness.get_widget(first_tag).ctx().is_stashed());
+        assert!(harness.get_widget(second_tag).ctx().is_stashed());
+
+        harness.edit_root_widget(|mut either| {
+            Either::set_selected(&mut either, EitherChild::Second);
+        });
+        assert!(harness.get_widget(first_tag).ctx().is_stashed());
+        assert!(!harness.get_widget(second_tag).ctx().is_stashed());
+
+        // Modify the inactive child, which should keep the change while hidden.
+        harness.edit_widget(first_tag, |mut label| {
+            Label::set_text(&mut label, "Edited");
+        });
+
+        harness.edit_root_widget(|mut either| {
+            Either::set_selected(&mut either, EitherChild::First);
+        });
+        let first = harness.get_widget(first_tag);
+        assert!(!first.ctx().is_stashed());
+        assert_eq!(first.id(), first_id);
+        assert_eq!(first.text().as_ref(), "Edited");
+        assert!(harness.get_widget(second_tag).ctx().is_stashed());
+    }
+
+    #[test]
+    fn initial_selection() {
+        let first_tag = WidgetTag::unique();
+        let second_tag = WidgetTag::unique();
+
+        let widget = Either::new_selected(
+            Label::new("First").prepare().with_tag(first_tag),
+            Label::new("Second").prepare().with_tag(second_tag),
+            EitherChild::Second,
+        )
+        .prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (100, 40));
+        assert_eq!(harness.root_widget().selected(), EitherChild::Second);
+        assert!(harness.get_widget(first_tag).ctx().is_stashed());
+        assert!(!harness.get_widget(second_tag).ctx().is_stashed());
+
+        // Replacing a child keeps the selection.
+        let new_tag = WidgetTag::unique();
+        harness.edit_root_widget(|mut either| {
+            Either::set_second(&mut either, Label::new("New").prepare().with_tag(new_tag));
+        });
+        assert!(!harness.get_widget(new_tag).ctx().is_stashed());
+    }
+}
@@ -15,7 +15,7 @@ use crate::imaging::Painter;
 use crate::kurbo::{Axis, Size};
 use crate::layout::{AsUnit, LayoutSize, LenDef, LenReq, Length};
 use crate::properties::Gap;
-use crate::properties::types::{CrossAxisAlignment, MainAxisAlignment};
+use crate::properties::types::{CrossAxisAlignment, EdgeSpacing, MainAxisAlignment};
 use crate::util::Sanitize;
 
 /// A container with either horizontal or vertical layout.
@@ -42,6 +42,10 @@ use crate::util::Sanitize;
 /// There is currently no fine-grained support for flex grow or flex shrink.
 /// Instead every child gets their size decided in one shot, as described above.
 ///
+/// Uniform spacing between adjacent children is set with the [`Gap`] property,
+/// which doesn't require adding spacer children.
+/// It's not added before the first child or after the last one.
+///
 #[doc = concat!(
     "![Flex column with multiple labels](",
     include_doc_path!("screenshots/flex_col_main_axis_spaceAround.png"),
@@ -51,6 +55,10 @@ pub struct Flex {
     direction: Axis,
     cross_alignment: CrossAxisAlignment,
     main_alignment: MainAxisAlignment,
+    edge_spacing: EdgeSpacing,
+    equal_sizes: bool,
+    reversed: bool,
+    baseline_group: bool,
     children: Vec<Child>,
 }
 
@@ -72,6 +80,33 @@ pub enum FlexBasis {
     Auto,
     /// Always use a zero basis for the child, regardless of its sizing wishes.
     Zero,
+    /// A percentage of the container's main axis space, with gaps already subtracted.
+    ///
+    /// The percentage is resolved before any flex space is distributed,
+    /// so flexible children only share what remains after fixed and percentage children.
+    ///
+    /// When the container's main axis space isn't known yet, e.g. when measuring
+    /// its content size, this behaves like [`FlexBasis::Auto`].
+    Percent(f64),
+}
+
+/// Whether a [`Flex`] child widget is shown, and whether it keeps its space when it isn't.
+///
+/// Set with [`Flex::set_child_visibility`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum FlexVisibility {
+    /// The child is laid out and painted normally.
+    #[default]
+    Visible,
+    /// The child is not painted, but its slot stays reserved.
+    ///
+    /// The slot keeps the size the child had when it was hidden,
+    /// so the surrounding children don't move.
+    Hidden,
+    /// The child is not painted and takes up no space, as if it had been removed.
+    ///
+    /// No gap is added around collapsed children.
+    Collapsed,
 }
 
 /// Optional parameters for an item in a [`Flex`] container (row or column).
@@ -115,6 +150,9 @@ enum Child {
         alignment: Option<CrossAxisAlignment>,
         flex: f64,
         basis: Option<FlexBasis>,
+        visibility: FlexVisibility,
+        /// The size reserved for a hidden widget, captured when it was hidden.
+        hidden_size: Option<Size>,
         /// Ephemeral resolved basis.
         ///
         /// It is a logic error to read this value before writing to it in the same method.
@@ -143,6 +181,10 @@ impl Flex {
             children: Vec::new(),
             cross_alignment: CrossAxisAlignment::Center,
             main_alignment: MainAxisAlignment::Start,
+            edge_spacing: EdgeSpacing::Both,
+            equal_sizes: false,
+            reversed: false,
+            baseline_group: false,
         }
     }
 
@@ -173,6 +215,55 @@ impl Flex {
         self
     }
 
+    /// Builder-style method for specifying the [`EdgeSpacing`] of evenly distributed children.
+    ///
+    /// This only has an effect with [`MainAxisAlignment::SpaceEvenly`]
+    /// and [`MainAxisAlignment::SpaceAround`].
+    pub fn edge_spacing(mut self, edge_spacing: EdgeSpacing) -> Self {
+        self.edge_spacing = edge_spacing;
+        self
+    }
+
+    /// Builder-style method for making all inflexible children the same main axis length.
+    ///
+    /// When enabled, every widget child with a zero flex factor gets the largest
+    /// of their resolved bases, capped so that they all fit in the available space.
+    /// This is useful for e.g. toolbars with equal-width buttons.
+    pub fn equal_sizes(mut self, equal_sizes: bool) -> Self {
+        self.equal_sizes = equal_sizes;
+        self
+    }
+
+    /// Builder-style method for placing the children in reverse order along the main axis.
+    ///
+    /// When enabled, a row is laid out from right to left and a column from bottom to top,
+    /// e.g. for a chat log which grows upward.
+    /// The [`MainAxisAlignment`] is reversed as well, so `Start` packs children at the end.
+    ///
+    /// Only the placement is reversed: the children keep their logical order,
+    /// which is used for accessibility and focus navigation.
+    pub fn reversed(mut self, reversed: bool) -> Self {
+        self.reversed = reversed;
+        self
+    }
+
+    /// Builder-style method for making the children of a column a baseline group.
+    ///
+    /// When enabled, a column moves each child down so that the first baselines of all its
+    /// children are at the same distance from the top of their slot, which is the largest one.
+    /// This is useful to align e.g. the labels of a form laid out in several rows,
+    /// even if they have different font sizes.
+    ///
+    /// The extra space above the children isn't known before they're laid out,
+    /// so like with [`CrossAxisAlignment::FirstBaseline`] it isn't measured,
+    /// and the children can overflow the column if it isn't given enough space.
+    ///
+    /// This has no effect on rows.
+    pub fn baseline_group(mut self, baseline_group: bool) -> Self {
+        self.baseline_group = baseline_group;
+        self
+    }
+
     /// Builder-style variant of [`Flex::add_fixed`].
     ///
     /// Convenient for assembling a group of widgets in a single expression.
@@ -193,6 +284,28 @@ impl Flex {
         self
     }
 
+    /// Builder-style method to add a child which takes up `percent` of the available space.
+    ///
+    /// The child gets exactly that main axis length, see [`FlexBasis::Percent`].
+    ///
+    /// The `percent` must be finite and non-negative.
+    /// Non-finite or negative percentage will fall back to zero with a logged warning.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `percent` is non-finite or negative and debug assertions are enabled.
+    pub fn with_child_percent(
+        mut self,
+        child: NewWidget<impl Widget + ?Sized>,
+        percent: f64,
+    ) -> Self {
+        let percent = percent.sanitize("flex child percentage");
+        let params = FlexParams::new(0., FlexBasis::Percent(percent), None);
+        let child = new_child(params, child.erased().to_pod());
+        self.children.push(child);
+        self
+    }
+
     /// Builder-style method for adding a fixed-size spacer child to the container.
     ///
     /// A good default is [`DEFAULT_SPACER_LEN`](crate::theme::DEFAULT_SPACER_LEN).
@@ -248,6 +361,74 @@ impl Flex {
         this.ctx.request_layout();
     }
 
+    /// Sets the [`EdgeSpacing`] of evenly distributed children.
+    ///
+    /// This only has an effect with [`MainAxisAlignment::SpaceEvenly`]
+    /// and [`MainAxisAlignment::SpaceAround`].
+    pub fn set_edge_spacing(this: &mut WidgetMut<'_, Self>, edge_spacing: EdgeSpacing) {
+        this.widget.edge_spacing = edge_spacing;
+        this.ctx.request_layout();
+    }
+
+    /// Sets whether all inflexible children have the same main axis length.
+    ///
+    /// See [`equal_sizes`](Self::equal_sizes) for details.
+    pub fn set_equal_sizes(this: &mut WidgetMut<'_, Self>, equal_sizes: bool) {
+        this.widget.equal_sizes = equal_sizes;
+        this.ctx.request_layout();
+    }
+
+    /// Sets whether the children are placed in reverse order along the main axis.
+    ///
+    /// See [`reversed`](Self::reversed) for details.
+    pub fn set_reversed(this: &mut WidgetMut<'_, Self>, reversed: bool) {
+        this.widget.reversed = reversed;
+        this.ctx.request_layout();
+    }
+
+    /// Sets whether the children of this column are a baseline group.
+    ///
+    /// See [`baseline_group`](Self::baseline_group) for details.
+    pub fn set_baseline_group(this: &mut WidgetMut<'_, Self>, baseline_group: bool) {
+        this.widget.baseline_group = baseline_group;
+        this.ctx.request_layout();
+    }
+
+    /// Sets the [`FlexVisibility`] of the child widget at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    ///
+    /// Panics if `idx` contains a spacer instead of a widget.
+    pub fn set_child_visibility(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        visibility: FlexVisibility,
+    ) {
+        let Child::Widget {
+            widget,
+            visibility: old_visibility,
+            hidden_size,
+            ..
+        } = &mut this.widget.children[idx]
+        else {
+            panic!("The provided Flex idx contains a spacer");
+        };
+        if *old_visibility == visibility {
+            return;
+        }
+        *old_visibility = visibility;
+        *hidden_size = match visibility {
+            FlexVisibility::Hidden => Some(this.ctx.get_mut(widget).ctx.border_box().size()),
+            FlexVisibility::Visible | FlexVisibility::Collapsed => None,
+        };
+        // Children which aren't visible are stashed, so they're not laid out or painted.
+        this.ctx
+            .set_stashed(widget, visibility != FlexVisibility::Visible);
+        this.ctx.request_layout();
+    }
+
     /// Adds a non-flex child widget.
     ///
     /// See also [`with_fixed`].
@@ -505,13 +686,27 @@ impl CollectionWidget<FlexParams> for Flex {
                 length_resolved: Length::ZERO,
             },
         );
-        let widget = match child_val {
-            Child::Widget { widget, .. } => widget,
+        let (widget, old_visibility, old_hidden_size) = match child_val {
+            Child::Widget {
+                widget,
+                visibility,
+                hidden_size,
+                ..
+            } => (widget, visibility, hidden_size),
             Child::Spacer { .. } => {
                 panic!("Can't update flex parameters of a spacer element");
             }
         };
-        let new_child = new_child(params, widget);
+        let mut new_child = new_child(params, widget);
+        if let Child::Widget {
+            visibility,
+            hidden_size,
+            ..
+        } = &mut new_child
+        {
+            *visibility = old_visibility;
+            *hidden_size = old_hidden_size;
+        }
         *child = new_child;
         this.ctx.children_changed();
     }
@@ -622,6 +817,53 @@ impl Child {
             _ => None,
         }
     }
+
+    fn is_visible_widget(&self) -> bool {
+        matches!(
+            self,
+            Self::Widget {
+                visibility: FlexVisibility::Visible,
+                ..
+            }
+        )
+    }
+
+    fn is_collapsed(&self) -> bool {
+        matches!(
+            self,
+            Self::Widget {
+                visibility: FlexVisibility::Collapsed,
+                ..
+            }
+        )
+    }
+
+    /// Returns the size reserved for a widget which doesn't take part in measurement and layout.
+    ///
+    /// Collapsed widgets reserve nothing, and hidden widgets reserve the size they had
+    /// when they were hidden.
+    fn reserved_size(&self) -> Option<Size> {
+        match self {
+            Self::Widget {
+                visibility: FlexVisibility::Collapsed,
+                ..
+            } => Some(Size::ZERO),
+            Self::Widget {
+                visibility: FlexVisibility::Hidden,
+                hidden_size,
+                ..
+            } => *hidden_size,
+            _ => None,
+        }
+    }
+
+    /// Returns the flex factor, which is zero for widgets with a reserved size.
+    fn effective_flex(&self) -> f64 {
+        match self {
+            Self::Widget { .. } if self.reserved_size().is_some() => 0.,
+            Self::Widget { flex, .. } | Self::Spacer { flex, .. } => *flex,
+        }
+    }
 }
 
 /// Creates a new [`Child::Widget`].
@@ -632,12 +874,72 @@ fn new_child(params: impl Into<FlexParams>, child: WidgetPod<dyn Widget>) -> Chi
         alignment: params.alignment,
         flex: params.flex,
         basis: params.basis,
+        visibility: FlexVisibility::Visible,
+        hidden_size: None,
         basis_resolved: Length::ZERO,
     }
 }
 
+/// Sets the resolved basis of all inflexible widget children to their largest resolved basis.
+///
+/// The shared basis is rounded to whole pixels, so that the children stay the same
+/// length after their edges are pixel aligned.
+///
+/// If `budget` is provided, the shared basis is capped so that all of them fit in
+/// `budget` plus their previous bases, and the remaining budget is returned.
+fn equalize_bases(children: &mut [Child], budget: Option<Length>) -> Option<Length> {
+    let mut count = 0;
+    let mut bases_sum = Length::ZERO;
+    let mut max_basis = Length::ZERO;
+    for child in children.iter() {
+        if child.reserved_size().is_none()
+            && let Child::Widget {
+                flex,
+                basis_resolved,
+                ..
+            } = child
+            && *flex == 0.
+        {
+            count += 1;
+            bases_sum = bases_sum.saturating_add(*basis_resolved);
+            max_basis = max_basis.max(*basis_resolved);
+        }
+    }
+    if count == 0 {
+        return budget;
+    }
+
+    let budget = budget.map(|budget| budget.saturating_add(bases_sum));
+    let max_basis = max_basis.get().ceil().px();
+    let equal_basis = match budget {
+        Some(budget) => max_basis.min((budget.get() / count as f64).floor().px()),
+        None => max_basis,
+    };
+    for child in children.iter_mut() {
+        if child.reserved_size().is_none()
+            && let Child::Widget {
+                flex,
+                basis_resolved,
+                ..
+            } = child
+            && *flex == 0.
+        {
+            *basis_resolved = equal_basis;
+        }
+    }
+
+    budget.map(|budget| budget.saturating_sub((equal_basis.get() * count as f64).px()))
+}
+
 /// Calculates `(space_before, space_between)` from the `extra` space given the `child_count`.
-fn get_spacing(alignment: MainAxisAlignment, extra: f64, child_count: usize) -> (f64, f64) {
+///
+/// `edges` is only used for [`MainAxisAlignment::SpaceEvenly`] and [`MainAxisAlignment::SpaceAround`].
+fn get_spacing(
+    alignment: MainAxisAlignment,
+    edges: EdgeSpacing,
+    extra: f64,
+    child_count: usize,
+) -> (f64, f64) {
     let space_before;
     let space_between;
     match alignment {
@@ -662,14 +964,38 @@ fn get_spacing(alignment: MainAxisAlignment, extra: f64, child_count: usize) ->
             space_before = 0.;
             space_between = equal_space;
         }
+        MainAxisAlignment::SpaceEvenly | MainAxisAlignment::SpaceAround
+            if edges == EdgeSpacing::Neither =>
+        {
+            let equal_space = extra / (child_count - 1).max(1) as f64;
+            space_before = 0.;
+            space_between = equal_space;
+        }
         MainAxisAlignment::SpaceEvenly => {
-            let equal_space = extra / (child_count + 1) as f64;
-            space_before = equal_space;
+            // Each gap between children is one unit, and so is each included edge.
+            // `Neither` is handled above, so exactly one edge is left out otherwise.
+            let units = match edges {
+                EdgeSpacing::Both => child_count + 1,
+                _ => child_count,
+            };
+            let equal_space = extra / units as f64;
+            space_before = match edges {
+                EdgeSpacing::Both | EdgeSpacing::Leading => equal_space,
+                _ => 0.,
+            };
             space_between = equal_space;
         }
         MainAxisAlignment::SpaceAround => {
-            let equal_space = extra / (2 * child_count) as f64;
-            space_before = equal_space;
+            // Each gap between children is two units, and each included edge is one.
+            let units = match edges {
+                EdgeSpacing::Both => 2 * child_count,
+                _ => 2 * child_count - 1,
+            };
+            let equal_space = extra / units as f64;
+            space_before = match edges {
+                EdgeSpacing::Both | EdgeSpacing::Leading => equal_space,
+                _ => 0.,
+            };
             space_between = equal_space * 2.;
         }
     }
@@ -712,7 +1038,12 @@ impl Widget for Flex {
         let gap = props.get::<Gap>(cache);
 
         let gap_length = gap.gap.get();
-        let gap_count = self.children.len().saturating_sub(1);
+        let gap_count = self
+            .children
+            .iter()
+            .filter(|child| !child.is_collapsed())
+            .count()
+            .saturating_sub(1);
 
         let (main_space, cross_space) = if perp == main {
             (perp_length, None)
@@ -738,6 +1069,12 @@ impl Widget for Flex {
             let main_auto = LenDef::MaxContent;
 
             for child in &mut self.children {
+                if let Some(size) = child.reserved_size()
+                    && let Child::Widget { basis_resolved, .. } = child
+                {
+                    *basis_resolved = size.get_coord(main).px();
+                    continue;
+                }
                 match child {
                     Child::Widget {
                         widget,
@@ -760,6 +1097,22 @@ impl Widget for Flex {
                             //      then need to account for them here.
                             *basis_resolved = Length::ZERO;
                         }
+                        FlexBasis::Percent(percent) => {
+                            *basis_resolved = match main_space {
+                                Some(main_space) => {
+                                    let space =
+                                        (main_space.get() - gap_count as f64 * gap_length).max(0.);
+                                    (space * percent / 100.).px()
+                                }
+                                None => ctx.compute_length(
+                                    widget,
+                                    main_auto,
+                                    context_size,
+                                    main,
+                                    cross_space,
+                                ),
+                            };
+                        }
                     },
                     Child::Spacer {
                         basis,
@@ -770,6 +1123,10 @@ impl Widget for Flex {
                     }
                 }
             }
+
+            if self.equal_sizes {
+                equalize_bases(&mut self.children, None);
+            }
         }
 
         let mut length = Length::ZERO;
@@ -781,6 +1138,7 @@ impl Widget for Flex {
             let main_auto = len_req.into();
 
             for child in &mut self.children {
+                let is_reserved = child.reserved_size().is_some();
                 let desired_flex_fraction = match child {
                     Child::Widget {
                         widget,
@@ -788,11 +1146,12 @@ impl Widget for Flex {
                         basis,
                         ..
                     } => {
-                        if *flex > 0. {
+                        if *flex > 0. && !is_reserved {
                             match effective_basis(*basis, *flex) {
-                                FlexBasis::Auto => {
+                                FlexBasis::Auto | FlexBasis::Percent(_) => {
                                     // Auto basis is always MaxContent, so this child doesn't want
                                     // any extra flex space regardless if the request is Min or Max.
+                                    // A percentage basis is not content based, so it doesn't either.
                                     0.
                                 }
                                 FlexBasis::Zero => {
@@ -809,8 +1168,8 @@ impl Widget for Flex {
                                 }
                             }
                         } else {
-                            // Inflexible children remain at their basis size,
-                            // and don't want any extra flex space.
+                            // Inflexible children and children with a reserved size
+                            // remain at their basis size, and don't want any extra flex space.
                             0.
                         }
                     }
@@ -828,16 +1187,9 @@ impl Widget for Flex {
                 .children
                 .iter()
                 .map(|child| match child {
-                    Child::Widget {
-                        flex,
-                        basis_resolved,
-                        ..
+                    Child::Widget { basis_resolved, .. } | Child::Spacer { basis_resolved, .. } => {
+                        basis_resolved.get() + child.effective_flex() * flex_fraction
                     }
-                    | Child::Spacer {
-                        flex,
-                        basis_resolved,
-                        ..
-                    } => basis_resolved.get() + *flex * flex_fraction,
                 })
                 .sum::<f64>();
             length = length.saturating_add(total_space_needed.px());
@@ -854,19 +1206,11 @@ impl Widget for Flex {
             let flex_fraction = main_space.map(|mut main_space| {
                 // Sum flex factors and subtract bases from main space.
                 let mut flex_sum = 0.;
-                for child in &mut self.children {
+                for child in &self.children {
                     match child {
-                        Child::Widget {
-                            flex,
-                            basis_resolved,
-                            ..
-                        }
-                        | Child::Spacer {
-                            flex,
-                            basis_resolved,
-                            ..
-                        } => {
-                            flex_sum += *flex;
+                        Child::Widget { basis_resolved, .. }
+                        | Child::Spacer { basis_resolved, .. } => {
+                            flex_sum += child.effective_flex();
                             main_space = main_space.saturating_sub(*basis_resolved);
                         }
                     }
@@ -886,6 +1230,10 @@ impl Widget for Flex {
 
             // Calculate the total space needed for all children
             for child in &mut self.children {
+                if let Some(size) = child.reserved_size() {
+                    length = length.max(size.get_coord(cross).px());
+                    continue;
+                }
                 match child {
                     Child::Widget {
                         widget,
@@ -923,7 +1271,12 @@ impl Widget for Flex {
         let cache = ctx.property_cache();
         let gap = props.get::<Gap>(cache);
         let gap_length = gap.gap.get();
-        let gap_count = self.children.len().saturating_sub(1);
+        let gap_count = self
+            .children
+            .iter()
+            .filter(|child| !child.is_collapsed())
+            .count()
+            .saturating_sub(1);
 
         let main = self.direction;
         let cross = main.cross();
@@ -932,6 +1285,8 @@ impl Widget for Flex {
         let mut main_space = (size.get_coord(main) - gap_count as f64 * gap_length)
             .max(0.)
             .px();
+        // Percentage bases are resolved against the space before anything is subtracted.
+        let percent_space = main_space;
         let mut flex_sum = 0.;
 
         // Helper function to calculate child size when main length is decided
@@ -958,16 +1313,22 @@ impl Widget for Flex {
                 main.pack_size(child_main_length.get(), child_cross_length.get())
             };
 
-        // Sum flex factors, resolve bases, subtract bases from main space,
-        // and lay out inflexible widgets.
+        // Sum flex factors, resolve bases, and subtract bases from main space.
         for child in &mut self.children {
+            if let Some(size) = child.reserved_size()
+                && let Child::Widget { basis_resolved, .. } = child
+            {
+                *basis_resolved = size.get_coord(main).px();
+                main_space = main_space.saturating_sub(*basis_resolved);
+                continue;
+            }
             match child {
                 Child::Widget {
                     widget,
-                    alignment,
                     flex,
                     basis,
                     basis_resolved,
+                    ..
                 } => {
                     match effective_basis(*basis, *flex) {
                         FlexBasis::Auto => {
@@ -988,14 +1349,12 @@ impl Widget for Flex {
                             //      subtract the result for main_space.
                             *basis_resolved = Length::ZERO;
                         }
+                        FlexBasis::Percent(percent) => {
+                            *basis_resolved = (percent_space.get() * percent / 100.).px();
+                            main_space = main_space.saturating_sub(*basis_resolved);
+                        }
                     }
-                    if *flex == 0. {
-                        let child_main_length = *basis_resolved;
-                        let child_size =
-                            compute_child_size(ctx, widget, child_main_length, alignment);
-
-                        ctx.run_layout(widget, child_size);
-                    } else {
+                    if *flex > 0. {
                         flex_sum += *flex;
                     }
                 }
@@ -1017,6 +1376,29 @@ impl Widget for Flex {
             }
         }
 
+        if self.equal_sizes {
+            main_space = equalize_bases(&mut self.children, Some(main_space)).unwrap();
+        }
+
+        // Lay out inflexible widgets
+        for child in &mut self.children {
+            if child.reserved_size().is_none()
+                && let Child::Widget {
+                    widget,
+                    alignment,
+                    flex,
+                    basis_resolved,
+                    ..
+                } = child
+                && *flex == 0.
+            {
+                let child_main_length = *basis_resolved;
+                let child_size = compute_child_size(ctx, widget, child_main_length, alignment);
+
+                ctx.run_layout(widget, child_size);
+            }
+        }
+
         // Calculate the flex fraction, i.e. the amount of space per one flex factor
         let flex_fraction = if flex_sum > 0. {
             main_space.get() / flex_sum
@@ -1026,6 +1408,7 @@ impl Widget for Flex {
 
         // Offer the available space to flexible children
         for child in &mut self.children {
+            let is_reserved = child.reserved_size().is_some();
             match child {
                 Child::Widget {
                     widget,
@@ -1033,7 +1416,7 @@ impl Widget for Flex {
                     flex,
                     basis_resolved,
                     ..
-                } if *flex > 0. => {
+                } if *flex > 0. && !is_reserved => {
                     // Currently we just decide the space distribution in one go.
                     // When Flex gets configurable grow/shrink support,
                     // and min/max style constraints get implemented,
@@ -1067,10 +1450,14 @@ impl Widget for Flex {
         let widget_count = self
             .children
             .iter()
-            .filter(|child| child.is_widget())
+            .filter(|child| child.is_widget() && !child.is_collapsed())
             .count();
-        let (space_before, space_between) =
-            get_spacing(self.main_alignment, main_space.get(), widget_count);
+        let (space_before, space_between) = get_spacing(
+            self.main_alignment,
+            self.edge_spacing,
+            main_space.get(),
+            widget_count,
+        );
 
         // Determine the shared cross alignment baselines.
         // As we currently only support the horizontal-tb writing mode, we do it only for rows.
@@ -1081,7 +1468,7 @@ impl Widget for Flex {
                 match child {
                     Child::Widget {
                         widget, alignment, ..
-                    } => {
+                    } if child.reserved_size().is_none() => {
                         let alignment = alignment.unwrap_or(self.cross_alignment);
                         match alignment {
                             CrossAxisAlignment::FirstBaseline => {
@@ -1107,10 +1494,35 @@ impl Widget for Flex {
             }
         }
 
+        // Determine the shared first baseline of a column's baseline group.
+        let mut group_ascent: Option<f64> = None;
+        if main == Axis::Vertical && self.baseline_group {
+            for child in &self.children {
+                if let Child::Widget { widget, .. } = child
+                    && child.reserved_size().is_none()
+                {
+                    let (first_baseline, _) = ctx.child_layout_baselines(widget);
+                    group_ascent = Some(group_ascent.unwrap_or(first_baseline).max(first_baseline));
+                }
+            }
+        }
+
         // Distribute free space and place children
         let mut main_offset = space_before;
         let mut previous_was_widget = false;
         for child in &mut self.children {
+            if let Some(size) = child.reserved_size() {
+                // Hidden children keep their slot, including the spacing around it.
+                if !child.is_collapsed() {
+                    if previous_was_widget {
+                        main_offset += space_between;
+                    }
+                    main_offset += size.get_coord(main);
+                    main_offset += gap_length;
+                    previous_was_widget = true;
+                }
+                continue;
+            }
             match child {
                 Child::Widget {
                     widget, alignment, ..
@@ -1119,6 +1531,11 @@ impl Widget for Flex {
                         main_offset += space_between;
                     }
 
+                    if let Some(group_ascent) = group_ascent {
+                        let (first_baseline, _) = ctx.child_layout_baselines(widget);
+                        main_offset += group_ascent - first_baseline;
+                    }
+
                     let child_size = ctx.child_size(widget);
                     let alignment = alignment.unwrap_or(self.cross_alignment);
                     let child_origin_cross = match alignment {
@@ -1139,7 +1556,13 @@ impl Widget for Flex {
                         }
                     };
 
-                    let child_origin = main.pack_point(main_offset, child_origin_cross);
+                    // Reversed placement mirrors the offsets along the main axis.
+                    let child_origin_main = if self.reversed {
+                        size.get_coord(main) - main_offset - child_size.get_coord(main)
+                    } else {
+                        main_offset
+                    };
+                    let child_origin = main.pack_point(child_origin_main, child_origin_cross);
                     ctx.place_child(widget, child_origin);
 
                     main_offset += child_size.get_coord(main);
@@ -1177,7 +1600,7 @@ impl Widget for Flex {
             }
             // If there are no baseline alignment groups then derive it from specific children.
             (None, None) => {
-                let any_child_widgets = self.children.iter().any(|child| child.is_widget());
+                let any_child_widgets = self.children.iter().any(|child| child.is_visible_widget());
                 if any_child_widgets {
                     // We use the startmost/endmost children. This is easier to implement
                     // and provides more stable baselines, however it does mean that
@@ -1186,7 +1609,7 @@ impl Widget for Flex {
                     let first_child = self
                         .children
                         .iter()
-                        .find(|c| c.is_widget())
+                        .find(|c| c.is_visible_widget())
                         .unwrap()
                         .widget()
                         .unwrap();
@@ -1197,7 +1620,7 @@ impl Widget for Flex {
                     let last_child = self
                         .children
                         .iter()
-                        .rfind(|c| c.is_widget())
+                        .rfind(|c| c.is_visible_widget())
                         .unwrap()
                         .widget()
                         .unwrap();
@@ -1251,10 +1674,12 @@ impl Widget for Flex {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::WidgetTag;
+    use crate::core::{WidgetTag, WindowEvent};
+    use crate::dpi::PhysicalSize;
     use crate::kurbo::{Cap, Line, Rect, Stroke};
     use crate::layout::AsUnit;
     use crate::palette;
+    use crate::parley::StyleProperty;
     use crate::properties::{BorderColor, BorderWidth, Dimensions, Padding};
     use crate::testing::{ModularWidget, TestHarness, assert_debug_panics, assert_render_snapshot};
     use crate::theme::{ACCENT_COLOR, test_property_set};
@@ -1263,7 +1688,8 @@ mod tests {
     #[test]
     fn test_main_axis_alignment_spacing() {
         let apply_align = |align, extra, child_count| {
-            let (space_before, space_between) = get_spacing(align, extra, child_count);
+            let (space_before, space_between) =
+                get_spacing(align, EdgeSpacing::Both, extra, child_count);
             let space_after =
                 extra - space_before - space_between * child_count.saturating_sub(1) as f64;
             (space_before, space_between, space_after)
@@ -1460,6 +1886,223 @@ mod tests {
         assert_eq!(rect_2.x1, rect_3.x0);
     }
 
+    #[test]
+    fn equal_sizes_row() {
+        let short = WidgetTag::unique();
+        let medium = WidgetTag::unique();
+        let long = WidgetTag::unique();
+
+        let widget = Flex::row()
+            .equal_sizes(true)
+            .with_fixed(Label::new("a").prepare().with_tag(short))
+            .with_fixed(Label::new("Medium").prepare().with_tag(medium))
+            .with_fixed(Label::new("Much longer label").prepare().with_tag(long))
+            .prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (400, 50));
+
+        let width = |harness: &TestHarness<Flex>, tag: WidgetTag<Label>| {
+            harness.get_widget(tag).ctx().border_box().width()
+        };
+        let long_width = width(&harness, long);
+        assert_eq!(width(&harness, short), long_width);
+        assert_eq!(width(&harness, medium), long_width);
+
+        harness.edit_root_widget(|mut flex| {
+            Flex::set_equal_sizes(&mut flex, false);
+        });
+        assert!(width(&harness, short) < width(&harness, medium));
+        assert!(width(&harness, medium) < width(&harness, long));
+        // The shared width was the longest one, rounded up to whole pixels.
+        assert!(long_width - width(&harness, long) <= 1.);
+    }
+
+    #[test]
+    fn hidden_and_collapsed_children() {
+        let middle = WidgetTag::unique();
+        let last = WidgetTag::unique();
+        let fixed_box = || {
+            SizedBox::empty()
+                .prepare()
+                .with_props(Dimensions::fixed(30.px(), 20.px()))
+        };
+
+        let widget = Flex::row()
+            .with_fixed(fixed_box())
+            .with_fixed(fixed_box().with_tag(middle))
+            .with_fixed(fixed_box().with_tag(last))
+            .prepare()
+            .with_props(Gap::new(10.px()));
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (200, 50));
+
+        let last_x = |harness: &TestHarness<Flex>| harness.get_widget(last).ctx().bounding_box().x0;
+        assert_eq!(last_x(&harness), 80.);
+
+        // A hidden child keeps its slot and the gaps around it.
+        harness.edit_root_widget(|mut flex| {
+            Flex::set_child_visibility(&mut flex, 1, FlexVisibility::Hidden);
+        });
+        assert!(harness.get_widget(middle).ctx().is_stashed());
+        assert_eq!(last_x(&harness), 80.);
+
+        // A collapsed child gives up its slot and one of the gaps.
+        harness.edit_root_widget(|mut flex| {
+            Flex::set_child_visibility(&mut flex, 1, FlexVisibility::Collapsed);
+        });
+        assert!(harness.get_widget(middle).ctx().is_stashed());
+        assert_eq!(last_x(&harness), 40.);
+
+        harness.edit_root_widget(|mut flex| {
+            Flex::set_child_visibility(&mut flex, 1, FlexVisibility::Visible);
+        });
+        assert!(!harness.get_widget(middle).ctx().is_stashed());
+        assert_eq!(last_x(&harness), 80.);
+    }
+
+    #[test]
+    fn hidden_flex_child_keeps_its_slot() {
+        let middle = WidgetTag::unique();
+        let last = WidgetTag::unique();
+        let fixed_box = || {
+            SizedBox::empty()
+                .prepare()
+                .with_props(Dimensions::fixed(30.px(), 20.px()))
+        };
+
+        let widget = Flex::row()
+            .with_fixed(fixed_box())
+            .with(SizedBox::empty().prepare().with_tag(middle), 1.0)
+            .with_fixed(fixed_box().with_tag(last))
+            .prepare()
+            .with_props(Gap::new(10.px()));
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (200, 50));
+
+        let last_x = |harness: &TestHarness<Flex>| harness.get_widget(last).ctx().bounding_box().x0;
+        assert_eq!(last_x(&harness), 170.);
+
+        harness.edit_root_widget(|mut flex| {
+            Flex::set_child_visibility(&mut flex, 1, FlexVisibility::Hidden);
+        });
+        assert_eq!(last_x(&harness), 170.);
+
+        // The hidden slot keeps its size when the available space changes.
+        harness.process_window_event(WindowEvent::Resize(PhysicalSize::new(300, 50)));
+        assert!(harness.get_widget(middle).ctx().is_stashed());
+        assert_eq!(last_x(&harness), 170.);
+    }
+
+    #[test]
+    fn flex_row_gap() {
+        let tags = [
+            WidgetTag::unique(),
+            WidgetTag::unique(),
+            WidgetTag::unique(),
+        ];
+        let widget = Flex::row()
+            .with_fixed(Label::new("one").prepare().with_tag(tags[0]))
+            .with_fixed(Label::new("two").prepare().with_tag(tags[1]))
+            .with_fixed(Label::new("three").prepare().with_tag(tags[2]))
+            .prepare()
+            .with_props(Gap::new(10.px()));
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (200, 50));
+
+        let child_box = |harness: &TestHarness<Flex>, idx: usize| {
+            harness.get_widget(tags[idx]).ctx().bounding_box()
+        };
+        // No gap before the first child, then exactly one gap between each pair.
+        assert_eq!(child_box(&harness, 0).x0, 0.);
+        assert_eq!(child_box(&harness, 1).x0, child_box(&harness, 0).x1 + 10.);
+        assert_eq!(child_box(&harness, 2).x0, child_box(&harness, 1).x1 + 10.);
+
+        assert_render_snapshot!(harness, "flex_row_gap");
+
+        harness.edit_root_widget(|mut flex| {
+            flex.insert_prop(Gap::new(20.px()));
+        });
+        assert_eq!(child_box(&harness, 1).x0, child_box(&harness, 0).x1 + 20.);
+        assert_eq!(child_box(&harness, 2).x0, child_box(&harness, 1).x1 + 20.);
+    }
+
+    #[test]
+    fn flex_row_percent_child() {
+        let percent = WidgetTag::unique();
+        let flexible = WidgetTag::unique();
+        let fixed = WidgetTag::unique();
+
+        let widget = Flex::row()
+            .with_child_percent(SizedBox::empty().prepare().with_tag(percent), 30.)
+            .with(SizedBox::empty().prepare().with_tag(flexible), 1.)
+            .prepare()
+            .with_props(Gap::new(0.px()));
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (200, 50));
+
+        let child_box = |harness: &TestHarness<Flex>, tag: WidgetTag<SizedBox>| {
+            harness.get_widget(tag).ctx().bounding_box()
+        };
+        assert_eq!(child_box(&harness, percent).width(), 60.);
+        assert_eq!(child_box(&harness, flexible).x0, 60.);
+        assert_eq!(child_box(&harness, flexible).width(), 140.);
+
+        // Fixed children are taken out along with the percentage, flex gets the rest.
+        harness.edit_root_widget(|mut flex| {
+            let fixed_box = SizedBox::empty()
+                .prepare()
+                .with_props(Dimensions::width(40.px()))
+                .with_tag(fixed);
+            Flex::add_fixed(&mut flex, fixed_box);
+        });
+        assert_eq!(child_box(&harness, percent).width(), 60.);
+        assert_eq!(child_box(&harness, flexible).width(), 100.);
+        assert_eq!(child_box(&harness, fixed).x0, 160.);
+    }
+
+    #[test]
+    fn flex_col_reversed() {
+        let first = WidgetTag::unique();
+        let last = WidgetTag::unique();
+        let fixed_box = || {
+            SizedBox::empty()
+                .prepare()
+                .with_props(Dimensions::fixed(30.px(), 20.px()))
+        };
+
+        let widget = Flex::column()
+            .with_fixed(fixed_box().with_tag(first))
+            .with_spacer(1.)
+            .with(Label::new("flexible").prepare(), 1.)
+            .with_fixed(fixed_box().with_tag(last))
+            .prepare()
+            .with_props(Gap::new(10.px()));
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (100, 200));
+
+        let child_box = |harness: &TestHarness<Flex>, tag: WidgetTag<SizedBox>| {
+            harness.get_widget(tag).ctx().bounding_box()
+        };
+        assert_eq!(child_box(&harness, first).y0, 0.);
+        assert_eq!(child_box(&harness, last).y1, 200.);
+        assert_render_snapshot!(harness, "flex_col_normal");
+
+        harness.edit_root_widget(|mut flex| {
+            Flex::set_reversed(&mut flex, true);
+        });
+        // The flexible children still share the free space, only the placement is mirrored.
+        assert_eq!(child_box(&harness, first).y1, 200.);
+        assert_eq!(child_box(&harness, last).y0, 0.);
+        assert_render_snapshot!(harness, "flex_col_reversed");
+
+        // Children keep their logical order.
+        let children = harness.root_widget().children();
+        assert_eq!(
+            children.first().unwrap().id(),
+            harness.get_widget(first).id()
+        );
+    }
+
     #[test]
     fn main_axis_spacing_snaps_shared_edges() {
         let child_1 = WidgetTag::unique();
@@ -1493,6 +2136,101 @@ mod tests {
         assert_eq!(rect(child_3), Rect::new(73., 0., 83., 10.));
     }
 
+    #[test]
+    fn edge_spacing_positions() {
+        let child_1 = WidgetTag::unique();
+        let child_2 = WidgetTag::unique();
+        let child_3 = WidgetTag::unique();
+
+        let child = |tag| {
+            SizedBox::empty()
+                .size(20.px(), 10.px())
+                .prepare()
+                .with_tag(tag)
+        };
+        let widget = Flex::row()
+            .with_fixed(child(child_1))
+            .with_fixed(child(child_2))
+            .with_fixed(child(child_3))
+            .prepare()
+            .with_props(Gap::new(0.px()));
+
+        // The three children leave 120px of extra space.
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (180, 10));
+
+        let mut check = |align, edges, expected: [f64; 3]| {
+            harness.edit_root_widget(|mut flex| {
+                Flex::set_main_axis_alignment(&mut flex, align);
+                Flex::set_edge_spacing(&mut flex, edges);
+            });
+            let x = |tag: WidgetTag<SizedBox>| {
+                harness.widget_layout_rect(harness.get_widget(tag).id()).x0
+            };
+            assert_eq!(
+                [x(child_1), x(child_2), x(child_3)],
+                expected,
+                "{align:?} with {edges:?}"
+            );
+        };
+
+        // Spacing: 30 [-] 30 [-] 30 [-] 30
+        check(
+            MainAxisAlignment::SpaceEvenly,
+            EdgeSpacing::Both,
+            [30., 80., 130.],
+        );
+        // Spacing: 40 [-] 40 [-] 40 [-]
+        check(
+            MainAxisAlignment::SpaceEvenly,
+            EdgeSpacing::Leading,
+            [40., 100., 160.],
+        );
+        // Spacing: [-] 40 [-] 40 [-] 40
+        check(
+            MainAxisAlignment::SpaceEvenly,
+            EdgeSpacing::Trailing,
+            [0., 60., 120.],
+        );
+        // Spacing: [-] 60 [-] 60 [-]
+        check(
+            MainAxisAlignment::SpaceEvenly,
+            EdgeSpacing::Neither,
+            [0., 80., 160.],
+        );
+
+        // Spacing: 20 [-] 40 [-] 40 [-] 20
+        check(
+            MainAxisAlignment::SpaceAround,
+            EdgeSpacing::Both,
+            [20., 80., 140.],
+        );
+        // Spacing: 24 [-] 48 [-] 48 [-]
+        check(
+            MainAxisAlignment::SpaceAround,
+            EdgeSpacing::Leading,
+            [24., 92., 160.],
+        );
+        // Spacing: [-] 48 [-] 48 [-] 24
+        check(
+            MainAxisAlignment::SpaceAround,
+            EdgeSpacing::Trailing,
+            [0., 68., 136.],
+        );
+        // Spacing: [-] 60 [-] 60 [-]
+        check(
+            MainAxisAlignment::SpaceAround,
+            EdgeSpacing::Neither,
+            [0., 80., 160.],
+        );
+
+        // Other alignments ignore the edge spacing.
+        check(
+            MainAxisAlignment::Center,
+            EdgeSpacing::Neither,
+            [60., 80., 100.],
+        );
+    }
+
     #[test]
     fn center_cross_alignment_snap() {
         let child_tag = WidgetTag::unique();
@@ -1836,6 +2574,59 @@ mod tests {
         assert_render_snapshot!(harness, "flex_row_baselines_four_last_and_first");
     }
 
+    #[test]
+    fn flex_col_baseline_group() {
+        let column: WidgetTag<Flex> = WidgetTag::unique();
+        let tall_row: WidgetTag<Flex> = WidgetTag::unique();
+        let short_row: WidgetTag<Flex> = WidgetTag::unique();
+        let tall_label: WidgetTag<Label> = WidgetTag::unique();
+        let short_label: WidgetTag<Label> = WidgetTag::unique();
+
+        let row = |row_tag, label_tag, name_size: f32| {
+            Flex::row()
+                .cross_axis_alignment(CrossAxisAlignment::FirstBaseline)
+                .with_fixed(
+                    Label::new("Name")
+                        .with_style(StyleProperty::FontSize(name_size))
+                        .prepare(),
+                )
+                .with_fixed(Label::new("Value").prepare().with_tag(label_tag))
+                .prepare()
+                .with_tag(row_tag)
+        };
+        let widget = Flex::column()
+            .cross_axis_alignment(CrossAxisAlignment::Start)
+            .baseline_group(true)
+            .with_fixed(row(tall_row, tall_label, 40.))
+            .with_fixed(row(short_row, short_label, 10.))
+            .prepare()
+            .with_tag(column);
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (200, 150));
+
+        let label_offset =
+            |harness: &TestHarness<Flex>, row: WidgetTag<Flex>, label: WidgetTag<Label>| {
+                let row = harness.get_widget(row).ctx().bounding_box();
+                let label = harness.get_widget(label).ctx().bounding_box();
+                label.y0 - row.y0
+            };
+        // The default sized label of the tall row sits below the large one's ascent,
+        // while it sets the baseline of the short row.
+        let tall_label_offset = label_offset(&harness, tall_row, tall_label);
+        assert!(tall_label_offset > 0.);
+        assert_eq!(label_offset(&harness, short_row, short_label), 0.);
+        assert_render_snapshot!(harness, "flex_col_baseline_group");
+
+        // The short row is moved down by the difference between the rows' ascents,
+        // which is how far the default sized label of the tall row is below its top.
+        let grouped_y = harness.get_widget(short_row).ctx().bounding_box().y0;
+        harness.edit_widget(column, |mut column| {
+            Flex::set_baseline_group(&mut column, false);
+        });
+        let ungrouped_y = harness.get_widget(short_row).ctx().bounding_box().y0;
+        assert_eq!(grouped_y - ungrouped_y, tall_label_offset);
+    }
+
     // The green baseline should be a 1px straight line in both first and last baseline cases.
     // Currently that is not the case and so pixel snapping in relation to baselines needs work.
     #[test]
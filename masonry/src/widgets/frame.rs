@@ -0,0 +1,364 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::{
+    AccessCtx, ChildrenIds, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx, PropertiesRef,
+    RegisterCtx, Widget, WidgetId, WidgetMut, WidgetPod, paint_background, paint_border, pre_paint,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Insets, Point, Rect, Size};
+use crate::layout::{LayoutSize, LenReq, Length};
+use crate::peniko::Color;
+use crate::properties::{Background, BorderColor, BorderWidth, CornerRadius, Padding};
+
+/// A decorated container for a single child, such as a card.
+///
+/// This bundles a background, a border, a corner radius, and padding with builder methods,
+/// along with optional clipping of the child.
+///
+/// The decoration is stored in the frame itself, and is drawn inside the frame's content box.
+/// It comes on top of the frame's [`Background`], [`BorderWidth`], and other box properties,
+/// which still apply as for any other widget.
+///
+/// Otherwise, `Frame` behaves like a [`SizedBox`](crate::widgets::SizedBox) without an explicit size:
+/// it adopts the size of its child, expanded by the border and padding.
+pub struct Frame {
+    child: WidgetPod<dyn Widget>,
+    clip: bool,
+    background: Option<Background>,
+    border_width: BorderWidth,
+    border_color: BorderColor,
+    corner_radius: CornerRadius,
+    padding: Padding,
+}
+
+// --- MARK: BUILDERS
+impl Frame {
+    /// Creates a new `Frame` with no decoration around the given child.
+    pub fn new(child: NewWidget<impl Widget + ?Sized>) -> Self {
+        Self {
+            child: child.erased().to_pod(),
+            clip: false,
+            background: None,
+            border_width: BorderWidth::default(),
+            border_color: BorderColor::default(),
+            corner_radius: CornerRadius::default(),
+            padding: Padding::default(),
+        }
+    }
+
+    /// Builder-style method to set the background.
+    pub fn with_background(mut self, background: impl Into<Background>) -> Self {
+        self.background = Some(background.into());
+        self
+    }
+
+    /// Builder-style method to set the border's width and color.
+    ///
+    /// The width can be a [`Length`] for all edges, or a [`BorderWidth`] with a width per edge.
+    pub fn with_border(mut self, width: impl Into<BorderWidth>, color: Color) -> Self {
+        self.border_width = width.into();
+        self.border_color = BorderColor::new(color);
+        self
+    }
+
+    /// Builder-style method to set the corner radius.
+    pub fn with_corner_radius(mut self, radius: Length) -> Self {
+        self.corner_radius = CornerRadius::all(radius);
+        self
+    }
+
+    /// Builder-style method to set the padding between the border and the child.
+    pub fn with_padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Builder-style method to set whether the child is clipped to the frame's padding-box.
+    ///
+    /// The clip follows the rounded corners of the background.
+    pub fn with_clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl Frame {
+    /// Returns whether the child is clipped to the frame's padding-box.
+    pub fn clip(&self) -> bool {
+        self.clip
+    }
+
+    /// Returns the space taken by the border and padding around the child.
+    fn insets(&self) -> Insets {
+        self.border_width
+            .insets_up(self.padding.insets_up(Insets::ZERO))
+    }
+
+    /// Returns the total length of the border and padding on the given `axis`.
+    fn insets_length(&self, axis: Axis) -> Length {
+        self.border_width
+            .length(axis)
+            .saturating_add(self.padding.length(axis))
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Frame {
+    /// Replaces the child widget with a new one.
+    pub fn set_child(this: &mut WidgetMut<'_, Self>, child: NewWidget<impl Widget + ?Sized>) {
+        let old_child = std::mem::replace(&mut this.widget.child, child.erased().to_pod());
+        this.ctx.remove_child(old_child);
+        this.ctx.children_changed();
+    }
+
+    /// Sets the background, or removes it if `None`.
+    pub fn set_background(this: &mut WidgetMut<'_, Self>, background: Option<Background>) {
+        this.widget.background = background;
+        this.ctx.request_paint_only();
+    }
+
+    /// Sets the border's width and color.
+    pub fn set_border(this: &mut WidgetMut<'_, Self>, width: impl Into<BorderWidth>, color: Color) {
+        this.widget.border_width = width.into();
+        this.widget.border_color = BorderColor::new(color);
+        this.ctx.request_layout();
+    }
+
+    /// Sets the corner radius.
+    pub fn set_corner_radius(this: &mut WidgetMut<'_, Self>, radius: Length) {
+        this.widget.corner_radius = CornerRadius::all(radius);
+        // The clip path is computed in layout.
+        if this.widget.clip {
+            this.ctx.request_layout();
+        } else {
+            this.ctx.request_paint_only();
+        }
+    }
+
+    /// Sets the padding between the border and the child.
+    pub fn set_padding(this: &mut WidgetMut<'_, Self>, padding: impl Into<Padding>) {
+        this.widget.padding = padding.into();
+        this.ctx.request_layout();
+    }
+
+    /// Sets whether the child is clipped to the frame's padding-box.
+    pub fn set_clip(this: &mut WidgetMut<'_, Self>, clip: bool) {
+        this.widget.clip = clip;
+        this.ctx.request_layout();
+    }
+
+    /// Returns a mutable reference to the child.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Frame {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        let insets_length = self.insets_length(axis);
+        let cross_space = cross_length
+            .map(|cross_length| cross_length.saturating_sub(self.insets_length(axis.cross())));
+
+        let auto_length = len_req.reduce(insets_length).into();
+        let context_size = LayoutSize::maybe(axis.cross(), cross_space);
+        let child_length = ctx.compute_length(
+            &mut self.child,
+            auto_length,
+            context_size,
+            axis,
+            cross_space,
+        );
+        child_length.saturating_add(insets_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let insets = self.insets();
+        let child_size = Size::new(
+            (size.width - insets.x_value()).max(0.),
+            (size.height - insets.y_value()).max(0.),
+        );
+        ctx.run_layout(&mut self.child, child_size);
+        ctx.place_child(&mut self.child, Point::new(insets.x0, insets.y0));
+        ctx.derive_baselines(&self.child);
+
+        if self.clip {
+            let frame_box = Rect::from_origin_size(Point::ORIGIN, size);
+            ctx.set_rounded_clip_path(self.border_width.bg_rect(frame_box, &self.corner_radius));
+        } else {
+            ctx.clear_clip_path();
+        }
+    }
+
+    fn pre_paint(
+        &mut self,
+        ctx: &mut PaintCtx<'_>,
+        props: &PropertiesRef<'_>,
+        painter: &mut Painter<'_>,
+    ) {
+        pre_paint(ctx, props, painter);
+
+        // The decoration is painted here rather than in `paint`, so that the border isn't clipped.
+        let frame_box = ctx.content_box();
+        if let Some(background) = &self.background {
+            paint_background(
+                painter,
+                frame_box,
+                background,
+                &self.border_width,
+                &self.corner_radius,
+            );
+        }
+        paint_border(
+            painter,
+            frame_box,
+            &self.border_color,
+            &self.border_width,
+            &self.corner_radius,
+        );
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.child.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Frame", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::WidgetTag;
+    use crate::layout::{AsUnit, UnitPoint};
+    use crate::palette;
+    use crate::testing::{TestHarness, TestHarnessParams, assert_render_snapshot};
+    use crate::theme::test_property_set;
+    use crate::widgets::{Align, Label, SizedBox};
+
+    #[test]
+    fn card() {
+        let frame = Frame::new(Label::new("Card").prepare())
+            .with_background(palette::css::LIGHT_YELLOW)
+            .with_border(2.px(), palette::css::DARK_BLUE)
+            .with_corner_radius(8.px())
+            .with_padding(12.px())
+            .prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), frame, (100, 60));
+
+        assert_render_snapshot!(harness, "frame_card");
+    }
+
+    #[test]
+    fn rounded_clip() {
+        let child = SizedBox::empty()
+            .prepare()
+            .with_props(Background::Color(palette::css::MAGENTA));
+        let border = BorderWidth::new(2.px(), 6.px(), 2.px(), 6.px());
+        let frame = Frame::new(child)
+            .with_border(border, palette::css::DARK_BLUE)
+            .with_corner_radius(20.px())
+            .with_clip(true)
+            .prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), frame, (100, 60));
+        let render = harness.render();
+        // Hack: If we are using `SKIP_RENDER_TESTS`, the output image is a 1x1 white pixel.
+        if std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+            return;
+        }
+
+        // The child is clipped at the rounded corners, inside the border.
+        let magenta = palette::css::MAGENTA.to_rgba8();
+        let magenta = [magenta.r, magenta.g, magenta.b, magenta.a];
+        assert_eq!(render.get_pixel(50, 30).0, magenta);
+        assert_eq!(render.get_pixel(50, 3).0, magenta);
+        assert_eq!(render.get_pixel(7, 30).0, magenta);
+        let background = TestHarnessParams::DEFAULT_BACKGROUND_COLOR.to_rgba8();
+        assert_eq!(
+            render.get_pixel(7, 3).0,
+            [background.r, background.g, background.b, background.a]
+        );
+    }
+
+    #[test]
+    fn decoration_is_kept_by_new_widget() {
+        let frame_tag = WidgetTag::named("frame");
+        let child_tag = WidgetTag::named("child");
+        let child = SizedBox::empty()
+            .size(20.px(), 10.px())
+            .prepare()
+            .with_tag(child_tag);
+        let frame = Frame::new(child)
+            .with_background(palette::css::MAGENTA)
+            .with_border(2.px(), palette::css::DARK_BLUE)
+            .with_padding(4.px());
+        // The decoration doesn't depend on how the frame is wrapped in a `NewWidget`.
+        let frame = NewWidget::new(frame).with_tag(frame_tag);
+        let root = Align::new(UnitPoint::TOP_LEFT, frame).prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), root, (100, 60));
+
+        let frame_size = harness.get_widget(frame_tag).ctx().border_box().size();
+        assert_eq!(frame_size, Size::new(32., 22.));
+        let child_id = harness.get_widget(child_tag).id();
+        assert_eq!(
+            harness.widget_layout_rect(child_id),
+            Rect::new(6., 6., 26., 16.)
+        );
+
+        let render = harness.render();
+        // Hack: If we are using `SKIP_RENDER_TESTS`, the output image is a 1x1 white pixel.
+        if std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+            return;
+        }
+        let magenta = palette::css::MAGENTA.to_rgba8();
+        assert_eq!(
+            render.get_pixel(3, 3).0,
+            [magenta.r, magenta.g, magenta.b, magenta.a]
+        );
+        let blue = palette::css::DARK_BLUE.to_rgba8();
+        assert_eq!(render.get_pixel(0, 10).0, [blue.r, blue.g, blue.b, blue.a]);
+    }
+}
@@ -2,8 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::any::TypeId;
+use std::collections::BTreeMap;
 
-use accesskit::{Node, Role};
+use accesskit::{Node, NodeId, Role};
 use include_doc_path::include_doc_path;
 use tracing::{Span, trace_span};
 
@@ -12,7 +13,7 @@ use crate::core::{
     PropertiesRef, RegisterCtx, UpdateCtx, UsesProperty, Widget, WidgetId, WidgetMut, WidgetPod,
 };
 use crate::imaging::Painter;
-use crate::kurbo::{Axis, Point, Size};
+use crate::kurbo::{Axis, Point, Rect, Size};
 use crate::layout::{AsUnit, LayoutSize, LenReq, Length, SizeDef};
 use crate::properties::Gap;
 use crate::util::debug_panic;
@@ -22,6 +23,15 @@ use crate::util::debug_panic;
 /// Children are drawn in index order,
 /// i.e. each child is drawn on top of the other children with lower indices.
 ///
+/// Each column and row is a track whose size is set by a [`GridTrack`].
+/// By default all tracks are [`GridTrack::Flex(1.)`](GridTrack::Flex), i.e. the grid
+/// is divided into cells of equal size.
+///
+/// By default the grid is a plain layout container for assistive technologies.
+/// Grids holding tabular data can opt into [table semantics](Self::table),
+/// in which case each child is in its own cell, grouped in rows by the row the child starts in.
+/// The cells of the first row can be marked as column headers with [`header_row`](Self::header_row).
+///
 #[doc = concat!(
     "![Grid with buttons of various sizes](",
     include_doc_path!("screenshots/grid_with_changed_spacing.png"),
@@ -29,12 +39,38 @@ use crate::util::debug_panic;
 )]
 pub struct Grid {
     children: Vec<Child>,
-    grid_column_count: i32,
-    grid_row_count: i32,
+    columns: Vec<GridTrack>,
+    rows: Vec<GridTrack>,
+    table: bool,
+    header_row: bool,
+    /// The area of each row, set during layout.
+    row_areas: Vec<Rect>,
+    /// The ids of the accessibility nodes of the table rows, grown as needed.
+    row_node_ids: Vec<NodeId>,
+}
+
+/// The sizing of a single column or row of a [`Grid`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GridTrack {
+    /// The track has a fixed length.
+    Fixed(Length),
+    /// The track takes a share of the space left over by the other tracks,
+    /// proportional to the given factor.
+    ///
+    /// Negative factors are treated as zero.
+    Flex(f64),
+    /// The track is as long as the largest child placed only in this track.
+    ///
+    /// Children spanning several tracks don't affect the length of auto tracks.
+    Auto,
 }
 
 struct Child {
     widget: WidgetPod<dyn Widget>,
+    /// The id of the accessibility node of the table cell wrapping the child.
+    cell_node_id: NodeId,
+    /// The area of the child's cells, set during layout.
+    area: Rect,
     x: i32,
     y: i32,
     width: i32,
@@ -58,30 +94,175 @@ pub struct GridParams {
 impl Grid {
     /// Creates a new grid with the given number of columns and rows.
     pub fn with_dimensions(columns: i32, rows: i32) -> Self {
+        Self::with_tracks(
+            vec![GridTrack::Flex(1.); columns.max(0) as usize],
+            vec![GridTrack::Flex(1.); rows.max(0) as usize],
+        )
+    }
+
+    /// Creates a new grid with the given column and row tracks.
+    pub fn with_tracks(columns: Vec<GridTrack>, rows: Vec<GridTrack>) -> Self {
         Self {
             children: Vec::new(),
-            grid_column_count: columns,
-            grid_row_count: rows,
+            columns,
+            rows,
+            table: false,
+            header_row: false,
+            row_areas: Vec::new(),
+            row_node_ids: Vec::new(),
         }
     }
 
+    /// Builder-style method to set whether the grid is reported as a table.
+    ///
+    /// Table semantics are meant for grids holding tabular data, not for layout grids.
+    /// This only affects how the grid is reported to assistive technologies.
+    pub fn table(mut self, table: bool) -> Self {
+        self.table = table;
+        self
+    }
+
+    /// Builder-style method to set whether the cells of the first row are column headers.
+    ///
+    /// This only has an effect if the grid is a [table](Self::table).
+    pub fn header_row(mut self, header_row: bool) -> Self {
+        self.header_row = header_row;
+        self
+    }
+
     /// Builder-style method to add a child widget.
     pub fn with(mut self, child: NewWidget<impl Widget + ?Sized>, params: GridParams) -> Self {
         let child = new_grid_child(params, child);
         self.children.push(child);
         self
     }
+
+    /// Builder-style method to add a child widget at the given cell, spanning
+    /// `col_span` columns and `row_span` rows.
+    ///
+    /// This is equivalent to [`with`](Self::with) with [`GridParams::new`].
+    pub fn with_child_at(
+        self,
+        child: NewWidget<impl Widget + ?Sized>,
+        col: i32,
+        row: i32,
+        col_span: i32,
+        row_span: i32,
+    ) -> Self {
+        self.with(child, GridParams::new(col, row, col_span, row_span))
+    }
 }
 
-// --- MARK: METHODS
-impl Grid {
-    /// Returns the number of cells tracks have on the given `axis`.
-    fn track_cells(&self, axis: Axis) -> i32 {
-        match axis {
-            Axis::Horizontal => self.grid_column_count,
-            Axis::Vertical => self.grid_row_count,
+// --- MARK: HELPERS
+/// Resolves the lengths of `tracks` on the given `axis`.
+///
+/// Fixed tracks get their length and auto tracks get the largest length
+/// of the children placed only in them.
+///
+/// With [`LenReq::FitContent`], auto tracks use the children's max-content lengths
+/// and flex tracks share whatever space is left.
+/// Otherwise, flex tracks get the smallest lengths which fit the children spanning them.
+///
+/// `cross_lengths` are the resolved lengths of the cross axis tracks, if known,
+/// which are used to derive the cross length of each child's area.
+fn resolve_tracks(
+    tracks: &[GridTrack],
+    children: &mut [Child],
+    axis: Axis,
+    gap: f64,
+    len_req: LenReq,
+    cross_lengths: Option<&[f64]>,
+    measure: &mut dyn FnMut(&mut WidgetPod<dyn Widget>, Axis, LenReq, Option<Length>) -> f64,
+) -> Vec<f64> {
+    let (len_req, available) = match len_req {
+        LenReq::MinContent | LenReq::MaxContent => (len_req, None),
+        LenReq::FitContent(space) => (LenReq::MaxContent, Some(space.get())),
+    };
+
+    let cross = axis.cross();
+    let cross_area_length = |child: &Child| {
+        cross_lengths.map(|lengths| {
+            span_length(
+                lengths,
+                gap,
+                child.area_start(cross),
+                child.area_cells(cross),
+            )
+            .px()
+        })
+    };
+
+    let mut lengths: Vec<f64> = tracks
+        .iter()
+        .map(|track| match track {
+            GridTrack::Fixed(length) => length.get(),
+            GridTrack::Flex(_) | GridTrack::Auto => 0.,
+        })
+        .collect();
+
+    // Auto tracks fit the children placed only in them
+    for child in children.iter_mut() {
+        let start = child.area_start(axis);
+        if child.area_cells(axis) != 1 || tracks.get(start) != Some(&GridTrack::Auto) {
+            continue;
         }
+        let cross_area_length = cross_area_length(child);
+        let child_length = measure(&mut child.widget, axis, len_req, cross_area_length);
+        lengths[start] = lengths[start].max(child_length);
     }
+
+    let flex_factor = |track: &GridTrack| match track {
+        GridTrack::Flex(factor) => factor.max(0.),
+        GridTrack::Fixed(_) | GridTrack::Auto => 0.,
+    };
+    let flex_total: f64 = tracks.iter().map(flex_factor).sum();
+    if flex_total <= 0. {
+        return lengths;
+    }
+
+    let flex_unit = if let Some(available) = available {
+        let used = span_length(&lengths, gap, 0, lengths.len());
+        (available - used).max(0.) / flex_total
+    } else {
+        // Find the largest length per flex factor desired by a child
+        let mut flex_unit: f64 = 0.;
+        for child in children.iter_mut() {
+            let start = child.area_start(axis);
+            let cells = child.area_cells(axis);
+            let spanned = tracks.iter().skip(start).take(cells);
+            let child_flex: f64 = spanned.map(flex_factor).sum();
+            if child_flex <= 0. {
+                continue;
+            }
+            let cross_area_length = cross_area_length(child);
+            let child_length = measure(&mut child.widget, axis, len_req, cross_area_length);
+            let fixed_length = span_length(&lengths, gap, start, cells);
+            flex_unit = flex_unit.max((child_length - fixed_length) / child_flex);
+        }
+        flex_unit
+    };
+
+    for (length, track) in lengths.iter_mut().zip(tracks) {
+        *length += flex_unit * flex_factor(track);
+    }
+    lengths
+}
+
+/// Returns the offset of the track at index `start`.
+fn track_offset(lengths: &[f64], gap: f64, start: usize) -> f64 {
+    lengths.iter().take(start).sum::<f64>() + start as f64 * gap
+}
+
+fn to_accesskit_rect(r: Rect) -> accesskit::Rect {
+    accesskit::Rect::new(r.x0, r.y0, r.x1, r.y1)
+}
+
+/// Returns the length of `cells` tracks starting at index `start`, including the gaps between them.
+fn span_length(lengths: &[f64], gap: f64, start: usize, cells: usize) -> f64 {
+    let tracks_length: f64 = lengths.iter().skip(start).take(cells).sum();
+    // Guard against the derived length becoming negative,
+    // which can happen if total space can't fit all cells and gaps.
+    (tracks_length + cells.saturating_sub(1) as f64 * gap).max(0.)
 }
 
 // --- MARK: IMPL CHILD
@@ -93,18 +274,30 @@ impl Child {
         self.height = params.height;
     }
 
+    /// Returns the index of the first cell of the child's area on the given `axis`.
+    fn area_start(&self, axis: Axis) -> usize {
+        let start = match axis {
+            Axis::Horizontal => self.x,
+            Axis::Vertical => self.y,
+        };
+        start.max(0) as usize
+    }
+
     /// Returns the number of cells the child's area spans on the given `axis`.
-    fn area_cells(&self, axis: Axis) -> i32 {
-        match axis {
+    fn area_cells(&self, axis: Axis) -> usize {
+        let cells = match axis {
             Axis::Horizontal => self.width,
             Axis::Vertical => self.height,
-        }
+        };
+        cells.max(0) as usize
     }
 }
 
 fn new_grid_child(params: GridParams, child: NewWidget<impl Widget + ?Sized>) -> Child {
     Child {
         widget: child.erased().to_pod(),
+        cell_node_id: AccessCtx::next_node_id(),
+        area: Rect::ZERO,
         x: params.x,
         y: params.y,
         width: params.width,
@@ -155,15 +348,51 @@ impl GridParams {
 // --- MARK: WIDGETMUT
 impl Grid {
     /// Sets the number of columns of the grid.
+    ///
+    /// Added columns are [`GridTrack::Flex(1.)`](GridTrack::Flex).
     pub fn set_column_count(this: &mut WidgetMut<'_, Self>, columns: i32) {
-        this.widget.grid_column_count = columns;
+        let columns = columns.max(0) as usize;
+        this.widget.columns.resize(columns, GridTrack::Flex(1.));
         this.ctx.request_layout();
+        this.ctx.request_accessibility_update();
     }
 
     /// Sets the number of rows of the grid.
+    ///
+    /// Added rows are [`GridTrack::Flex(1.)`](GridTrack::Flex).
     pub fn set_row_count(this: &mut WidgetMut<'_, Self>, rows: i32) {
-        this.widget.grid_row_count = rows;
+        let rows = rows.max(0) as usize;
+        this.widget.rows.resize(rows, GridTrack::Flex(1.));
+        this.ctx.request_layout();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Sets the column tracks of the grid.
+    pub fn set_column_tracks(this: &mut WidgetMut<'_, Self>, columns: Vec<GridTrack>) {
+        this.widget.columns = columns;
+        this.ctx.request_layout();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Sets the row tracks of the grid.
+    pub fn set_row_tracks(this: &mut WidgetMut<'_, Self>, rows: Vec<GridTrack>) {
+        this.widget.rows = rows;
         this.ctx.request_layout();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Sets whether the grid is reported as a table.
+    ///
+    /// See [`table`](Self::table) for details.
+    pub fn set_table(this: &mut WidgetMut<'_, Self>, table: bool) {
+        this.widget.table = table;
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Sets whether the cells of the first row are column headers.
+    pub fn set_header_row(this: &mut WidgetMut<'_, Self>, header_row: bool) {
+        this.widget.header_row = header_row;
+        this.ctx.request_accessibility_update();
     }
 }
 
@@ -309,12 +538,6 @@ impl Widget for Grid {
 
         let gap_length = gap.gap.get();
 
-        let cross = axis.cross();
-        let cross_track_cells = self.track_cells(cross) as f64;
-        let cross_cell_length = cross_length
-            .filter(|_| cross_track_cells > 0.) // Guard against div by zero
-            .map(|cross_length| (cross_length.get() + gap_length) / cross_track_cells);
-
         let (len_req, min_result) = match len_req {
             LenReq::MinContent | LenReq::MaxContent => (len_req, Length::ZERO),
             // We always want to use up all offered space but may need even more,
@@ -322,37 +545,46 @@ impl Widget for Grid {
             LenReq::FitContent(space) => (LenReq::MinContent, space),
         };
 
-        // Find the largest desired cell length
-        let mut cell_length: f64 = 0.;
-        for child in &mut self.children {
-            let desired_cell_length = {
-                let area_cells = child.area_cells(axis) as f64;
-                let cross_area_length = cross_cell_length.map(|cross_cell_length| {
-                    let cross_area_cells = child.area_cells(cross) as f64;
-                    let length = cross_area_cells * cross_cell_length - gap_length;
-                    // Guard against the derived area length becoming negative,
-                    // which can happen if total space can't fit all cells and gaps.
-                    length.max(0.).px()
-                });
-
-                let auto_length = len_req.into();
-                let context_size = LayoutSize::maybe(cross, cross_area_length);
-
-                let child_length = ctx.compute_length(
-                    &mut child.widget,
-                    auto_length,
-                    context_size,
-                    axis,
-                    cross_area_length,
-                );
-
-                (child_length.get() + gap_length) / area_cells
-            };
-            cell_length = cell_length.max(desired_cell_length);
-        }
+        let mut measure = |child: &mut WidgetPod<dyn Widget>,
+                           axis: Axis,
+                           len_req: LenReq,
+                           cross_area_length: Option<Length>| {
+            let context_size = LayoutSize::maybe(axis.cross(), cross_area_length);
+            ctx.compute_length(child, len_req.into(), context_size, axis, cross_area_length)
+                .get()
+        };
 
-        let track_cells = self.track_cells(axis) as f64;
-        let length = (track_cells * cell_length - gap_length).px();
+        let cross = axis.cross();
+        let cross_tracks = match cross {
+            Axis::Horizontal => &self.columns,
+            Axis::Vertical => &self.rows,
+        };
+        let cross_lengths = cross_length.map(|cross_length| {
+            resolve_tracks(
+                cross_tracks,
+                &mut self.children,
+                cross,
+                gap_length,
+                LenReq::FitContent(cross_length),
+                None,
+                &mut measure,
+            )
+        });
+
+        let tracks = match axis {
+            Axis::Horizontal => &self.columns,
+            Axis::Vertical => &self.rows,
+        };
+        let lengths = resolve_tracks(
+            tracks,
+            &mut self.children,
+            axis,
+            gap_length,
+            len_req,
+            cross_lengths.as_deref(),
+            &mut measure,
+        );
+        let length = span_length(&lengths, gap_length, 0, lengths.len()).px();
 
         min_result.max(length)
     }
@@ -363,27 +595,65 @@ impl Widget for Grid {
 
         let gap_length = gap.gap.get();
 
-        let cell_width = (size.width + gap_length) / self.grid_column_count as f64;
-        let cell_height = (size.height + gap_length) / self.grid_row_count as f64;
+        let (columns, rows) = {
+            let mut measure = |child: &mut WidgetPod<dyn Widget>,
+                               axis: Axis,
+                               len_req: LenReq,
+                               cross_area_length: Option<Length>| {
+                let context_size = LayoutSize::maybe(axis.cross(), cross_area_length);
+                ctx.compute_length(child, len_req.into(), context_size, axis, cross_area_length)
+                    .get()
+            };
+            let columns = resolve_tracks(
+                &self.columns,
+                &mut self.children,
+                Axis::Horizontal,
+                gap_length,
+                LenReq::FitContent(size.width.px()),
+                None,
+                &mut measure,
+            );
+            let rows = resolve_tracks(
+                &self.rows,
+                &mut self.children,
+                Axis::Vertical,
+                gap_length,
+                LenReq::FitContent(size.height.px()),
+                Some(&columns[..]),
+                &mut measure,
+            );
+            (columns, rows)
+        };
 
         for child in &mut self.children {
+            let (x, y) = (
+                child.area_start(Axis::Horizontal),
+                child.area_start(Axis::Vertical),
+            );
             let area = Size::new(
-                child.width as f64 * cell_width - gap_length,
-                child.height as f64 * cell_height - gap_length,
-            )
-            // Guard against the derived area becoming negative,
-            // which can happen if total space can't fit all cells and gaps.
-            .max(Size::ZERO);
+                span_length(&columns, gap_length, x, child.area_cells(Axis::Horizontal)),
+                span_length(&rows, gap_length, y, child.area_cells(Axis::Vertical)),
+            );
             let auto_size = SizeDef::fixed(area);
 
             let child_size = ctx.compute_size(&mut child.widget, auto_size, area.into());
             ctx.run_layout(&mut child.widget, child_size);
 
-            let child_origin =
-                Point::new(child.x as f64 * cell_width, child.y as f64 * cell_height);
+            let child_origin = Point::new(
+                track_offset(&columns, gap_length, x),
+                track_offset(&rows, gap_length, y),
+            );
             ctx.place_child(&mut child.widget, child_origin);
+            child.area = Rect::from_origin_size(child_origin, area);
         }
 
+        self.row_areas = (0..rows.len())
+            .map(|row| {
+                let y0 = track_offset(&rows, gap_length, row);
+                Rect::new(0., y0, size.width, y0 + rows[row])
+            })
+            .collect();
+
         if !self.children.is_empty() {
             // Determine the first and last occupied row
             let min_row = self.children.iter().map(|c| c.y).min().unwrap();
@@ -429,15 +699,77 @@ impl Widget for Grid {
     }
 
     fn accessibility_role(&self) -> Role {
-        Role::GenericContainer
+        if self.table {
+            Role::Grid
+        } else {
+            Role::GenericContainer
+        }
     }
 
     fn accessibility(
         &mut self,
-        _ctx: &mut AccessCtx<'_>,
+        ctx: &mut AccessCtx<'_>,
         _props: &PropertiesRef<'_>,
-        _node: &mut Node,
+        node: &mut Node,
     ) {
+        if !self.table {
+            return;
+        }
+        ctx.set_table_dimensions(node, self.rows.len(), self.columns.len());
+
+        // Each child is wrapped in a cell node carrying its coordinates,
+        // and cells are grouped in row nodes by the row they start in.
+        // The node's children are already filtered to exclude stashed widgets.
+        let visible_children = node.children().to_vec();
+        let mut row_cells: BTreeMap<usize, Vec<(usize, NodeId)>> = BTreeMap::new();
+        for child in &self.children {
+            let child_node_id = child.widget.id().into();
+            if !visible_children.contains(&child_node_id) {
+                continue;
+            }
+            // Like in layout, negative coordinates are clamped to the first row and column.
+            let row = child.area_start(Axis::Vertical);
+            let column = child.area_start(Axis::Horizontal);
+            let role = if self.header_row && row == 0 {
+                Role::ColumnHeader
+            } else {
+                Role::Cell
+            };
+            let mut cell = Node::new(role);
+            ctx.set_table_cell(
+                &mut cell,
+                row,
+                column,
+                child.area_cells(Axis::Vertical),
+                child.area_cells(Axis::Horizontal),
+            );
+            cell.set_bounds(to_accesskit_rect(child.area));
+            cell.set_children(vec![child_node_id]);
+            ctx.tree_update().nodes.push((child.cell_node_id, cell));
+            row_cells
+                .entry(row)
+                .or_default()
+                .push((column, child.cell_node_id));
+        }
+
+        let mut row_nodes = Vec::with_capacity(row_cells.len());
+        for (row, mut cells) in row_cells {
+            while self.row_node_ids.len() <= row {
+                self.row_node_ids.push(AccessCtx::next_node_id());
+            }
+            cells.sort_by_key(|(x, _)| *x);
+            let mut row_node = Node::new(Role::Row);
+            row_node.set_row_index(row);
+            if let Some(area) = self.row_areas.get(row) {
+                row_node.set_bounds(to_accesskit_rect(*area));
+            }
+            row_node.set_children(cells.into_iter().map(|(_, id)| id).collect::<Vec<_>>());
+            ctx.tree_update()
+                .nodes
+                .push((self.row_node_ids[row], row_node));
+            row_nodes.push(self.row_node_ids[row]);
+        }
+        node.set_children(row_nodes);
     }
 
     fn children_ids(&self) -> ChildrenIds {
@@ -456,13 +788,15 @@ impl Widget for Grid {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::WidgetTag;
+    use crate::kurbo::Rect;
     use crate::layout::AsUnit;
     use crate::palette;
     use crate::properties::types::CrossAxisAlignment;
     use crate::properties::{Background, Dimensions, Padding};
     use crate::testing::{TestHarness, assert_render_snapshot};
     use crate::theme::test_property_set;
-    use crate::widgets::{Button, Flex, Label};
+    use crate::widgets::{Button, Flex, Label, SizedBox};
 
     #[test]
     fn test_grid_basics() {
@@ -524,6 +858,192 @@ mod tests {
         assert_render_snapshot!(harness, "grid_with_changed_spacing");
     }
 
+    #[test]
+    fn track_sizing() {
+        let auto_tag = WidgetTag::named("auto");
+        let flex_tag = WidgetTag::named("flex");
+        let span_tag = WidgetTag::named("span");
+
+        let widget = Grid::with_tracks(
+            vec![GridTrack::Fixed(60.px()), GridTrack::Flex(1.)],
+            vec![GridTrack::Auto, GridTrack::Flex(1.)],
+        )
+        .with_child_at(
+            SizedBox::empty()
+                .height(30.px())
+                .prepare()
+                .with_tag(auto_tag)
+                .with_props(Background::Color(palette::css::ORANGE)),
+            0,
+            0,
+            1,
+            1,
+        )
+        .with_child_at(
+            SizedBox::empty()
+                .prepare()
+                .with_tag(flex_tag)
+                .with_props(Background::Color(palette::css::DARK_BLUE)),
+            1,
+            0,
+            1,
+            1,
+        )
+        .with_child_at(
+            SizedBox::empty()
+                .prepare()
+                .with_tag(span_tag)
+                .with_props(Background::Color(palette::css::DARK_SALMON)),
+            0,
+            1,
+            2,
+            1,
+        )
+        .prepare()
+        .with_props((Dimensions::STRETCH, Gap::new(10.px())));
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (200, 200));
+
+        assert_render_snapshot!(harness, "grid_track_sizing");
+
+        let auto_id = harness.get_widget(auto_tag).id();
+        let flex_id = harness.get_widget(flex_tag).id();
+        let span_id = harness.get_widget(span_tag).id();
+        // The auto row fits the 30px tall child, the flex tracks share the rest.
+        assert_eq!(
+            harness.widget_layout_rect(auto_id),
+            Rect::new(0., 0., 60., 30.)
+        );
+        assert_eq!(
+            harness.widget_layout_rect(flex_id),
+            Rect::new(70., 0., 200., 30.)
+        );
+        assert_eq!(
+            harness.widget_layout_rect(span_id),
+            Rect::new(0., 40., 200., 200.)
+        );
+
+        // A fixed row no longer depends on its children.
+        harness.edit_root_widget(|mut grid| {
+            Grid::set_row_tracks(
+                &mut grid,
+                vec![GridTrack::Fixed(50.px()), GridTrack::Flex(1.)],
+            );
+        });
+        assert_eq!(
+            harness.widget_layout_rect(auto_id),
+            Rect::new(0., 0., 60., 30.)
+        );
+        assert_eq!(
+            harness.widget_layout_rect(flex_id),
+            Rect::new(70., 0., 200., 50.)
+        );
+        assert_eq!(
+            harness.widget_layout_rect(span_id),
+            Rect::new(0., 60., 200., 200.)
+        );
+    }
+
+    #[test]
+    fn table_accessibility() {
+        let grid_tag = WidgetTag::unique();
+        let header = WidgetTag::unique();
+        let cell = WidgetTag::unique();
+        let widget = Grid::with_dimensions(3, 2)
+            .table(true)
+            .header_row(true)
+            .with(
+                Label::new("Name").prepare().with_tag(header),
+                GridParams::new(0, 0, 1, 1),
+            )
+            .with(
+                Label::new("Value").prepare().with_tag(cell),
+                GridParams::new(1, 1, 2, 1),
+            )
+            .prepare()
+            .with_tag(grid_tag);
+        // The padding makes window coordinates differ from grid-local ones.
+        let widget = SizedBox::new(widget)
+            .prepare()
+            .with_props(Padding::all(10.px()));
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (200, 200));
+
+        let grid_id = harness.get_widget(grid_tag).id();
+        let grid = harness.access_node(grid_id).unwrap();
+        assert_eq!(grid.role(), Role::Grid);
+        assert_eq!(grid.data().row_count(), Some(2));
+        assert_eq!(grid.data().column_count(), Some(3));
+
+        let cell_id = harness.get_widget(cell).id();
+        let cell_node = harness.access_node(cell_id).unwrap().parent().unwrap();
+        assert_eq!(cell_node.role(), Role::Cell);
+        assert_eq!(cell_node.data().row_index(), Some(1));
+        assert_eq!(cell_node.data().column_index(), Some(1));
+        assert_eq!(cell_node.data().column_span(), Some(2));
+        // The cell spans the last two of three columns in the 180x180 grid, in grid coordinates.
+        assert_eq!(
+            cell_node.data().bounds(),
+            Some(accesskit::Rect::new(60., 90., 180., 180.))
+        );
+
+        // Cells are grouped in rows, which are the children of the grid.
+        let row_node = cell_node.parent().unwrap();
+        assert_eq!(row_node.role(), Role::Row);
+        assert_eq!(row_node.data().row_index(), Some(1));
+        assert_eq!(row_node.parent().unwrap().locate().0, grid.locate().0);
+        assert_eq!(grid.children().len(), 2);
+        assert!(grid.children().all(|row| row.role() == Role::Row));
+
+        let header_id = harness.get_widget(header).id();
+        let header_node = harness.access_node(header_id).unwrap().parent().unwrap();
+        assert_eq!(header_node.role(), Role::ColumnHeader);
+
+        harness.edit_widget(grid_tag, |mut grid| {
+            Grid::set_header_row(&mut grid, false);
+            Grid::set_row_count(&mut grid, 4);
+        });
+        let _ = harness.render();
+        let grid = harness.access_node(grid_id).unwrap();
+        assert_eq!(grid.data().row_count(), Some(4));
+        let header_node = harness.access_node(header_id).unwrap().parent().unwrap();
+        assert_eq!(header_node.role(), Role::Cell);
+
+        // Without table semantics, the grid is a plain container of its children.
+        harness.edit_widget(grid_tag, |mut grid| Grid::set_table(&mut grid, false));
+        let _ = harness.render();
+        let grid = harness.access_node(grid_id).unwrap();
+        assert_eq!(grid.role(), Role::GenericContainer);
+        assert_eq!(grid.data().row_count(), None);
+        let header_node = harness.access_node(header_id).unwrap();
+        assert_eq!(header_node.parent().unwrap().locate().0, grid.locate().0);
+    }
+
+    #[test]
+    fn table_accessibility_negative_coordinates() {
+        let grid_tag = WidgetTag::unique();
+        let cell = WidgetTag::unique();
+        let widget = Grid::with_dimensions(2, 2)
+            .table(true)
+            .with(
+                Label::new("Outside").prepare().with_tag(cell),
+                // `GridParams::new` rejects negative coordinates, but the fields are public.
+                GridParams {
+                    x: -1,
+                    y: -2,
+                    width: 2,
+                    height: 1,
+                },
+            )
+            .prepare()
+            .with_tag(grid_tag);
+        let harness = TestHarness::create_with_size(test_property_set(), widget, (200, 200));
+
+        let cell_id = harness.get_widget(cell).id();
+        let cell_node = harness.access_node(cell_id).unwrap().parent().unwrap();
+        assert_eq!(cell_node.role(), Role::Cell);
+        assert_eq!(cell_node.data().row_index(), Some(0));
+        assert_eq!(cell_node.data().column_index(), Some(0));
+    }
+
     #[test]
     fn test_widget_removal_and_modification() {
         let widget = NewWidget::new(Grid::with_dimensions(2, 2).with(
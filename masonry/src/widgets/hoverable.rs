@@ -0,0 +1,405 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::{
+    AccessCtx, ChildrenIds, EventCtx, FromDynWidget, LayoutCtx, MeasureCtx, NewWidget, NoAction,
+    PaintCtx, PointerEvent, PropertiesMut, PropertiesRef, Property, RegisterCtx, Update, UpdateCtx,
+    Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Point, Size};
+use crate::layout::{LenReq, Length};
+
+/// A wrapper which swaps properties of its child when the pointer hovers over it.
+///
+/// For each property registered with [`with_hovered_prop`](Self::with_hovered_prop),
+/// the child gets one value normally and another while hovered.
+/// [`with_pressed_prop`](Self::with_pressed_prop) additionally sets a value
+/// used while the child is pressed.
+///
+/// Values are applied as local properties of the child, from the update pass,
+/// so they take precedence over default properties.
+/// In a state without a value, the child's own local value is restored.
+///
+/// # Examples
+/// ```
+/// use masonry::core::Widget;
+/// use masonry::palette;
+/// use masonry::properties::Background;
+/// use masonry::widgets::{Hoverable, SizedBox};
+///
+/// let hoverable = Hoverable::new(SizedBox::empty().prepare()).with_hovered_prop(
+///     Background::Color(palette::css::BLUE),
+///     Background::Color(palette::css::RED),
+/// );
+/// ```
+pub struct Hoverable<W: Widget + ?Sized> {
+    child: WidgetPod<W>,
+    swaps: Vec<Box<dyn PropertySwap>>,
+    state: HoverState,
+}
+
+/// The interaction state used to pick which property values a [`Hoverable`] applies.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+enum HoverState {
+    #[default]
+    Normal,
+    Hovered,
+    Pressed,
+}
+
+/// The values of a single property type for each [`HoverState`].
+struct Swap<P: Property> {
+    normal: Option<P>,
+    hovered: Option<P>,
+    pressed: Option<P>,
+    /// The child's own local value, captured when leaving [`HoverState::Normal`].
+    original: Arc<Mutex<Option<P>>>,
+}
+
+/// Type-erased [`Swap`].
+trait PropertySwap: Any {
+    /// Returns a callback which sets the property value for `state` on the child.
+    ///
+    /// If `snapshot` is true, the callback first captures the child's own local value.
+    /// If no value is set for `state`, that captured value is restored.
+    fn apply(
+        &self,
+        state: HoverState,
+        snapshot: bool,
+    ) -> Box<dyn FnOnce(&mut WidgetMut<'_, dyn Widget>) + Send>;
+
+    /// Returns `true` if a value is set for [`HoverState::Pressed`].
+    fn has_pressed(&self) -> bool;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<P: Property> PropertySwap for Swap<P> {
+    fn apply(
+        &self,
+        state: HoverState,
+        snapshot: bool,
+    ) -> Box<dyn FnOnce(&mut WidgetMut<'_, dyn Widget>) + Send> {
+        let value = match state {
+            HoverState::Normal => self.normal.clone(),
+            HoverState::Hovered => self.hovered.clone().or_else(|| self.normal.clone()),
+            HoverState::Pressed => self
+                .pressed
+                .clone()
+                .or_else(|| self.hovered.clone())
+                .or_else(|| self.normal.clone()),
+        };
+        let original = self.original.clone();
+        Box::new(move |child| {
+            let mut original = original.lock().unwrap();
+            if snapshot {
+                *original = child
+                    .contains_prop::<P>()
+                    .then(|| child.get_prop::<P>().clone());
+            }
+            match value.or_else(|| original.clone()) {
+                Some(value) => {
+                    child.insert_prop(value);
+                }
+                None if child.contains_prop::<P>() => {
+                    child.remove_prop::<P>();
+                }
+                None => {}
+            }
+        })
+    }
+
+    fn has_pressed(&self) -> bool {
+        self.pressed.is_some()
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+// --- MARK: BUILDERS
+impl<W: Widget + ?Sized> Hoverable<W> {
+    /// Creates a new `Hoverable` wrapping the given child.
+    pub fn new(child: NewWidget<W>) -> Self {
+        Self {
+            child: child.to_pod(),
+            swaps: Vec::new(),
+            state: HoverState::Normal,
+        }
+    }
+
+    /// Builder-style method to apply `normal` to the child, and `hovered` while it is hovered.
+    pub fn with_hovered_prop<P: Property>(mut self, normal: P, hovered: P) -> Self {
+        let swap = self.swap_mut::<P>();
+        swap.normal = Some(normal);
+        swap.hovered = Some(hovered);
+        self
+    }
+
+    /// Builder-style method to apply `pressed` to the child while it is pressed.
+    ///
+    /// Pressing the child captures the pointer, like a [`Button`](crate::widgets::Button) does.
+    pub fn with_pressed_prop<P: Property>(mut self, pressed: P) -> Self {
+        self.swap_mut::<P>().pressed = Some(pressed);
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl<W: Widget + ?Sized> Hoverable<W> {
+    /// Returns the [`Swap`] for property `P`, inserting an empty one if needed.
+    fn swap_mut<P: Property>(&mut self) -> &mut Swap<P> {
+        let idx = self
+            .swaps
+            .iter_mut()
+            .position(|swap| swap.as_any_mut().is::<Swap<P>>());
+        let idx = idx.unwrap_or_else(|| {
+            self.swaps.push(Box::new(Swap::<P> {
+                normal: None,
+                hovered: None,
+                pressed: None,
+                original: Arc::default(),
+            }));
+            self.swaps.len() - 1
+        });
+        self.swaps[idx]
+            .as_any_mut()
+            .downcast_mut::<Swap<P>>()
+            .unwrap()
+    }
+
+    /// Returns the state matching the current hovered and active status.
+    fn current_state(ctx: &UpdateCtx<'_>) -> HoverState {
+        if ctx.has_active() {
+            HoverState::Pressed
+        } else if ctx.has_hovered() {
+            HoverState::Hovered
+        } else {
+            HoverState::Normal
+        }
+    }
+
+    /// Queues the property values for `state` to be applied to the child.
+    ///
+    /// The child's own values are captured while it is in the normal state,
+    /// so that they can be restored when no value is set for `state`.
+    fn apply_state(&mut self, ctx: &mut UpdateCtx<'_>, state: HoverState) {
+        let snapshot = self.state == HoverState::Normal;
+        self.state = state;
+        let callbacks: Vec<_> = self
+            .swaps
+            .iter()
+            .map(|swap| swap.apply(state, snapshot))
+            .collect();
+        ctx.mutate_later(self.child.id(), move |mut child| {
+            for callback in callbacks {
+                callback(&mut child);
+            }
+        });
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl<W: Widget + FromDynWidget + ?Sized> Hoverable<W> {
+    /// Returns a mutable reference to the child.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, W> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl<W: Widget + FromDynWidget + ?Sized> Widget for Hoverable<W> {
+    type Action = NoAction;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if let PointerEvent::Down(..) = event
+            && self.swaps.iter().any(|swap| swap.has_pressed())
+        {
+            ctx.capture_pointer();
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::WidgetAdded => {
+                let state = Self::current_state(ctx);
+                self.apply_state(ctx, state);
+            }
+            Update::HoveredChanged(_)
+            | Update::ChildHoveredChanged(_)
+            | Update::ActiveChanged(_)
+            | Update::ChildActiveChanged(_) => {
+                let state = Self::current_state(ctx);
+                if state != self.state {
+                    self.apply_state(ctx, state);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        ctx.redirect_measurement(&mut self.child, axis, cross_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.run_layout(&mut self.child, size);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        ctx.derive_baselines(&self.child);
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.child.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Hoverable", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::WidgetTag;
+    use crate::layout::AsUnit;
+    use crate::palette;
+    use crate::properties::Background;
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+    use crate::widgets::SizedBox;
+
+    fn blue() -> Background {
+        Background::Color(palette::css::BLUE)
+    }
+
+    fn red() -> Background {
+        Background::Color(palette::css::RED)
+    }
+
+    #[test]
+    fn hover_changes_background() {
+        let child_tag = WidgetTag::named("child");
+        let child = SizedBox::empty()
+            .size(40.px(), 40.px())
+            .prepare()
+            .with_tag(child_tag);
+        let widget = Hoverable::new(child)
+            .with_hovered_prop(blue(), red())
+            .prepare();
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (60, 60));
+
+        assert_render_snapshot!(harness, "hoverable_normal");
+
+        harness.mouse_move((20., 20.));
+        assert_render_snapshot!(harness, "hoverable_hovered");
+
+        harness.mouse_move((50., 50.));
+        harness.edit_widget(child_tag, |mut child| {
+            assert_eq!(child.get_prop::<Background>(), &blue());
+        });
+    }
+
+    #[test]
+    fn press_changes_background() {
+        let child_tag = WidgetTag::named("child");
+        let child = SizedBox::empty()
+            .size(40.px(), 40.px())
+            .prepare()
+            .with_tag(child_tag);
+        let green = Background::Color(palette::css::GREEN);
+        let widget = Hoverable::new(child)
+            .with_hovered_prop(blue(), red())
+            .with_pressed_prop(green.clone())
+            .prepare();
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (60, 60));
+
+        harness.mouse_move((20., 20.));
+        harness.mouse_button_press(None);
+        harness.edit_widget(child_tag, |mut child| {
+            assert_eq!(child.get_prop::<Background>(), &green);
+        });
+
+        harness.mouse_button_release(None);
+        harness.edit_widget(child_tag, |mut child| {
+            assert_eq!(child.get_prop::<Background>(), &red());
+        });
+    }
+
+    #[test]
+    fn unset_state_restores_child_value() {
+        let child_tag = WidgetTag::named("child");
+        let child = SizedBox::empty()
+            .size(40.px(), 40.px())
+            .prepare()
+            .with_tag(child_tag)
+            .with_props(blue());
+        let green = Background::Color(palette::css::GREEN);
+        let widget = Hoverable::new(child)
+            .with_pressed_prop(green.clone())
+            .prepare();
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (60, 60));
+
+        // Without a normal value, the child keeps its own local value.
+        harness.edit_widget(child_tag, |mut child| {
+            assert!(child.contains_prop::<Background>());
+            assert_eq!(child.get_prop::<Background>(), &blue());
+        });
+
+        harness.mouse_move((20., 20.));
+        harness.mouse_button_press(None);
+        harness.edit_widget(child_tag, |mut child| {
+            assert_eq!(child.get_prop::<Background>(), &green);
+        });
+
+        harness.mouse_button_release(None);
+        harness.mouse_move((50., 50.));
+        harness.edit_widget(child_tag, |mut child| {
+            assert_eq!(child.get_prop::<Background>(), &blue());
+        });
+    }
+}
@@ -4,7 +4,7 @@
 use std::any::TypeId;
 
 use accesskit::{Node, Role};
-use tracing::{Span, trace_span};
+use tracing::{Span, trace_span, warn};
 
 use crate::core::{
     AccessCtx, ArcStr, ChildrenIds, LayoutCtx, MeasureCtx, NoAction, PaintCtx, PropertiesMut,
@@ -12,54 +12,108 @@ use crate::core::{
     WidgetMut,
 };
 use crate::imaging::Painter;
-use crate::kurbo::{Axis, Size};
-use crate::layout::{LenReq, Length};
-use crate::peniko::ImageBrush;
-use crate::properties::ObjectFit;
-
-// TODO: Make this a configurable option of the widget.
-/// The scale that the image is native to.
-///
-/// Used to calculate the preferred logical size of the image.
-///
-/// For example you might have a 256 px icon meant for crisp details on 8K screens.
-/// Then the image could have an preferred scale of e.g. 4.0,
-/// meaning that its preferred logical size is 64px.
-/// That way the image looks good at any scale and doesn't shift the layout around.
-const IMAGE_SCALE: f64 = 1.0;
+use crate::kurbo::{Affine, Axis, Rect, Shape as _, Size};
+use crate::layout::{LenReq, Length, UnitPoint};
+use crate::peniko::{Color, ImageBrush, ImageFormat, ImageQuality};
+use crate::properties::{AltTextFallback, BorderWidth, CornerRadius, ObjectFit};
+use crate::util::debug_panic;
 
 // TODO - Resolve name collision between masonry::Image and peniko::Image
 
+/// How an [`Image`] samples its pixels when it is drawn at a different size than its data.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ImageInterpolation {
+    /// Blends neighboring pixels, which gives smooth results for photos and illustrations.
+    #[default]
+    Bilinear,
+    /// Uses the nearest pixel, which keeps hard edges, e.g. for pixel art.
+    NearestNeighbor,
+}
+
 /// A widget that renders a bitmap Image.
 ///
 /// The underlying image uses `Arc` for buffer data, making it cheap to clone.
 ///
-/// This currently uses bilinear interpolation, which falls down when the image is
-/// larger than its layout size (e.g. it is in a [sized box](super::SizedBox) smaller
-/// than the image size).
+/// By default the image is sampled with the quality of its [`ImageBrush`]'s sampler,
+/// which is bilinear interpolation unless the brush says otherwise.
+/// Bilinear interpolation falls down when the image is larger than its layout size
+/// (e.g. it is in a [sized box](super::SizedBox) smaller than the image size).
+/// For pixel art, which should stay crisp when scaled up, use
+/// [`ImageInterpolation::NearestNeighbor`] instead.
+///
+/// The image is sampled at device-pixel resolution: its transform is computed in logical
+/// pixels, and scaled along with the rest of the scene by the window's scale factor.
+/// For instance, a 100x100 image laid out at 50x50 logical pixels is drawn without any
+/// resampling at a scale factor of 2.
 ///
 /// You can change the sizing of the image with the [`ObjectFit`] property.
+/// To change it for every image of an app, insert it in the [`DefaultProperties`] for `Image`,
+/// e.g. `properties.insert::<Image, _>(ObjectFit::Cover)`.
+/// An `ObjectFit` set on a given image still takes precedence.
+///
+/// The image's corners are rounded according to the [`CornerRadius`] property,
+/// reduced by the [`BorderWidth`] like the background,
+/// and clamped to half of the smaller side of the image's box.
+///
+/// The image data is only handed to the renderer, which uploads it to the GPU,
+/// on the first paint with a non-zero size.
+/// An image which is never painted, e.g. because it is off-screen in a
+/// [virtual list](super::VirtualScroll), or which is painted with a zero size,
+/// thus doesn't use any GPU memory.
+/// Replacing the data before that just swaps the pending data.
+///
+/// [`DefaultProperties`]: crate::core::DefaultProperties
 pub struct Image {
     image_data: ImageBrush,
     decorative: bool,
     alt_text: Option<ArcStr>,
+    interpolation: Option<ImageInterpolation>,
+    alignment: UnitPoint,
+    color_key: Option<Color>,
+    pixel_snap: bool,
+    content_scale: f64,
+    /// The image handed to the renderer, with the color key applied.
+    ///
+    /// This is `None` until the image is painted with a non-zero size,
+    /// and is reset when the drawn image changes.
+    drawn_image: Option<ImageBrush>,
+    placeholder: Option<ImageBrush>,
 }
 
 // --- MARK: BUILDERS
 impl Image {
     /// Creates an image drawing widget from an image buffer.
     ///
-    /// By default, the image will be scaled to fully fit within the container.
-    /// ([`ObjectFit::Contain`]).
+    /// By default, the image will be scaled to fully fit within the container
+    /// ([`ObjectFit::Contain`]), unless the default properties say otherwise.
     #[inline]
     pub fn new(image_data: impl Into<ImageBrush>) -> Self {
         Self {
             image_data: image_data.into(),
             decorative: false,
             alt_text: None,
+            interpolation: None,
+            alignment: UnitPoint::CENTER,
+            color_key: None,
+            pixel_snap: false,
+            content_scale: 1.0,
+            drawn_image: None,
+            placeholder: None,
         }
     }
 
+    /// Sets an image which is shown instead of the image data while the latter is empty.
+    ///
+    /// This is useful for images which are loaded asynchronously: the image can be created
+    /// with empty data, which is replaced with [`set_image_data`](Self::set_image_data) once loaded.
+    /// While the placeholder is shown, the image is also sized according to the placeholder.
+    ///
+    /// The [color key](Self::with_color_key) isn't applied to the placeholder.
+    pub fn with_placeholder(mut self, placeholder: impl Into<ImageBrush>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
     /// Specifies whether the image is decorative, meaning it doesn't have meaningful content
     /// and is only for visual presentation.
     ///
@@ -74,21 +128,101 @@ impl Image {
     /// Users are encouraged to set alt text for the image.
     /// If possible, the alt-text should succinctly describe what the image represents.
     ///
-    /// If the image is decorative users should set alt text to `""`.
-    /// If it's too hard to describe through text, the alt text should be left unset.
-    /// This allows accessibility clients to know that there is no accessible description of the image content.
+    /// The alt text is exposed as the label of the image's accessibility node.
+    /// If the image is decorative users should set alt text to `""`, which has
+    /// the same effect as [`decorative`](Self::decorative).
+    ///
+    /// Images without alt text are hidden from screen readers like decorative ones,
+    /// unless an [`AltTextFallback`] hook provides alt text for them.
     pub fn with_alt_text(mut self, alt_text: impl Into<ArcStr>) -> Self {
         self.alt_text = Some(alt_text.into());
         self
     }
+
+    /// Sets how the image is sampled when it is scaled.
+    ///
+    /// This overrides the sampler quality of the image data.
+    /// By default, that quality is kept as is.
+    pub fn with_interpolation(mut self, interpolation: ImageInterpolation) -> Self {
+        self.interpolation = Some(interpolation);
+        self
+    }
+
+    /// Sets where the image is placed when it doesn't fill its box.
+    ///
+    /// This applies when the [`ObjectFit`] leaves some space around the image,
+    /// e.g. with [`ObjectFit::Contain`] and an image with a different aspect ratio than its box.
+    /// With fits which make the image overflow, such as [`ObjectFit::Cover`],
+    /// it chooses which part of the image is visible.
+    ///
+    /// The default is [`UnitPoint::CENTER`].
+    pub fn with_alignment(mut self, alignment: UnitPoint) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Specifies whether the image is drawn at a whole device pixel offset.
+    ///
+    /// When the [`ObjectFit`] and alignment place the image at a fractional offset,
+    /// sampling blends neighboring pixels, which causes shimmer when the image moves.
+    /// With pixel snapping, the offset is rounded to whole device pixels instead.
+    /// This is useful for pixel art, along with [`ImageInterpolation::NearestNeighbor`].
+    ///
+    /// The offset is rounded within the image's box, whose origin is already snapped by layout.
+    /// Transforms and scrolling by fractional amounts aren't compensated for.
+    ///
+    /// The default is `false`.
+    pub fn with_pixel_snap(mut self, pixel_snap: bool) -> Self {
+        self.pixel_snap = pixel_snap;
+        self
+    }
+
+    /// Sets the scale that the image data is native to.
+    ///
+    /// The image's natural logical size is its data size divided by this scale.
+    /// For example a 256px icon meant for crisp details on high density screens
+    /// could have a content scale of 4.0, meaning that its natural logical size is 64px.
+    /// That way the image looks sharp at high scale factors and doesn't shift the layout around.
+    ///
+    /// This is independent of the [`ObjectFit`], which still decides how the natural size
+    /// maps to the image's box, e.g. [`ObjectFit::None`] draws the image at its natural size.
+    ///
+    /// The scale must be finite and positive.
+    /// Other values will fall back to `1.0` with a logged warning.
+    ///
+    /// The default is `1.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is non-finite or non-positive and debug assertions are enabled.
+    pub fn with_content_scale(mut self, scale: f64) -> Self {
+        self.content_scale = sanitize_content_scale(scale);
+        self
+    }
+
+    /// Makes pixels of the given color transparent.
+    ///
+    /// This is meant for legacy assets, such as sprites using magenta as a transparent color.
+    /// Only the RGB channels are compared, and they must match exactly.
+    ///
+    /// The image data is processed once, on the first paint after the key or the image changes.
+    /// Only RGBA and BGRA image formats are supported; other formats are drawn as-is.
+    pub fn with_color_key(mut self, color_key: Color) -> Self {
+        self.color_key = Some(color_key);
+        self
+    }
 }
 
 // --- MARK: WIDGETMUT
 impl Image {
     /// Sets new `ImageBrush`.
+    ///
+    /// The new data is only processed once painted,
+    /// so replacing the data of an image which wasn't painted yet is cheap.
     #[inline]
     pub fn set_image_data(this: &mut WidgetMut<'_, Self>, image_data: impl Into<ImageBrush>) {
         this.widget.image_data = image_data.into();
+        this.widget.drawn_image = None;
         this.ctx.request_layout();
     }
 
@@ -108,24 +242,177 @@ impl Image {
         this.widget.alt_text = alt_text.map(Into::into);
         this.ctx.request_accessibility_update();
     }
+
+    /// Sets how the image is sampled when it is scaled.
+    ///
+    /// `None` keeps the sampler quality of the image data.
+    /// See [`Image::with_interpolation`] for details.
+    pub fn set_interpolation(
+        this: &mut WidgetMut<'_, Self>,
+        interpolation: impl Into<Option<ImageInterpolation>>,
+    ) {
+        this.widget.interpolation = interpolation.into();
+        this.ctx.request_paint_only();
+    }
+
+    /// Sets where the image is placed when it doesn't fill its box.
+    ///
+    /// See [`Image::with_alignment`] for details.
+    pub fn set_alignment(this: &mut WidgetMut<'_, Self>, alignment: UnitPoint) {
+        this.widget.alignment = alignment;
+        this.ctx.request_paint_only();
+    }
+
+    /// Sets whether the image is drawn at a whole device pixel offset.
+    ///
+    /// See [`Image::with_pixel_snap`] for details.
+    pub fn set_pixel_snap(this: &mut WidgetMut<'_, Self>, pixel_snap: bool) {
+        this.widget.pixel_snap = pixel_snap;
+        this.ctx.request_paint_only();
+    }
+
+    /// Sets the scale that the image data is native to.
+    ///
+    /// See [`Image::with_content_scale`] for details.
+    pub fn set_content_scale(this: &mut WidgetMut<'_, Self>, scale: f64) {
+        this.widget.content_scale = sanitize_content_scale(scale);
+        this.ctx.request_layout();
+    }
+
+    /// Sets or removes the placeholder image.
+    ///
+    /// See [`Image::with_placeholder`] for details.
+    pub fn set_placeholder(
+        this: &mut WidgetMut<'_, Self>,
+        placeholder: Option<impl Into<ImageBrush>>,
+    ) {
+        this.widget.placeholder = placeholder.map(Into::into);
+        this.widget.drawn_image = None;
+        this.ctx.request_layout();
+    }
+
+    /// Sets or removes the color which is made transparent.
+    ///
+    /// See [`Image::with_color_key`] for details.
+    pub fn set_color_key(this: &mut WidgetMut<'_, Self>, color_key: Option<Color>) {
+        this.widget.color_key = color_key;
+        this.widget.drawn_image = None;
+        this.ctx.request_paint_only();
+    }
 }
 
+// --- MARK: METHODS
 impl Image {
-    /// Returns the preferred size of the image.
+    /// Returns the preferred size of the image, i.e. its natural size.
     ///
     /// The returned size is in logical pixels.
     ///
-    /// This takes into account [`IMAGE_SCALE`], so a high-resolution resource can
-    /// have a stable preferred logical size.
-    fn preferred_size(&self) -> Size {
+    /// This takes into account the [content scale](Self::with_content_scale),
+    /// so a high-resolution resource can have a stable preferred logical size.
+    ///
+    /// This is what the image reports to containers which measure it
+    /// without any available space, e.g. when asking for its `MaxContent` size.
+    /// That holds for every [`ObjectFit`], including [`ObjectFit::Stretch`], even though
+    /// a stretched image will happily distort to fill any space it is actually given.
+    /// Reporting the natural size gives containers a sensible size to build around,
+    /// instead of the stretched image claiming zero or infinite space.
+    ///
+    /// While the [placeholder](Self::with_placeholder) is shown, this is the placeholder's size.
+    pub fn preferred_size(&self) -> Size {
+        let image = &self.displayed_image().image;
         Size::new(
-            self.image_data.image.width as f64 / IMAGE_SCALE,
-            self.image_data.image.height as f64 / IMAGE_SCALE,
+            image.width as f64 / self.content_scale,
+            image.height as f64 / self.content_scale,
         )
     }
+
+    /// Returns `true` if the placeholder is shown instead of the image data.
+    pub fn shows_placeholder(&self) -> bool {
+        let image = &self.image_data.image;
+        self.placeholder.is_some() && (image.width == 0 || image.height == 0)
+    }
+}
+
+impl Image {
+    /// Returns the image which is shown, before the color key is applied.
+    fn displayed_image(&self) -> &ImageBrush {
+        match &self.placeholder {
+            Some(placeholder) if self.shows_placeholder() => placeholder,
+            _ => &self.image_data,
+        }
+    }
+
+    /// Returns the transform which maps the image data to the content box.
+    ///
+    /// This maps the image to logical pixels, and the renderer applies the scale factor on top.
+    /// We must not pre-scale the image data, as that would sample it at logical resolution.
+    fn fill_transform(
+        &self,
+        content_box: Rect,
+        object_fit: ObjectFit,
+        scale_factor: f64,
+    ) -> Affine {
+        // The fit is computed for the natural logical size, and then the image data
+        // is scaled down to it, so that the content scale doesn't depend on the fit.
+        let transform = object_fit
+            .affine_aligned(content_box, self.preferred_size().to_rect(), self.alignment)
+            .pre_scale(1. / self.content_scale);
+        if !self.pixel_snap {
+            return transform;
+        }
+        let [a, b, c, d, x, y] = transform.as_coeffs();
+        let snap = |value: f64| (value * scale_factor).round() / scale_factor;
+        Affine::new([a, b, c, d, snap(x), snap(y)])
+    }
+
+    /// Returns the image to hand to the renderer, creating it if needed.
+    fn drawn_image(&mut self) -> &ImageBrush {
+        if self.drawn_image.is_none() {
+            let image = match self.color_key {
+                Some(key) if !self.shows_placeholder() => apply_color_key(&self.image_data, key),
+                _ => None,
+            };
+            self.drawn_image = Some(image.unwrap_or_else(|| self.displayed_image().clone()));
+        }
+        self.drawn_image.as_ref().unwrap()
+    }
+}
+
+fn sanitize_content_scale(scale: f64) -> f64 {
+    if scale.is_finite() && scale > 0. {
+        scale
+    } else {
+        debug_panic!("Image content scale must be finite and positive; got {scale}");
+        1.0
+    }
+}
+
+/// Returns a copy of `image` where pixels matching `key` are fully transparent.
+///
+/// Returns `None` if the image format isn't supported.
+fn apply_color_key(image: &ImageBrush, key: Color) -> Option<ImageBrush> {
+    let key = key.to_rgba8();
+    let key = match image.image.format {
+        ImageFormat::Rgba8 => [key.r, key.g, key.b],
+        ImageFormat::Bgra8 => [key.b, key.g, key.r],
+        format => {
+            warn!("Image color key isn't supported for format {format:?}");
+            return None;
+        }
+    };
+    let mut data = image.image.data.data().to_vec();
+    for pixel in data.chunks_exact_mut(4) {
+        if pixel[..3] == key {
+            pixel.copy_from_slice(&[0; 4]);
+        }
+    }
+    let mut keyed = image.clone();
+    keyed.image.data = data.into();
+    Some(keyed)
 }
 
 impl UsesProperty<ObjectFit> for Image {}
+impl UsesProperty<AltTextFallback> for Image {}
 
 // --- MARK: IMPL WIDGET
 impl Widget for Image {
@@ -137,6 +424,9 @@ impl Widget for Image {
         if ObjectFit::matches(property_type) {
             ctx.request_layout();
         }
+        if AltTextFallback::matches(property_type) {
+            ctx.request_accessibility_update();
+        }
     }
 
     fn update(
@@ -171,19 +461,40 @@ impl Widget for Image {
         painter: &mut Painter<'_>,
     ) {
         let content_box = ctx.content_box();
+        if content_box.is_zero_area() {
+            return;
+        }
+
         let cache = ctx.property_cache();
         let object_fit = props.get::<ObjectFit>(cache);
-        // For drawing we want to scale the actual image data lengths, which means
-        // we need to avoid using Image::preferred_size which does not match the data.
-        let image_size = Size::new(
-            self.image_data.image.width as f64,
-            self.image_data.image.height as f64,
-        );
-        let transform = object_fit.affine(content_box, image_size.to_rect());
+        let corner_radius = props.get::<CornerRadius>(cache);
+        let border_width = props.get::<BorderWidth>(cache);
+        let max_radius = content_box.width().min(content_box.height()) / 2.;
+        let radius = corner_radius
+            .radius
+            .saturating_sub(border_width.max_width())
+            .get()
+            .min(max_radius);
+        let transform = self.fill_transform(content_box, *object_fit, ctx.scale_factor());
+        let mut image = self.drawn_image().clone();
 
-        painter.with_fill_clip(content_box, |painter| {
-            painter.draw_image(&self.image_data, transform);
-        });
+        if let Some(interpolation) = self.interpolation {
+            image.sampler.quality = match interpolation {
+                ImageInterpolation::Bilinear => ImageQuality::Medium,
+                ImageInterpolation::NearestNeighbor => ImageQuality::Low,
+            };
+        }
+
+        if radius > 0. {
+            let clip = content_box.to_rounded_rect(radius).to_path(0.1);
+            painter.with_fill_clip_transformed(clip, Affine::IDENTITY, |painter| {
+                painter.draw_image(&image, transform);
+            });
+        } else {
+            painter.with_fill_clip(content_box, |painter| {
+                painter.draw_image(&image, transform);
+            });
+        }
     }
 
     fn accessibility_role(&self) -> Role {
@@ -192,15 +503,23 @@ impl Widget for Image {
 
     fn accessibility(
         &mut self,
-        _ctx: &mut AccessCtx<'_>,
-        _props: &PropertiesRef<'_>,
+        ctx: &mut AccessCtx<'_>,
+        props: &PropertiesRef<'_>,
         node: &mut Node,
     ) {
-        if let Some(alt_text) = &self.alt_text {
-            node.set_description(&**alt_text);
-        }
         if self.decorative {
             node.set_hidden();
+            return;
+        }
+        let alt_text = self.alt_text.clone().or_else(|| {
+            let fallback = props.get::<AltTextFallback>(ctx.property_cache());
+            fallback.hook.and_then(|hook| hook(&self.image_data))
+        });
+        match alt_text.as_deref() {
+            // Like in HTML, empty alt text marks the image as decorative.
+            // Images without alt text have nothing to announce, so they are hidden too.
+            None | Some("") => node.set_hidden(),
+            Some(alt_text) => node.set_label(alt_text),
         }
     }
 
@@ -219,9 +538,13 @@ impl Widget for Image {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::NewWidget;
-    use crate::peniko::{ImageAlphaType, ImageData, ImageFormat};
-    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::core::{NewWidget, WidgetTag};
+    use crate::kurbo::Point;
+    use crate::layout::{AsUnit, LenDef, SizeDef};
+    use crate::peniko::{ImageAlphaType, ImageData};
+    use crate::properties::Dimensions;
+    use crate::testing::{ModularWidget, TestHarness, TestHarnessParams, assert_render_snapshot};
+    use crate::tests::{checkerboard_image, matches_checkerboard};
     use crate::theme::test_property_set;
 
     /// Painting an empty image shouldn't crash.
@@ -270,6 +593,380 @@ mod tests {
         assert_render_snapshot!(harness, "image_tall_paint");
     }
 
+    #[test]
+    fn content_scale() {
+        // A one-pixel checkerboard meant for a scale factor of 2, which would blur if resampled.
+        const SIDE: u32 = 8;
+        let image_data = checkerboard_image(SIDE);
+        let image_widget = NewWidget::new(Image::new(image_data).with_content_scale(2.0))
+            .with_props(ObjectFit::None);
+
+        // The box is 4x4 logical pixels, and the image is drawn at its natural size.
+        let params = TestHarnessParams::default()
+            .with_size((SIDE, SIDE))
+            .with_scale(2.0);
+        let mut harness = TestHarness::create_with(test_property_set(), image_widget, params);
+        assert_eq!(harness.root_widget().preferred_size(), Size::new(4., 4.));
+        assert_eq!(
+            harness.root_widget().ctx().content_box().size(),
+            Size::new(4., 4.)
+        );
+
+        let render = harness.render();
+        // Hack: If we are using `SKIP_RENDER_TESTS`, the output image is a 1x1 white pixel.
+        if std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+            return;
+        }
+        // Every image pixel maps to exactly one device pixel.
+        assert!(matches_checkerboard(&render));
+
+        // At the default content scale, the same box only shows a blown up part of the image.
+        harness.edit_root_widget(|mut image| {
+            Image::set_content_scale(&mut image, 1.0);
+        });
+        assert_eq!(harness.root_widget().preferred_size(), Size::new(8., 8.));
+        assert!(!matches_checkerboard(&harness.render()));
+    }
+
+    #[test]
+    fn interpolation_modes() {
+        // A 2x2 checkerboard, scaled up to 40x40.
+        let image_data = ImageData {
+            data: [
+                [255, 255, 255, 255],
+                [000, 000, 000, 255],
+                [000, 000, 000, 255],
+                [255, 255, 255, 255],
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .into(),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width: 2,
+            height: 2,
+        };
+        let image_widget = NewWidget::new(Image::new(image_data.clone()));
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), image_widget, (40, 40));
+        assert_render_snapshot!(harness, "image_interpolation_bilinear");
+
+        harness.edit_root_widget(|mut image| {
+            Image::set_interpolation(&mut image, ImageInterpolation::NearestNeighbor);
+        });
+        assert_render_snapshot!(harness, "image_interpolation_nearest");
+        let nearest = harness.render();
+
+        // Without an interpolation mode, the quality set on the brush is kept.
+        let brush = ImageBrush::new(image_data).with_quality(ImageQuality::Low);
+        let image_widget = NewWidget::new(Image::new(brush));
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), image_widget, (40, 40));
+        assert!(harness.render() == nearest);
+
+        harness.edit_root_widget(|mut image| {
+            Image::set_interpolation(&mut image, ImageInterpolation::Bilinear);
+        });
+        assert!(harness.render() != nearest);
+    }
+
+    #[test]
+    fn pixel_snap() {
+        // A 4x2 image contained in a 10x10 box is scaled by 2.5 and centered at y = 2.5.
+        let image_data = ImageData {
+            data: vec![255; 4 * 2 * 4].into(),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width: 4,
+            height: 2,
+        };
+        let content_box = Rect::new(0., 0., 10., 10.);
+        let translation = |image: &Image, scale_factor| {
+            image
+                .fill_transform(content_box, ObjectFit::Contain, scale_factor)
+                .translation()
+        };
+
+        let image = Image::new(image_data);
+        assert_eq!(translation(&image, 1.).y, 2.5);
+
+        let image = image.with_pixel_snap(true);
+        let snapped = translation(&image, 1.);
+        assert_eq!(snapped.y.fract(), 0.);
+        assert!((snapped.y - 2.5).abs() <= 0.5);
+        // At a scale factor of 2, 2.5 logical pixels is already a whole device pixel offset.
+        assert_eq!(translation(&image, 2.).y, 2.5);
+        // Only the translation is snapped.
+        let scale = image
+            .fill_transform(content_box, ObjectFit::Contain, 1.)
+            .as_coeffs()[0];
+        assert_eq!(scale, 2.5);
+    }
+
+    #[test]
+    fn matches_affine_to_fill() {
+        let image_data = ImageData {
+            data: vec![255; 200 * 200 * 4].into(),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width: 200,
+            height: 200,
+        };
+        let image = Image::new(image_data);
+        let content_box = Rect::new(0., 0., 100., 50.);
+
+        assert_eq!(
+            image.fill_transform(content_box, ObjectFit::Contain, 1.),
+            ObjectFit::Contain.affine_to_fill(content_box.size(), Size::new(200., 200.)),
+        );
+    }
+
+    #[test]
+    fn contain_alignment() {
+        // A tall image: white on top, black at the bottom.
+        let image_data = ImageData {
+            data: [[255, 255, 255, 255], [000, 000, 000, 255]]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .into(),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width: 1,
+            height: 2,
+        };
+        let image_widget = NewWidget::new(
+            Image::new(image_data)
+                .with_interpolation(ImageInterpolation::NearestNeighbor)
+                .with_alignment(UnitPoint::TOP_LEFT),
+        );
+
+        // The box is wide, so the contained image leaves space on its sides.
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), image_widget, (80, 40));
+        assert_render_snapshot!(harness, "image_alignment_top_left");
+
+        harness.edit_root_widget(|mut image| {
+            Image::set_alignment(&mut image, UnitPoint::BOTTOM_RIGHT);
+        });
+        assert_render_snapshot!(harness, "image_alignment_bottom_right");
+    }
+
+    #[test]
+    fn color_key() {
+        const MAGENTA: [u8; 4] = [255, 0, 255, 255];
+        const WHITE: [u8; 4] = [255, 255, 255, 255];
+        let image_data = ImageData {
+            data: [MAGENTA, WHITE]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .into(),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width: 2,
+            height: 1,
+        };
+        let image_widget = NewWidget::new(
+            Image::new(image_data)
+                .with_interpolation(ImageInterpolation::NearestNeighbor)
+                .with_color_key(Color::from_rgb8(255, 0, 255)),
+        );
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), image_widget, (20, 10));
+        let render = harness.render();
+        // Hack: If we are using `SKIP_RENDER_TESTS`, the output image is a 1x1 white pixel.
+        if std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+            return;
+        }
+
+        // The keyed half shows the background, the other half is untouched.
+        let background = TestHarnessParams::DEFAULT_BACKGROUND_COLOR.to_rgba8();
+        assert_eq!(
+            render.get_pixel(5, 5).0,
+            [background.r, background.g, background.b, background.a]
+        );
+        assert_eq!(render.get_pixel(15, 5).0, WHITE);
+    }
+
+    #[test]
+    fn image_not_uploaded_until_painted() {
+        let magenta = |width| ImageData {
+            data: [255, 0, 255, 255].repeat(width as usize).into(),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width,
+            height: 1,
+        };
+        let tag = WidgetTag::named("image");
+        let image_widget = Image::new(magenta(1))
+            .prepare()
+            .with_tag(tag)
+            .with_props(Dimensions::fixed(0.px(), 0.px()));
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), image_widget, (20, 10));
+        let _ = harness.render();
+
+        // An image painted with a zero size isn't handed to the renderer.
+        assert!(harness.get_widget(tag).drawn_image.is_none());
+
+        // Replacing the data before the first paint just swaps the pending data.
+        harness.edit_widget(tag, |mut image| {
+            Image::set_image_data(&mut image, magenta(2));
+        });
+        let _ = harness.render();
+        assert!(harness.get_widget(tag).drawn_image.is_none());
+
+        harness.edit_widget(tag, |mut image| {
+            image.insert_prop(Dimensions::fixed(20.px(), 10.px()));
+        });
+        let _ = harness.render();
+        let drawn = harness.get_widget(tag).drawn_image.clone().unwrap();
+        assert_eq!(drawn.image.width, 2);
+
+        // Changing the data discards the drawn image until the next paint.
+        harness.edit_widget(tag, |mut image| {
+            Image::set_image_data(&mut image, magenta(3));
+        });
+        assert!(harness.get_widget(tag).drawn_image.is_none());
+    }
+
+    #[test]
+    fn color_key_applied_on_first_paint() {
+        let magenta = ImageData {
+            data: [255, 0, 255, 255].into(),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width: 1,
+            height: 1,
+        };
+        let tag = WidgetTag::named("image");
+        let image_widget = Image::new(magenta)
+            .with_color_key(Color::from_rgb8(255, 0, 255))
+            .prepare()
+            .with_tag(tag);
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), image_widget, (20, 10));
+        assert!(harness.get_widget(tag).drawn_image.is_none());
+
+        let _ = harness.render();
+        let drawn = harness.get_widget(tag).drawn_image.clone().unwrap();
+        assert_eq!(drawn.image.data.data(), [0; 4]);
+    }
+
+    #[test]
+    fn cover_wide_image_in_tall_box() {
+        const MAGENTA: [u8; 4] = [255, 0, 255, 255];
+        let image_data = ImageData {
+            data: MAGENTA.repeat(4).into(),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width: 4,
+            height: 1,
+        };
+        let image_widget = Image::new(image_data)
+            .with_interpolation(ImageInterpolation::NearestNeighbor)
+            .prepare()
+            .with_props(ObjectFit::Cover);
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), image_widget, (20, 40));
+        let render = harness.render();
+        // Hack: If we are using `SKIP_RENDER_TESTS`, the output image is a 1x1 white pixel.
+        if std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+            return;
+        }
+
+        // The image is scaled to the box's height, so it covers the whole box.
+        for (x, y) in [(0, 0), (19, 0), (10, 20), (0, 39), (19, 39)] {
+            assert_eq!(render.get_pixel(x, y).0, MAGENTA, "pixel ({x}, {y})");
+        }
+
+        // Whereas with `Contain` it is scaled to the box's width, leaving the corners empty.
+        harness.edit_root_widget(|mut image| {
+            image.insert_prop(ObjectFit::Contain);
+        });
+        let render = harness.render();
+        let background = TestHarnessParams::DEFAULT_BACKGROUND_COLOR.to_rgba8();
+        assert_eq!(
+            render.get_pixel(0, 0).0,
+            [background.r, background.g, background.b, background.a]
+        );
+        assert_eq!(render.get_pixel(10, 20).0, MAGENTA);
+    }
+
+    #[test]
+    fn corner_radius() {
+        let image_data = ImageData {
+            data: vec![255; 4 * 8 * 8].into(),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width: 8,
+            height: 8,
+        };
+        let image_widget = Image::new(image_data)
+            .prepare()
+            .with_props(CornerRadius::all(20.px()));
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), image_widget, (100, 100));
+        assert_render_snapshot!(harness, "image_corner_radius");
+    }
+
+    #[test]
+    fn placeholder() {
+        const MAGENTA: [u8; 4] = [255, 0, 255, 255];
+        const WHITE: [u8; 4] = [255, 255, 255, 255];
+        let empty_data = ImageData {
+            data: Vec::new().into(),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width: 0,
+            height: 0,
+        };
+        let placeholder_data = ImageData {
+            data: MAGENTA.repeat(2).into(),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width: 2,
+            height: 1,
+        };
+        let image_data = ImageData {
+            data: WHITE.repeat(4).into(),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width: 2,
+            height: 2,
+        };
+        let image_widget =
+            NewWidget::new(Image::new(empty_data).with_placeholder(placeholder_data));
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), image_widget, (20, 20));
+        assert!(harness.root_widget().shows_placeholder());
+        assert_eq!(harness.root_widget().preferred_size(), Size::new(2., 1.));
+        let render = harness.render();
+
+        harness.edit_root_widget(|mut image| {
+            Image::set_image_data(&mut image, image_data);
+        });
+        assert!(!harness.root_widget().shows_placeholder());
+        assert_eq!(harness.root_widget().preferred_size(), Size::new(2., 2.));
+        let loaded_render = harness.render();
+
+        // Hack: If we are using `SKIP_RENDER_TESTS`, the output image is a 1x1 white pixel.
+        if std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
+            return;
+        }
+        assert_eq!(render.get_pixel(10, 10).0, MAGENTA);
+        assert_eq!(loaded_render.get_pixel(10, 10).0, WHITE);
+    }
+
     #[test]
     fn edit_image() {
         let image_data = ImageData {
@@ -369,4 +1066,149 @@ mod tests {
         });
         assert_render_snapshot!(harness, "image_layout_stretch");
     }
+
+    #[test]
+    fn container_uses_preferred_size() {
+        let image_data = ImageData {
+            data: vec![255; 4 * 12 * 6].into(),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width: 12,
+            height: 6,
+        };
+        let image = Image::new(image_data);
+        assert_eq!(image.preferred_size(), Size::new(12., 6.));
+
+        let tag = WidgetTag::unique();
+        let root = ModularWidget::new_parent(NewWidget::new(image).with_tag(tag))
+            .layout_fn(|child, ctx, _, size| {
+                // Ask the image for its preferred size, without offering any space.
+                let child_size = ctx.compute_size(child, SizeDef::MAX, size.into());
+                ctx.run_layout(child, child_size);
+                ctx.place_child(child, Point::ORIGIN);
+            })
+            .prepare();
+        let mut harness = TestHarness::create_with_size(test_property_set(), root, (100, 100));
+
+        for object_fit in [ObjectFit::Contain, ObjectFit::Stretch, ObjectFit::None] {
+            harness.edit_widget(tag, |mut image| {
+                image.insert_prop(object_fit);
+            });
+            let size = harness.get_widget(tag).ctx().border_box().size();
+            assert_eq!(size, Size::new(12., 6.), "{object_fit:?}");
+        }
+    }
+
+    #[test]
+    fn default_object_fit() {
+        let image_data = ImageData {
+            data: vec![255; 4 * 12 * 6].into(),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width: 12,
+            height: 6,
+        };
+
+        let tag = WidgetTag::unique();
+        let root = ModularWidget::new_parent(NewWidget::new(Image::new(image_data)).with_tag(tag))
+            .layout_fn(|child, ctx, _, size| {
+                // Offer a wide area, so that only stretching fills it.
+                let width = ctx.compute_length(
+                    child,
+                    LenDef::FitContent(100.px()),
+                    size.into(),
+                    Axis::Horizontal,
+                    Some(20.px()),
+                );
+                ctx.run_layout(child, Size::new(width.get(), 20.));
+                ctx.place_child(child, Point::ORIGIN);
+            })
+            .prepare();
+
+        let mut properties = test_property_set();
+        properties.insert::<Image, _>(ObjectFit::Stretch);
+        let mut harness = TestHarness::create_with_size(properties, root, (100, 100));
+
+        let image = harness.get_widget(tag);
+        assert_eq!(*image.get_prop::<ObjectFit>(), ObjectFit::Stretch);
+        assert_eq!(image.ctx().border_box().width(), 100.);
+
+        // An explicit fit overrides the default.
+        harness.edit_widget(tag, |mut image| {
+            image.insert_prop(ObjectFit::Contain);
+        });
+        assert_eq!(harness.get_widget(tag).ctx().border_box().width(), 40.);
+    }
+
+    #[test]
+    fn alt_text_label() {
+        let image_tag = WidgetTag::named("image");
+        let image = Image::new(ImageData {
+            data: vec![255; 4].into(),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width: 1,
+            height: 1,
+        })
+        .with_alt_text("A white pixel");
+        let image = NewWidget::new(image).with_tag(image_tag);
+
+        let mut harness = TestHarness::create(test_property_set(), image);
+        let id = harness.get_widget(image_tag).id();
+        let node = harness.access_node(id).unwrap();
+        assert_eq!(node.data().label(), Some("A white pixel"));
+        assert!(!node.data().is_hidden());
+
+        // Empty alt text marks the image as decorative.
+        harness.edit_widget(image_tag, |mut image| {
+            Image::set_alt_text(&mut image, Some(""));
+        });
+        let _ = harness.render();
+        let node = harness.access_node(id).unwrap();
+        assert_eq!(node.data().label(), None);
+        assert!(node.data().is_hidden());
+
+        // So does missing alt text.
+        harness.edit_widget(image_tag, |mut image| {
+            Image::set_alt_text(&mut image, None::<ArcStr>);
+        });
+        let _ = harness.render();
+        let node = harness.access_node(id).unwrap();
+        assert_eq!(node.data().label(), None);
+        assert!(node.data().is_hidden());
+    }
+
+    #[test]
+    fn alt_text_fallback() {
+        fn describe(image: &ImageBrush) -> Option<ArcStr> {
+            let (width, height) = (image.image.width, image.image.height);
+            Some(format!("A {width}x{height} image").into())
+        }
+
+        let image_tag = WidgetTag::named("image");
+        let image = Image::new(ImageData {
+            data: vec![255; 4].into(),
+            format: ImageFormat::Rgba8,
+            alpha_type: ImageAlphaType::Alpha,
+            width: 1,
+            height: 1,
+        });
+        let image = NewWidget::new(image).with_tag(image_tag);
+
+        let mut properties = test_property_set();
+        properties.insert::<Image, _>(AltTextFallback::new(describe));
+        let mut harness = TestHarness::create(properties, image);
+        let id = harness.get_widget(image_tag).id();
+        let node = harness.access_node(id).unwrap();
+        assert_eq!(node.data().label(), Some("A 1x1 image"));
+        assert!(!node.data().is_hidden());
+
+        // Explicit alt text takes precedence.
+        harness.edit_widget(image_tag, |mut image| {
+            Image::set_alt_text(&mut image, Some("A white pixel"));
+        });
+        let _ = harness.render();
+        let node = harness.access_node(id).unwrap();
+        assert_eq!(node.data().label(), Some("A white pixel"));
+    }
 }
@@ -0,0 +1,359 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::{
+    AccessCtx, ChildrenIds, KeyboardEvent, LayoutCtx, MeasureCtx, Modifiers, NoAction, PaintCtx,
+    PropertiesRef, RegisterCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Point, Size};
+use crate::layout::{LayoutSize, LenReq, Length, SizeDef};
+use crate::widgets::Label;
+
+/// A key pressed along with a set of modifiers, e.g. <kbd>Ctrl</kbd>+<kbd>S</kbd>.
+///
+/// The same combo can be used to match keyboard events and to display the shortcut
+/// with a [`KeyboardShortcut`], so the displayed shortcut matches the one which is handled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyCombo {
+    /// The modifiers which must be held.
+    pub modifiers: Modifiers,
+    /// The key which must be pressed.
+    pub key: Key,
+}
+
+/// The platform conventions used to display a [`KeyCombo`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShortcutPlatform {
+    /// Modifier glyphs without separators, e.g. "⌃⇧P".
+    MacOs,
+    /// Modifier names joined with `+`, e.g. "Ctrl+Shift+P", with "Win" for the meta key.
+    Windows,
+    /// Modifier names joined with `+`, e.g. "Ctrl+Shift+P", with "Super" for the meta key.
+    Linux,
+}
+
+/// A non-interactive widget which displays a [`KeyCombo`], e.g. in menus.
+///
+/// The combo is formatted according to a [`ShortcutPlatform`], which defaults
+/// to the platform the app is compiled for.
+///
+/// # Examples
+/// ```
+/// use masonry::core::Modifiers;
+/// use masonry::core::keyboard::Key;
+/// use masonry::widgets::{KeyCombo, KeyboardShortcut};
+///
+/// let save = KeyCombo::new(Modifiers::CONTROL, Key::Character("s".into()));
+/// let shortcut = KeyboardShortcut::new(save);
+/// ```
+pub struct KeyboardShortcut {
+    combo: KeyCombo,
+    platform: ShortcutPlatform,
+    label: WidgetPod<Label>,
+}
+
+// --- MARK: KEYCOMBO
+impl KeyCombo {
+    /// Creates a new `KeyCombo`.
+    pub fn new(modifiers: Modifiers, key: Key) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// Returns `true` if the event is a press of this combo.
+    ///
+    /// Character keys are compared case-insensitively, since <kbd>Shift</kbd>
+    /// changes the reported character.
+    /// Only the <kbd>Ctrl</kbd>, <kbd>Alt</kbd>, <kbd>Shift</kbd>, and <kbd>Meta</kbd> modifiers
+    /// are compared, so that lock keys like <kbd>CapsLock</kbd> don't prevent a match.
+    pub fn matches(&self, event: &KeyboardEvent) -> bool {
+        const COMBO_MODIFIERS: Modifiers = Modifiers::CONTROL
+            .union(Modifiers::ALT)
+            .union(Modifiers::SHIFT)
+            .union(Modifiers::META);
+        if !event.state.is_down()
+            || event.modifiers & COMBO_MODIFIERS != self.modifiers & COMBO_MODIFIERS
+        {
+            return false;
+        }
+        match (&event.key, &self.key) {
+            (Key::Character(pressed), Key::Character(key)) => pressed.eq_ignore_ascii_case(key),
+            (pressed, key) => pressed == key,
+        }
+    }
+
+    /// Formats the combo according to the conventions of the given platform.
+    pub fn display(&self, platform: ShortcutPlatform) -> String {
+        let key = key_name(&self.key, platform);
+        if platform == ShortcutPlatform::MacOs {
+            // Apple's Human Interface Guidelines order: Control, Option, Shift, Command.
+            let modifiers = [
+                (Modifiers::CONTROL, "⌃"),
+                (Modifiers::ALT, "⌥"),
+                (Modifiers::SHIFT, "⇧"),
+                (Modifiers::META, "⌘"),
+            ];
+            let mut text: String = modifiers
+                .into_iter()
+                .filter(|(modifier, _)| self.modifiers.contains(*modifier))
+                .map(|(_, glyph)| glyph)
+                .collect();
+            text.push_str(&key);
+            text
+        } else {
+            let meta = match platform {
+                ShortcutPlatform::Windows => "Win",
+                _ => "Super",
+            };
+            let modifiers = [
+                (Modifiers::CONTROL, "Ctrl"),
+                (Modifiers::ALT, "Alt"),
+                (Modifiers::SHIFT, "Shift"),
+                (Modifiers::META, meta),
+            ];
+            let mut parts: Vec<&str> = modifiers
+                .into_iter()
+                .filter(|(modifier, _)| self.modifiers.contains(*modifier))
+                .map(|(_, name)| name)
+                .collect();
+            parts.push(&key);
+            parts.join("+")
+        }
+    }
+}
+
+fn key_name(key: &Key, platform: ShortcutPlatform) -> String {
+    let is_mac = platform == ShortcutPlatform::MacOs;
+    let name = match key {
+        Key::Character(c) if c == " " => "Space",
+        Key::Character(c) => return c.to_uppercase(),
+        Key::Named(NamedKey::ArrowLeft) => "←",
+        Key::Named(NamedKey::ArrowRight) => "→",
+        Key::Named(NamedKey::ArrowUp) => "↑",
+        Key::Named(NamedKey::ArrowDown) => "↓",
+        Key::Named(NamedKey::Enter) if is_mac => "↩",
+        Key::Named(NamedKey::Escape) if is_mac => "⎋",
+        Key::Named(NamedKey::Tab) if is_mac => "⇥",
+        Key::Named(NamedKey::Backspace) if is_mac => "⌫",
+        Key::Named(NamedKey::Delete) if is_mac => "⌦",
+        Key::Named(NamedKey::Escape) => "Esc",
+        Key::Named(NamedKey::Delete) => "Del",
+        Key::Named(named) => return format!("{named:?}"),
+    };
+    name.to_string()
+}
+
+impl ShortcutPlatform {
+    /// The platform the app is compiled for.
+    pub fn current() -> Self {
+        if cfg!(any(target_os = "macos", target_os = "ios")) {
+            Self::MacOs
+        } else if cfg!(target_os = "windows") {
+            Self::Windows
+        } else {
+            Self::Linux
+        }
+    }
+}
+
+impl Default for ShortcutPlatform {
+    fn default() -> Self {
+        Self::current()
+    }
+}
+
+// --- MARK: BUILDERS
+impl KeyboardShortcut {
+    /// Creates a widget displaying the given combo for the current platform.
+    pub fn new(combo: KeyCombo) -> Self {
+        let platform = ShortcutPlatform::current();
+        let label = Label::new(combo.display(platform)).prepare().to_pod();
+        Self {
+            combo,
+            platform,
+            label,
+        }
+    }
+
+    /// Builder-style method to set the platform whose conventions are used.
+    pub fn with_platform(mut self, platform: ShortcutPlatform) -> Self {
+        self.platform = platform;
+        self.label = Label::new(self.combo.display(platform)).prepare().to_pod();
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl KeyboardShortcut {
+    /// Returns the displayed combo.
+    pub fn combo(&self) -> &KeyCombo {
+        &self.combo
+    }
+
+    /// Returns the displayed text.
+    pub fn text(&self) -> String {
+        self.combo.display(self.platform)
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl KeyboardShortcut {
+    /// Sets the displayed combo.
+    pub fn set_combo(this: &mut WidgetMut<'_, Self>, combo: KeyCombo) {
+        this.widget.combo = combo;
+        Self::update_text(this);
+    }
+
+    /// Sets the platform whose conventions are used.
+    pub fn set_platform(this: &mut WidgetMut<'_, Self>, platform: ShortcutPlatform) {
+        this.widget.platform = platform;
+        Self::update_text(this);
+    }
+
+    /// Returns a mutable reference to the label, e.g. to change its style.
+    pub fn label_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, Label> {
+        this.ctx.get_mut(&mut this.widget.label)
+    }
+
+    fn update_text(this: &mut WidgetMut<'_, Self>) {
+        let text = this.widget.text();
+        Label::set_text(&mut Self::label_mut(this), text);
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for KeyboardShortcut {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.label);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        let auto_length = len_req.into();
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+
+        ctx.compute_length(
+            &mut self.label,
+            auto_length,
+            context_size,
+            axis,
+            cross_length,
+        )
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let label_size = ctx.compute_size(&mut self.label, SizeDef::fit(size), size.into());
+        ctx.run_layout(&mut self.label, label_size);
+        ctx.place_child(&mut self.label, Point::ZERO);
+        ctx.derive_baselines(&self.label);
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.label.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("KeyboardShortcut", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::keyboard::KeyState;
+    use crate::testing::{TestHarness, TestHarnessParams, assert_render_snapshot};
+    use crate::theme::test_property_set;
+
+    fn ctrl_shift_p() -> KeyCombo {
+        KeyCombo::new(
+            Modifiers::CONTROL | Modifiers::SHIFT,
+            Key::Character("p".into()),
+        )
+    }
+
+    #[test]
+    fn display_per_platform() {
+        let combo = ctrl_shift_p();
+        assert_eq!(combo.display(ShortcutPlatform::MacOs), "⌃⇧P");
+        assert_eq!(combo.display(ShortcutPlatform::Windows), "Ctrl+Shift+P");
+        assert_eq!(combo.display(ShortcutPlatform::Linux), "Ctrl+Shift+P");
+
+        let combo = KeyCombo::new(Modifiers::META, Key::Named(NamedKey::Enter));
+        assert_eq!(combo.display(ShortcutPlatform::MacOs), "⌘↩");
+        assert_eq!(combo.display(ShortcutPlatform::Windows), "Win+Enter");
+        assert_eq!(combo.display(ShortcutPlatform::Linux), "Super+Enter");
+    }
+
+    #[test]
+    fn matches_ignores_lock_keys() {
+        let combo = ctrl_shift_p();
+        let press = |modifiers| KeyboardEvent {
+            state: KeyState::Down,
+            key: Key::Character("P".into()),
+            modifiers,
+            ..KeyboardEvent::default()
+        };
+        assert!(combo.matches(&press(Modifiers::CONTROL | Modifiers::SHIFT)));
+        assert!(combo.matches(&press(
+            Modifiers::CONTROL | Modifiers::SHIFT | Modifiers::CAPS_LOCK | Modifiers::NUM_LOCK
+        )));
+        assert!(!combo.matches(&press(Modifiers::CONTROL)));
+        assert!(!combo.matches(&press(
+            Modifiers::CONTROL | Modifiers::SHIFT | Modifiers::ALT
+        )));
+    }
+
+    #[test]
+    fn keyboard_shortcut_text() {
+        let widget = KeyboardShortcut::new(ctrl_shift_p())
+            .with_platform(ShortcutPlatform::Linux)
+            .prepare();
+        let params =
+            TestHarnessParams::size_and_padding((120, 40), TestHarnessParams::ROOT_PADDING);
+        let mut harness = TestHarness::create_with(test_property_set(), widget, params);
+
+        assert_render_snapshot!(harness, "keyboard_shortcut_ctrl_shift_p");
+
+        harness.edit_root_widget(|mut shortcut| {
+            KeyboardShortcut::set_platform(&mut shortcut, ShortcutPlatform::MacOs);
+        });
+        assert_eq!(harness.root_widget().text(), "⌃⇧P");
+        let label = harness.root_widget().children()[0]
+            .downcast::<Label>()
+            .unwrap();
+        assert_eq!(label.inner().text().as_ref(), "⌃⇧P");
+    }
+}
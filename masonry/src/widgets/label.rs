@@ -3,33 +3,88 @@
 
 use std::any::TypeId;
 use std::mem::Discriminant;
+use std::ops::Range;
 
 use accesskit::{Node, Role};
 use include_doc_path::include_doc_path;
 use smallvec::SmallVec;
 use tracing::{Span, trace_span};
+use unicode_bidi::{BidiClass, Direction, bidi_class, get_base_direction, get_base_direction_full};
 
+use crate::core::keyboard::{Key, KeyState};
 use crate::core::{
-    AccessCtx, ArcStr, BrushIndex, ChildrenIds, LayoutCtx, MeasureCtx, NoAction, PaintCtx,
-    PropertiesMut, PropertiesRef, RegisterCtx, StyleProperty, StyleSet, Update, UpdateCtx,
-    UsesProperty, Widget, WidgetId, WidgetMut, render_text, set_accesskit_brush_properties,
+    AccessCtx, ArcStr, BrushIndex, ChildrenIds, EventCtx, LayoutCtx, MeasureCtx, NoAction,
+    PaintCtx, PointerButton, PointerButtonEvent, PointerEvent, PointerUpdate, PropertiesMut,
+    PropertiesRef, RegisterCtx, StyleProperty, StyleSet, TextEvent, Update, UpdateCtx,
+    UsesProperty, Widget, WidgetId, WidgetMut, render_text, render_text_with_line_offsets,
+    set_accesskit_brush_properties,
 };
-use crate::imaging::Painter;
-use crate::kurbo::{Affine, Axis, Point, Size};
+use crate::imaging::{Composite, GroupRef, Painter};
+use crate::kurbo::{Affine, Axis, Point, Rect, Size, Vec2};
 use crate::layout::{AsUnit, LenReq, Length};
-use crate::parley::{FontContext, Layout, LayoutAccessibility, LayoutContext};
-use crate::properties::{ContentColor, LineBreaking};
+use crate::parley::editing::{Cursor, Selection};
+use crate::parley::{
+    Affinity, FontContext, Layout, LayoutAccessibility, LayoutContext, LineHeight,
+    PositionedLayoutItem,
+};
+use crate::peniko::{BlendMode, Color, Compose, Gradient, Mix};
+use crate::properties::{ContentColor, LineBreaking, SelectionColor};
 use crate::theme::default_text_styles;
-use crate::util::debug_panic;
+use crate::util::{bounding_box_to_rect, debug_panic};
 use crate::{TextAlign, TextAlignOptions, theme};
 
+/// The text appended to truncated labels with [`TextOverflow::Ellipsis`].
+const ELLIPSIS: &str = "\u{2026}";
+/// The width of the gradient at the end of labels with [`TextOverflow::Fade`].
+const FADE_WIDTH: f64 = 24.;
+
+/// How a [`Label`] paints text which is wider than the label.
+///
+/// This is distinct from [`LineBreaking`], which decides where lines are broken.
+/// It is mostly meant for single-line labels, and only the first line is truncated
+/// with [`Ellipsis`](Self::Ellipsis).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextOverflow {
+    /// The text is painted past the label's edge.
+    #[default]
+    Visible,
+    /// The text is cut at the label's edge.
+    Clip,
+    /// The text is cut at the last glyph which fits along with a trailing ellipsis.
+    Ellipsis,
+    /// The text fades out towards the label's edge.
+    Fade,
+}
+
+/// The base direction of the paragraphs of a [`Label`].
+///
+/// The base direction decides which side [`TextAlign::Start`] and [`TextAlign::End`]
+/// refer to, e.g. right-to-left paragraphs start on the right.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextDirection {
+    /// Each paragraph takes the direction of its first strong character,
+    /// e.g. a paragraph starting with Arabic letters is right-to-left.
+    ///
+    /// Paragraphs without strong characters use the direction of the whole text.
+    #[default]
+    Auto,
+    /// All paragraphs are left-to-right.
+    LeftToRight,
+    /// All paragraphs are right-to-left.
+    RightToLeft,
+}
+
 /// A widget displaying non-interactive text.
 ///
 /// This is useful for creating interactive widgets which internally
 /// need support for displaying text, such as a button.
 ///
+/// A label can be made [selectable](Self::selectable), in which case the user can
+/// select its text by dragging the pointer, and copy it with `Ctrl+C` (`Cmd+C` on macOS).
+/// For longer immutable text, consider [`Prose`](super::Prose) instead.
+///
 /// You can customize the look of this label with the
-/// [`LineBreaking`] and [`ContentColor`] properties.
+/// [`LineBreaking`], [`ContentColor`], and [`SelectionColor`] properties.
 ///
 #[doc = concat!(
     "![Styled label](",
@@ -49,8 +104,11 @@ pub struct Label {
     active_layout: usize,
 
     text: ArcStr,
+    /// The text given to Parley, derived from `text` and `text_direction`.
+    layout_text: LayoutText,
     styles: StyleSet,
     text_alignment: TextAlign,
+    text_direction: TextDirection,
 
     /// Whether to hint whilst drawing the text.
     ///
@@ -58,9 +116,33 @@ pub struct Label {
     // TODO: What classes of animations?
     hint: bool,
 
+    /// The heading level (1-6) exposed to accessibility, if this label is a heading.
+    heading_level: Option<u8>,
+
+    overflow: TextOverflow,
+    /// Cached layout of the [`ELLIPSIS`], built on demand.
+    ellipsis: Option<Layout<BrushIndex>>,
+
+    /// Whether the user can select the text.
+    selectable: bool,
+    /// The current selection, if the label is selectable and the user selected something.
+    selection: Option<Selection>,
+
     accessibility: LayoutAccessibility,
 }
 
+/// The text of a [`Label`] as it is given to Parley.
+///
+/// Parley resolves a single base direction for the whole text.
+/// When the paragraphs don't all have that direction, each of them is wrapped
+/// in a directional embedding, so that Parley orders its content like it would
+/// in a paragraph with that base direction.
+struct LayoutText {
+    text: ArcStr,
+    /// The byte offsets in `text` of the inserted embedding characters.
+    embeddings: Vec<usize>,
+}
+
 /// Text layout computation inputs and output.
 struct TextLayout {
     /// Computed text layout.
@@ -71,6 +153,14 @@ struct TextLayout {
     alignment: TextAlign,
     /// Text alignment width of this layout.
     alignment_width: f32,
+    /// Paragraph direction this layout was aligned for.
+    direction: TextDirection,
+    /// Horizontal offsets of each line on top of Parley's alignment.
+    ///
+    /// Parley aligns all lines for the base direction of the whole layout,
+    /// so the lines of paragraphs with the other direction are mirrored
+    /// within the alignment width by these offsets.
+    line_offsets: Vec<f32>,
     /// Last use timestamp for cache eviction purposes.
     last_used: u8,
 }
@@ -86,6 +176,8 @@ impl TextLayout {
             max_advance,
             alignment: TextAlign::Start,
             alignment_width: -1., // Not aligned yet
+            direction: TextDirection::Auto,
+            line_offsets: Vec::new(),
             last_used: timestamp,
         }
     }
@@ -101,19 +193,68 @@ impl TextLayout {
     /// Align the text layout.
     ///
     /// This method ensures that alignment only happens when the inputs have changed.
-    fn align(&mut self, alignment: TextAlign, alignment_width: f32) {
+    fn align(
+        &mut self,
+        text: &str,
+        alignment: TextAlign,
+        alignment_width: f32,
+        direction: TextDirection,
+    ) {
         if self.alignment == alignment
+            && self.direction == direction
             && (self.alignment_width - alignment_width).abs() < Self::EPSILON
         {
             return;
         }
         self.alignment = alignment;
         self.alignment_width = alignment_width;
+        self.direction = direction;
         self.layout.align(
             Some(self.alignment_width),
             self.alignment,
             TextAlignOptions::default(),
         );
+
+        self.line_offsets.clear();
+        // Only the alignments relative to the base direction depend on it.
+        if !matches!(
+            alignment,
+            TextAlign::Start | TextAlign::End | TextAlign::Justify
+        ) {
+            return;
+        }
+        let layout_is_rtl = self.layout.is_rtl();
+        for line in self.layout.lines() {
+            let is_rtl = paragraph_is_rtl(
+                paragraph_at(text, line.text_range().start),
+                direction,
+                layout_is_rtl,
+            );
+            let offset = if is_rtl == layout_is_rtl {
+                0.
+            } else {
+                // Mirror the line within the alignment width.
+                let metrics = line.metrics();
+                alignment_width - metrics.advance - 2. * metrics.offset
+            };
+            self.line_offsets.push(offset);
+        }
+        if self.line_offsets.iter().all(|offset| *offset == 0.) {
+            self.line_offsets.clear();
+        }
+    }
+
+    /// Returns the offset of the line at the vertical position `y`, see [`Self::line_offsets`].
+    fn line_offset_at(&self, y: f32) -> f32 {
+        if self.line_offsets.is_empty() {
+            return 0.;
+        }
+        let line_idx = self
+            .layout
+            .lines()
+            .position(|line| y < line.metrics().max_coord)
+            .unwrap_or(self.layout.len().saturating_sub(1));
+        self.line_offsets.get(line_idx).copied().unwrap_or(0.)
     }
 
     /// Returns `true` if this layout would be the result for `max_advance`.
@@ -180,14 +321,22 @@ impl Label {
     pub fn new(text: impl Into<ArcStr>) -> Self {
         let mut styles = StyleSet::new(theme::TEXT_SIZE_NORMAL);
         default_text_styles(&mut styles);
+        let text = text.into();
         Self {
             layouts: Vec::new(),
             cache_time: 0,
             active_layout: usize::MAX,
-            text: text.into(),
+            layout_text: LayoutText::new(&text, TextDirection::Auto),
+            text,
             styles,
             text_alignment: TextAlign::Start,
+            text_direction: TextDirection::Auto,
             hint: true,
+            heading_level: None,
+            overflow: TextOverflow::Visible,
+            ellipsis: None,
+            selectable: false,
+            selection: None,
             accessibility: LayoutAccessibility::default(),
         }
     }
@@ -223,6 +372,17 @@ impl Label {
         self
     }
 
+    /// Sets the base direction of the paragraphs of the text.
+    ///
+    /// The default is [`TextDirection::Auto`], which detects the direction of each paragraph
+    /// from its content, so that e.g. Arabic and English paragraphs both start on their own side.
+    /// To modify this on an active label, use [`set_text_direction`](Self::set_text_direction).
+    pub fn with_text_direction(mut self, text_direction: TextDirection) -> Self {
+        self.text_direction = text_direction;
+        self.layout_text = LayoutText::new(&self.text, text_direction);
+        self
+    }
+
     /// Sets whether [hinting](https://en.wikipedia.org/wiki/Font_hinting) will be used for this label.
     ///
     /// Hinting is a process where text is drawn "snapped" to pixel boundaries to improve fidelity.
@@ -242,6 +402,67 @@ impl Label {
         self
     }
 
+    /// Marks this label as a heading with the given level.
+    ///
+    /// Headings are exposed with the [`Heading`](Role::Heading) role, which lets
+    /// assistive technologies offer navigation between them.
+    /// Levels are clamped to the `1..=6` range, matching HTML's `<h1>` to `<h6>`.
+    ///
+    /// To modify this on an active label, use [`set_heading_level`](Self::set_heading_level).
+    pub fn heading_level(mut self, level: u8) -> Self {
+        self.heading_level = Some(level.clamp(1, 6));
+        self
+    }
+
+    /// Sets how text wider than the label is painted.
+    ///
+    /// The default is [`TextOverflow::Visible`].
+    ///
+    /// To modify this on an active label, use [`set_overflow`](Self::set_overflow).
+    pub fn overflow(mut self, overflow: TextOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Sets whether the user can select the text of this label.
+    ///
+    /// A selectable label can be focused. Dragging the pointer over it selects text,
+    /// `Ctrl+A` (`Cmd+A` on macOS) selects all of it,
+    /// and `Ctrl+C` (`Cmd+C` on macOS) copies the selection.
+    /// The selection is cleared when the label loses focus.
+    ///
+    /// The default is false.
+    ///
+    /// This can't be changed once the label is added to the widget tree,
+    /// because it decides whether the label accepts focus and pointer interaction.
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
+    /// Sets the extra space added between characters, in logical pixels.
+    ///
+    /// Negative values bring characters closer together.
+    /// This is a shorthand for setting [`StyleProperty::LetterSpacing`](crate::parley::StyleProperty::LetterSpacing).
+    ///
+    /// To modify this on an active label, use [`set_letter_spacing`](Self::set_letter_spacing).
+    pub fn letter_spacing(self, spacing: f64) -> Self {
+        self.with_style(StyleProperty::LetterSpacing(spacing as f32))
+    }
+
+    /// Sets the height of each line, as a multiple of the font size.
+    ///
+    /// The default is `1.2`.
+    /// This is a shorthand for setting [`StyleProperty::LineHeight`](crate::parley::StyleProperty::LineHeight)
+    /// to [`LineHeight::FontSizeRelative`].
+    ///
+    /// To modify this on an active label, use [`set_line_height`](Self::set_line_height).
+    pub fn line_height(self, line_height: f64) -> Self {
+        self.with_style(StyleProperty::LineHeight(LineHeight::FontSizeRelative(
+            line_height as f32,
+        )))
+    }
+
     /// Shared logic between `with_style` and `insert_style`
     fn insert_style_inner(&mut self, property: StyleProperty) -> Option<StyleProperty> {
         if let StyleProperty::Brush(idx @ BrushIndex(1..))
@@ -264,6 +485,22 @@ impl Label {
     pub fn text(&self) -> &ArcStr {
         &self.text
     }
+
+    /// Returns the byte range of the selected text, if any text is selected.
+    pub fn selection_range(&self) -> Option<Range<usize>> {
+        self.selection
+            .as_ref()
+            .filter(|selection| !selection.is_collapsed())
+            .map(|selection| {
+                let range = selection.text_range();
+                self.layout_text.to_source(range.start)..self.layout_text.to_source(range.end)
+            })
+    }
+
+    /// Returns the selected text, if any text is selected.
+    pub fn selected_text(&self) -> Option<&str> {
+        self.selection_range().map(|range| &self.text[range])
+    }
 }
 
 // --- MARK: WIDGETMUT
@@ -318,9 +555,24 @@ impl Label {
         old
     }
 
+    /// The runtime equivalent of [`letter_spacing`](Self::letter_spacing).
+    pub fn set_letter_spacing(this: &mut WidgetMut<'_, Self>, spacing: f64) {
+        Self::insert_style(this, StyleProperty::LetterSpacing(spacing as f32));
+    }
+
+    /// The runtime equivalent of [`line_height`](Self::line_height).
+    pub fn set_line_height(this: &mut WidgetMut<'_, Self>, line_height: f64) {
+        Self::insert_style(
+            this,
+            StyleProperty::LineHeight(LineHeight::FontSizeRelative(line_height as f32)),
+        );
+    }
+
     /// Replaces the text of this widget.
     pub fn set_text(this: &mut WidgetMut<'_, Self>, new_text: impl Into<ArcStr>) {
         this.widget.text = new_text.into();
+        this.widget.layout_text = LayoutText::new(&this.widget.text, this.widget.text_direction);
+        this.widget.selection = None;
 
         this.widget.clear_cache();
         this.ctx.request_layout();
@@ -332,11 +584,35 @@ impl Label {
         this.ctx.request_layout();
     }
 
+    /// The runtime equivalent of [`with_text_direction`](Self::with_text_direction).
+    pub fn set_text_direction(this: &mut WidgetMut<'_, Self>, text_direction: TextDirection) {
+        this.widget.text_direction = text_direction;
+        this.widget.layout_text = LayoutText::new(&this.widget.text, text_direction);
+        this.widget.selection = None;
+
+        this.widget.clear_cache();
+        this.ctx.request_layout();
+    }
+
     /// The runtime equivalent of [`with_hint`](Self::with_hint).
     pub fn set_hint(this: &mut WidgetMut<'_, Self>, hint: bool) {
         this.widget.hint = hint;
         this.ctx.request_paint_only();
     }
+
+    /// The runtime equivalent of [`heading_level`](Self::heading_level).
+    ///
+    /// Passing `None` turns the heading back into a plain label.
+    pub fn set_heading_level(this: &mut WidgetMut<'_, Self>, level: Option<u8>) {
+        this.widget.heading_level = level.map(|level| level.clamp(1, 6));
+        this.ctx.request_accessibility_update();
+    }
+
+    /// The runtime equivalent of [`overflow`](Self::overflow).
+    pub fn set_overflow(this: &mut WidgetMut<'_, Self>, overflow: TextOverflow) {
+        this.widget.overflow = overflow;
+        this.ctx.request_paint_only();
+    }
 }
 
 impl Label {
@@ -346,6 +622,26 @@ impl Label {
     fn clear_cache(&mut self) {
         self.layouts.clear();
         self.active_layout = usize::MAX;
+        self.ellipsis = None;
+    }
+
+    /// Builds the [`ELLIPSIS`] layout with this label's styles, unless it's already cached.
+    fn build_ellipsis(
+        &mut self,
+        font_ctx: &mut FontContext,
+        layout_ctx: &mut LayoutContext<BrushIndex>,
+    ) {
+        if self.ellipsis.is_some() {
+            return;
+        }
+        let mut builder = layout_ctx.ranged_builder(font_ctx, ELLIPSIS, 1.0, true);
+        for prop in self.styles.inner().values() {
+            builder.push_default(prop.to_owned());
+        }
+        let mut layout = Layout::new();
+        builder.build_into(&mut layout, ELLIPSIS);
+        layout.break_all_lines(None);
+        self.ellipsis = Some(layout);
     }
 
     /// Total number of text layouts to cache.
@@ -413,11 +709,12 @@ impl Label {
 
         // TODO: Should we use a different scale?
         // See https://github.com/linebender/xilem/issues/1264
-        let mut builder = layout_ctx.ranged_builder(font_ctx, &self.text, 1.0, true);
+        let text = &self.layout_text.text;
+        let mut builder = layout_ctx.ranged_builder(font_ctx, text, 1.0, true);
         for prop in self.styles.inner().values() {
             builder.push_default(prop.to_owned());
         }
-        builder.build_into(&mut layout.layout, &self.text);
+        builder.build_into(&mut layout.layout, text);
 
         layout.layout.break_all_lines(max_advance);
 
@@ -462,15 +759,290 @@ impl Label {
     }
 }
 
+impl LayoutText {
+    /// The left-to-right embedding character.
+    const LRE: char = '\u{202A}';
+    /// The right-to-left embedding character.
+    const RLE: char = '\u{202B}';
+    /// The pop directional formatting character, which ends an embedding.
+    const PDF: char = '\u{202C}';
+
+    /// Prepares `text` to be laid out with paragraphs in the given `direction`.
+    fn new(text: &ArcStr, direction: TextDirection) -> Self {
+        // Parley uses the first strong character of the whole text as its base direction.
+        let text_is_rtl = get_base_direction_full(&**text) == Direction::Rtl;
+        let is_rtl = |paragraph: &str| paragraph_is_rtl(paragraph, direction, text_is_rtl);
+        let paragraphs = || text.split(is_paragraph_separator);
+        if paragraphs().all(|paragraph| is_rtl(paragraph) == text_is_rtl) {
+            return Self {
+                text: text.clone(),
+                embeddings: Vec::new(),
+            };
+        }
+
+        // An embedding of the same direction as the base direction doesn't change
+        // the resulting order, so we can embed every paragraph, whatever Parley resolves.
+        let mut layout_text = String::with_capacity(text.len() + 6 * paragraphs().count());
+        let mut embeddings = Vec::new();
+        let mut source_idx = 0;
+        for paragraph in paragraphs() {
+            let embedding = if is_rtl(paragraph) {
+                Self::RLE
+            } else {
+                Self::LRE
+            };
+            embeddings.push(layout_text.len());
+            layout_text.push(embedding);
+            layout_text.push_str(paragraph);
+            embeddings.push(layout_text.len());
+            layout_text.push(Self::PDF);
+            // Copy the separator which ended the paragraph.
+            source_idx += paragraph.len();
+            if let Some(separator) = text[source_idx..].chars().next() {
+                layout_text.push(separator);
+                source_idx += separator.len_utf8();
+            }
+        }
+        Self {
+            text: layout_text.into(),
+            embeddings,
+        }
+    }
+
+    /// Returns `true` if `c` is one of the characters inserted around paragraphs.
+    fn is_embedding(c: char) -> bool {
+        matches!(c, Self::LRE | Self::RLE | Self::PDF)
+    }
+
+    /// Removes the embedding characters from a text run node built from the layout text,
+    /// so that assistive technologies only see the label's text.
+    ///
+    /// The per-character properties of the run are updated to match.
+    fn strip_embeddings(node: &mut Node) {
+        let Some(value) = node.value() else {
+            return;
+        };
+        if !value.contains(Self::is_embedding) {
+            return;
+        }
+
+        // The indices, in the original run, of the characters which are kept.
+        let mut kept = Vec::new();
+        let mut stripped_value = String::with_capacity(value.len());
+        let mut lengths = Vec::new();
+        let mut start = 0;
+        for (idx, len) in node.character_lengths().iter().enumerate() {
+            let end = start + usize::from(*len);
+            let previous_len = stripped_value.len();
+            stripped_value.extend(
+                value[start..end]
+                    .chars()
+                    .filter(|c| !Self::is_embedding(*c)),
+            );
+            start = end;
+            let stripped_len = stripped_value.len() - previous_len;
+            if stripped_len > 0 {
+                kept.push(idx);
+                lengths.push(stripped_len as u8);
+            }
+        }
+
+        // A word starting with an embedding character now starts with the next character.
+        let mut word_starts: Vec<u8> = node
+            .word_starts()
+            .iter()
+            .filter_map(|start| kept.iter().position(|idx| *idx >= usize::from(*start)))
+            .map(|start| start as u8)
+            .collect();
+        word_starts.dedup();
+        let keep = |values: &[f32]| kept.iter().map(|idx| values[*idx]).collect::<Vec<_>>();
+        let positions = node.character_positions().map(keep);
+        let widths = node.character_widths().map(keep);
+
+        node.set_value(stripped_value);
+        node.set_character_lengths(lengths);
+        node.set_word_starts(word_starts);
+        if let Some(positions) = positions {
+            node.set_character_positions(positions);
+        }
+        if let Some(widths) = widths {
+            node.set_character_widths(widths);
+        }
+    }
+
+    /// Maps the byte `index` of the layout text to the matching byte index of the label's text.
+    fn to_source(&self, index: usize) -> usize {
+        let inserted = self
+            .embeddings
+            .iter()
+            .take_while(|embedding| **embedding < index)
+            .count();
+        // All the embedding characters are 3 bytes long in UTF-8.
+        index - 3 * inserted
+    }
+}
+
+/// Returns `true` if `c` ends a paragraph, as defined by the Unicode bidirectional algorithm.
+fn is_paragraph_separator(c: char) -> bool {
+    bidi_class(c) == BidiClass::B
+}
+
+/// Returns the paragraph of `text` containing the byte `index`, without its separator.
+fn paragraph_at(text: &str, index: usize) -> &str {
+    let start = text[..index]
+        .char_indices()
+        .rfind(|(_, c)| is_paragraph_separator(*c))
+        .map_or(0, |(idx, c)| idx + c.len_utf8());
+    let end = text[index..]
+        .find(is_paragraph_separator)
+        .map_or(text.len(), |idx| index + idx);
+    &text[start..end]
+}
+
+/// Returns whether `paragraph` is right-to-left when laid out with the given `direction`.
+///
+/// With [`TextDirection::Auto`], this is the direction of the first strong character
+/// of the paragraph, as defined by the Unicode bidirectional algorithm.
+/// Paragraphs without strong characters are right-to-left if `fallback_is_rtl` is `true`.
+fn paragraph_is_rtl(paragraph: &str, direction: TextDirection, fallback_is_rtl: bool) -> bool {
+    match direction {
+        TextDirection::Auto => match get_base_direction(paragraph) {
+            Direction::Ltr => false,
+            Direction::Rtl => true,
+            Direction::Mixed => fallback_is_rtl,
+        },
+        TextDirection::LeftToRight => false,
+        TextDirection::RightToLeft => true,
+    }
+}
+
+/// Returns where to cut the first line of `layout`, drawn `offset` to the right,
+/// on a glyph boundary.
+///
+/// The part of the line with the start of the text is kept: a left-to-right line
+/// keeps the glyphs which end before `limit`, and a right-to-left one (`is_rtl`)
+/// keeps the glyphs which start after `limit`.
+fn truncation_point(layout: &Layout<BrushIndex>, offset: f64, limit: f64, is_rtl: bool) -> f64 {
+    let mut cut_x = offset;
+    let Some(line) = layout.get(0) else {
+        return cut_x;
+    };
+    for item in line.items() {
+        let PositionedLayoutItem::GlyphRun(glyph_run) = item else {
+            continue;
+        };
+        let mut x = offset + f64::from(glyph_run.offset());
+        for glyph in glyph_run.glyphs() {
+            let end = x + f64::from(glyph.advance);
+            if is_rtl && x >= limit {
+                return x;
+            }
+            if !is_rtl && end > limit {
+                return cut_x;
+            }
+            cut_x = end;
+            x = end;
+        }
+    }
+    cut_x
+}
+
 impl UsesProperty<ContentColor> for Label {}
 impl UsesProperty<LineBreaking> for Label {}
+impl UsesProperty<SelectionColor> for Label {}
 
 // --- MARK: IMPL WIDGET
 impl Widget for Label {
     type Action = NoAction;
 
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if !self.selectable || self.active_layout >= self.layouts.len() {
+            return;
+        }
+        let text_layout = &self.layouts[self.active_layout];
+        let layout = &text_layout.layout;
+        // Maps a local position to the coordinates of the layout, before line offsets.
+        let layout_pos = |pos: Point| {
+            let y = pos.y as f32;
+            (pos.x as f32 - text_layout.line_offset_at(y), y)
+        };
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: None | Some(PointerButton::Primary),
+                state,
+                ..
+            }) => {
+                let (x, y) = layout_pos(ctx.local_position(state.position));
+                self.selection = Some(Selection::from_point(layout, x, y));
+                ctx.request_focus();
+                ctx.capture_pointer();
+                ctx.request_paint_only();
+            }
+            PointerEvent::Move(PointerUpdate { current, .. }) if ctx.is_active() => {
+                if let Some(selection) = &self.selection {
+                    let (x, y) = layout_pos(ctx.local_position(current.position));
+                    self.selection = Some(selection.extend_to_point(layout, x, y));
+                    ctx.request_paint_only();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        let TextEvent::Keyboard(key_event) = event else {
+            return;
+        };
+        if key_event.state != KeyState::Down {
+            return;
+        }
+        let action_mod = if cfg!(target_os = "macos") {
+            key_event.modifiers.meta()
+        } else {
+            key_event.modifiers.ctrl()
+        };
+        match &key_event.key {
+            Key::Character(c) if action_mod && c.as_str().eq_ignore_ascii_case("c") => {
+                if let Some(text) = self.selected_text() {
+                    ctx.set_clipboard(text.to_string());
+                    ctx.set_handled();
+                }
+            }
+            Key::Character(c) if action_mod && c.as_str().eq_ignore_ascii_case("a") => {
+                if !self.selectable || self.active_layout >= self.layouts.len() {
+                    return;
+                }
+                let layout = &self.layouts[self.active_layout].layout;
+                let end = self.layout_text.text.len();
+                let selection = Selection::from_byte_index(layout, 0, Affinity::Downstream);
+                self.selection = Some(selection.extend(Cursor::from_byte_index(
+                    layout,
+                    end,
+                    Affinity::Upstream,
+                )));
+                ctx.request_paint_only();
+                ctx.set_handled();
+            }
+            _ => {}
+        }
+    }
+
     fn accepts_pointer_interaction(&self) -> bool {
-        false
+        self.selectable
+    }
+
+    fn accepts_focus(&self) -> bool {
+        self.selectable
     }
 
     fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {}
@@ -478,6 +1050,7 @@ impl Widget for Label {
     fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
         LineBreaking::prop_changed(ctx, property_type);
         ContentColor::prop_changed(ctx, property_type);
+        SelectionColor::prop_changed(ctx, property_type);
     }
 
     fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
@@ -489,6 +1062,9 @@ impl Widget for Label {
             Update::DisabledChanged(_) => {
                 ctx.request_render();
             }
+            Update::FocusChanged(false) if self.selection.take().is_some() => {
+                ctx.request_paint_only();
+            }
             _ => {}
         }
     }
@@ -572,7 +1148,12 @@ impl Widget for Label {
         self.active_layout = self.build_and_break(font_ctx, layout_ctx, max_advance);
         let layout = &mut self.layouts[self.active_layout];
 
-        layout.align(self.text_alignment, inline_space);
+        layout.align(
+            &self.layout_text.text,
+            self.text_alignment,
+            inline_space,
+            self.text_direction,
+        );
 
         let line_count = layout.layout.len();
         if line_count > 0 {
@@ -599,22 +1180,151 @@ impl Widget for Label {
         props: &PropertiesRef<'_>,
         painter: &mut Painter<'_>,
     ) {
+        let content_box = ctx.content_box();
+        let overflows = f64::from(self.layouts[self.active_layout].layout.width())
+            > content_box.width() + f64::from(TextLayout::EPSILON);
+        let overflow = if overflows {
+            self.overflow
+        } else {
+            TextOverflow::Visible
+        };
+        if overflow == TextOverflow::Ellipsis {
+            let (font_ctx, layout_ctx) = ctx.text_contexts();
+            self.build_ellipsis(font_ctx, layout_ctx);
+        }
+
         let cache = ctx.property_cache();
         let text_color = props.get::<ContentColor>(cache);
+        let selection_color = props.get::<SelectionColor>(cache);
+        let brushes = [text_color.color.into()];
+
+        let text_layout = &self.layouts[self.active_layout];
+        let layout = &text_layout.layout;
+        let line_offsets = &text_layout.line_offsets;
+        let line_offset =
+            |line_idx: usize| -> f64 { line_offsets.get(line_idx).copied().unwrap_or(0.).into() };
+
+        if let Some(selection) = &self.selection {
+            for (rect, line_idx) in selection.geometry(layout) {
+                let rect = bounding_box_to_rect(rect) + Vec2::new(line_offset(line_idx), 0.);
+                painter.fill(rect, selection_color.color).draw();
+            }
+        }
 
-        let layout = &self.layouts[self.active_layout];
-
-        render_text(
-            painter,
-            Affine::IDENTITY,
-            &layout.layout,
-            &[text_color.color.into()],
-            self.hint,
-        );
+        match overflow {
+            TextOverflow::Visible => {
+                render_text_with_line_offsets(
+                    painter,
+                    Affine::IDENTITY,
+                    layout,
+                    line_offsets,
+                    &brushes,
+                    self.hint,
+                );
+            }
+            TextOverflow::Clip => {
+                painter.with_fill_clip(content_box, |painter| {
+                    render_text_with_line_offsets(
+                        painter,
+                        Affine::IDENTITY,
+                        layout,
+                        line_offsets,
+                        &brushes,
+                        self.hint,
+                    );
+                });
+            }
+            TextOverflow::Ellipsis => {
+                let ellipsis = self.ellipsis.as_ref().unwrap();
+                let ellipsis_width = f64::from(ellipsis.width());
+                let is_rtl = layout.get(0).is_some_and(|line| {
+                    paragraph_is_rtl(
+                        paragraph_at(&self.layout_text.text, line.text_range().start),
+                        self.text_direction,
+                        layout.is_rtl(),
+                    )
+                });
+                // A right-to-left line starts on its right, so that side is kept in the label.
+                let shift = if is_rtl {
+                    let line_end = truncation_point(layout, line_offset(0), f64::INFINITY, false);
+                    (content_box.x1 - line_end).min(0.)
+                } else {
+                    0.
+                };
+                let offset = line_offset(0) + shift;
+                let (clip, ellipsis_x) = if is_rtl {
+                    let min_x = (content_box.x0 + ellipsis_width).min(content_box.x1);
+                    let cut_x = truncation_point(layout, offset, min_x, true);
+                    let clip = Rect::new(cut_x, content_box.y0, content_box.x1, content_box.y1);
+                    (clip, cut_x - ellipsis_width)
+                } else {
+                    let max_x = (content_box.x1 - ellipsis_width).max(content_box.x0);
+                    let cut_x = truncation_point(layout, offset, max_x, false);
+                    let clip = Rect::new(content_box.x0, content_box.y0, cut_x, content_box.y1);
+                    (clip, cut_x)
+                };
+                painter.with_fill_clip(clip, |painter| {
+                    render_text_with_line_offsets(
+                        painter,
+                        Affine::translate((shift, 0.)),
+                        layout,
+                        line_offsets,
+                        &brushes,
+                        self.hint,
+                    );
+                });
+
+                // Align the baselines, in case the ellipsis comes from a fallback font.
+                let baseline = |layout: &Layout<BrushIndex>| {
+                    layout.get(0).map_or(0., |line| line.metrics().baseline)
+                };
+                let y = f64::from(baseline(layout) - baseline(ellipsis));
+                render_text(
+                    painter,
+                    Affine::translate((ellipsis_x, y)),
+                    ellipsis,
+                    &brushes,
+                    self.hint,
+                );
+            }
+            TextOverflow::Fade => {
+                let fade_width = FADE_WIDTH.min(content_box.width());
+                let fade_rect = Rect::new(
+                    content_box.x1 - fade_width,
+                    content_box.y0,
+                    content_box.x1,
+                    content_box.y1,
+                );
+                let gradient = Gradient::new_linear((fade_rect.x0, 0.), (fade_rect.x1, 0.))
+                    .with_stops([(0., Color::TRANSPARENT), (1., Color::BLACK)]);
+                let erase = Composite::new(BlendMode::new(Mix::Normal, Compose::DestOut), 1.);
+
+                painter.with_fill_clip(content_box, |painter| {
+                    painter.push_group(GroupRef::new());
+                    render_text_with_line_offsets(
+                        painter,
+                        Affine::IDENTITY,
+                        layout,
+                        line_offsets,
+                        &brushes,
+                        self.hint,
+                    );
+                    // Erase the text progressively towards the end.
+                    painter.push_group(GroupRef::new().with_composite(erase));
+                    painter.fill(fade_rect, &gradient).draw();
+                    painter.pop_group();
+                    painter.pop_group();
+                });
+            }
+        }
     }
 
     fn accessibility_role(&self) -> Role {
-        Role::Label
+        if self.heading_level.is_some() {
+            Role::Heading
+        } else {
+            Role::Label
+        }
     }
 
     fn accessibility(
@@ -630,8 +1340,13 @@ impl Widget for Label {
 
         let layout = &self.layouts[self.active_layout];
 
+        if let Some(level) = self.heading_level {
+            node.set_level(level.into());
+        }
+
+        let first_run_node = ctx.tree_update().nodes.len();
         self.accessibility.build_nodes(
-            self.text.as_ref(),
+            self.layout_text.text.as_ref(),
             &layout.layout,
             ctx.tree_update(),
             node,
@@ -640,6 +1355,27 @@ impl Widget for Label {
             text_origin_in_border_box_space.y,
             |node, style| set_accesskit_brush_properties(node, style, &[text_color.color.into()]),
         );
+
+        // The run nodes are built from the layout text, so hide its embedding characters.
+        if !self.layout_text.embeddings.is_empty() {
+            for (_, run_node) in &mut ctx.tree_update().nodes[first_run_node..] {
+                LayoutText::strip_embeddings(run_node);
+            }
+        }
+
+        // Move the run nodes of mirrored lines along with their painted text.
+        if !layout.line_offsets.is_empty() {
+            for (_, run_node) in &mut ctx.tree_update().nodes[first_run_node..] {
+                let Some(mut bounds) = run_node.bounds() else {
+                    continue;
+                };
+                let line_y = (bounds.y0 + bounds.y1) / 2. - text_origin_in_border_box_space.y;
+                let line_offset = f64::from(layout.line_offset_at(line_y as f32));
+                bounds.x0 += line_offset;
+                bounds.x1 += line_offset;
+                run_node.set_bounds(bounds);
+            }
+        }
     }
 
     fn children_ids(&self) -> ChildrenIds {
@@ -659,7 +1395,7 @@ impl Widget for Label {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::{NewWidget, PropertySet};
+    use crate::core::{Modifiers, NewWidget, PropertySet, WidgetTag};
     use crate::layout::{AsUnit, Dim};
     use crate::parley::style::GenericFamily;
     use crate::parley::{FontFamily, FontFamilyName, StyleProperty};
@@ -723,6 +1459,57 @@ mod tests {
         assert_render_snapshot!(harness, "label_strikethrough_label");
     }
 
+    #[test]
+    fn letter_spacing_label() {
+        let label_tag = WidgetTag::unique();
+        let label = Label::new("Tracking")
+            .letter_spacing(4.)
+            .prepare()
+            .with_tag(label_tag);
+        let row = Flex::row().with_fixed(label).prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), row, (150, 40));
+        let width =
+            |harness: &TestHarness<Flex>| harness.get_widget(label_tag).ctx().border_box().width();
+        let wide_width = width(&harness);
+        assert_render_snapshot!(harness, "label_letter_spacing_wide");
+
+        harness.edit_widget(label_tag, |mut label| {
+            Label::set_letter_spacing(&mut label, -1.);
+        });
+        let tight_width = width(&harness);
+
+        harness.edit_widget(label_tag, |mut label| {
+            Label::set_letter_spacing(&mut label, 0.);
+        });
+        let default_width = width(&harness);
+        assert!(tight_width < default_width);
+        assert!(default_width < wide_width);
+        assert_render_snapshot!(harness, "label_letter_spacing_default");
+    }
+
+    #[test]
+    fn line_height_label() {
+        let label_tag = WidgetTag::unique();
+        let label = Label::new("First line\nSecond line\nThird line")
+            .line_height(1.5)
+            .prepare()
+            .with_tag(label_tag);
+        let column = Flex::column().with_fixed(label).prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), column, (120, 100));
+        let height =
+            |harness: &TestHarness<Flex>| harness.get_widget(label_tag).ctx().border_box().height();
+        let tall_height = height(&harness);
+        assert_render_snapshot!(harness, "label_line_height_1_5");
+
+        harness.edit_widget(label_tag, |mut label| {
+            Label::set_line_height(&mut label, 1.2);
+        });
+        assert!(height(&harness) < tall_height);
+        assert_render_snapshot!(harness, "label_line_height_default");
+    }
+
     #[test]
     /// A label's text alignment should be respected, regardless of
     /// its parent's alignment plans for it, if the label has stretched width.
@@ -754,6 +1541,104 @@ mod tests {
         assert_render_snapshot!(harness, "label_label_alignment_flex");
     }
 
+    #[test]
+    fn paragraph_directions() {
+        const TEXT: &str = "مرحبا بالعالم\nHello world";
+
+        let hello = TEXT.find('H').unwrap();
+        assert_eq!(paragraph_at(TEXT, hello), "Hello world");
+        assert!(paragraph_is_rtl(
+            paragraph_at(TEXT, 0),
+            TextDirection::Auto,
+            false
+        ));
+        assert!(!paragraph_is_rtl(
+            paragraph_at(TEXT, hello),
+            TextDirection::Auto,
+            true
+        ));
+        // Paragraphs without strong characters take the fallback direction.
+        assert!(paragraph_is_rtl("123", TextDirection::Auto, true));
+        assert!(!paragraph_is_rtl("123", TextDirection::Auto, false));
+
+        let label = Label::new(TEXT)
+            .prepare()
+            .with_props(Dimensions::width(Dim::Stretch));
+        let mut harness = TestHarness::create_with_size(test_property_set(), label, (200, 60));
+
+        // Each line starts on the side of its own direction.
+        assert_render_snapshot!(harness, "label_paragraph_directions_auto");
+
+        harness.edit_root_widget(|mut label| {
+            Label::set_text_direction(&mut label, TextDirection::LeftToRight);
+        });
+        assert_render_snapshot!(harness, "label_paragraph_directions_ltr");
+    }
+
+    #[test]
+    fn paragraph_embeddings() {
+        const TEXT: &str = "مرحبا 123 world\nHello عالم";
+
+        // Paragraphs sharing the direction of the whole text are laid out as is.
+        let text = ArcStr::from("Hello\nworld");
+        let layout_text = LayoutText::new(&text, TextDirection::Auto);
+        assert_eq!(&*layout_text.text, "Hello\nworld");
+        assert!(layout_text.embeddings.is_empty());
+
+        // Otherwise each paragraph is embedded with its own direction.
+        let text = ArcStr::from(TEXT);
+        let layout_text = LayoutText::new(&text, TextDirection::Auto);
+        assert_eq!(
+            &*layout_text.text,
+            "\u{202B}مرحبا 123 world\u{202C}\n\u{202A}Hello عالم\u{202C}"
+        );
+        let layout_text = LayoutText::new(&text, TextDirection::LeftToRight);
+        assert_eq!(
+            &*layout_text.text,
+            "\u{202A}مرحبا 123 world\u{202C}\n\u{202A}Hello عالم\u{202C}"
+        );
+
+        // Indices map back to the label's text.
+        let hello = layout_text.text.find('H').unwrap();
+        assert_eq!(layout_text.to_source(hello), TEXT.find('H').unwrap());
+        assert_eq!(layout_text.to_source(layout_text.text.len()), TEXT.len());
+
+        let label = Label::new(TEXT)
+            .selectable(true)
+            .prepare()
+            .with_props(Dimensions::width(Dim::Stretch));
+        let mut harness = TestHarness::create_with_size(test_property_set(), label, (200, 60));
+        assert_render_snapshot!(harness, "label_paragraph_embeddings");
+
+        // Selected text is taken from the label's text, without the embeddings.
+        harness.edit_root_widget(|label| {
+            let label = &mut *label.widget;
+            let layout = &label.layouts[label.active_layout].layout;
+            let end = label.layout_text.text.len();
+            let selection = Selection::from_byte_index(layout, 0, Affinity::Downstream);
+            let selection =
+                selection.extend(Cursor::from_byte_index(layout, end, Affinity::Upstream));
+            label.selection = Some(selection);
+        });
+        assert_eq!(harness.root_widget().selected_text(), Some(TEXT));
+
+        // Assistive technologies don't see the embeddings either.
+        let label_id = harness.root_id();
+        let label_node = harness.access_node(label_id).unwrap();
+        let mut run_text_len = 0;
+        for run in label_node.children() {
+            let value = run.data().value().unwrap();
+            assert!(!value.contains(LayoutText::is_embedding), "{value:?}");
+            let lengths = run.data().character_lengths();
+            assert_eq!(
+                lengths.iter().map(|len| usize::from(*len)).sum::<usize>(),
+                value.len()
+            );
+            run_text_len += value.len();
+        }
+        assert_eq!(run_text_len, TEXT.len());
+    }
+
     #[test]
     fn line_break_modes() {
         let widget = Flex::column()
@@ -795,6 +1680,197 @@ mod tests {
         assert_render_snapshot!(harness, "label_line_break_modes");
     }
 
+    #[test]
+    fn paragraph_wrap_versus_clip() {
+        const PARAGRAPH: &str = "Masonry labels can show a long paragraph of text, \
+            which either wraps to the available width or gets clipped.";
+
+        let label_tag = WidgetTag::named("label");
+        let paragraph = |line_breaking: LineBreaking| {
+            SizedBox::new(
+                Label::new(PARAGRAPH)
+                    .prepare()
+                    .with_tag(label_tag)
+                    .with_props(PropertySet::new().with(line_breaking)),
+            )
+            .width(100.px())
+            .prepare()
+        };
+
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            paragraph(LineBreaking::WordWrap),
+            (120, 200),
+        );
+        let wrapped = harness.get_widget(label_tag).ctx().border_box().size();
+        assert_render_snapshot!(harness, "label_paragraph_wrap_100");
+
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            paragraph(LineBreaking::Clip),
+            (120, 200),
+        );
+        let clipped = harness.get_widget(label_tag).ctx().border_box().size();
+        assert_render_snapshot!(harness, "label_paragraph_clip_100");
+
+        // Both fit the available width, but only the wrapped label grows to several lines.
+        assert!(wrapped.width <= 100.);
+        assert!(clipped.width <= 100.);
+        assert!(wrapped.height > 2. * clipped.height);
+    }
+
+    #[test]
+    fn selectable_drag_and_copy() {
+        const TEXT: &str = "Hello world";
+        let label_tag = WidgetTag::named("label");
+        let label = Label::new(TEXT)
+            .selectable(true)
+            .prepare()
+            .with_tag(label_tag);
+        let row = Flex::row().with_fixed(label).prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), row, (120, 40));
+        let label_box = harness.widget_layout_rect(harness.get_widget(label_tag).id());
+        let y = label_box.center().y;
+
+        // Drag from the start of the text to past its end.
+        harness.mouse_move((label_box.x0 + 1., y));
+        harness.mouse_button_press(None);
+        harness.mouse_move((label_box.x1 + 10., y));
+        harness.mouse_button_release(None);
+
+        let label_id = harness.get_widget(label_tag).id();
+        assert_eq!(harness.focused_widget_id(), Some(label_id));
+        assert_eq!(
+            harness.get_widget(label_tag).selection_range(),
+            Some(0..TEXT.len())
+        );
+        assert_render_snapshot!(harness, "label_selection_highlight");
+
+        let action_mod = if cfg!(target_os = "macos") {
+            Modifiers::META
+        } else {
+            Modifiers::CONTROL
+        };
+        harness.keyboard_key(Key::Character("c".into()), action_mod);
+        assert_eq!(harness.clipboard_contents(), TEXT);
+
+        harness.focus_on(None);
+        assert_eq!(harness.get_widget(label_tag).selected_text(), None);
+    }
+
+    #[test]
+    fn selectable_select_all() {
+        const TEXT: &str = "Hello world\nSecond line";
+        let label_tag = WidgetTag::named("label");
+        let label = Label::new(TEXT)
+            .selectable(true)
+            .prepare()
+            .with_tag(label_tag);
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), label, (120, 60));
+        let label_id = harness.get_widget(label_tag).id();
+        harness.focus_on(Some(label_id));
+        assert_eq!(harness.get_widget(label_tag).selection_range(), None);
+
+        let action_mod = if cfg!(target_os = "macos") {
+            Modifiers::META
+        } else {
+            Modifiers::CONTROL
+        };
+        harness.keyboard_key(Key::Character("a".into()), action_mod);
+        assert_eq!(
+            harness.get_widget(label_tag).selection_range(),
+            Some(0..TEXT.len())
+        );
+        harness.keyboard_key(Key::Character("c".into()), action_mod);
+        assert_eq!(harness.clipboard_contents(), TEXT);
+    }
+
+    #[test]
+    fn not_selectable_by_default() {
+        let label_tag = WidgetTag::named("label");
+        let label = Label::new("Hello").prepare().with_tag(label_tag);
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), label, (100, 40));
+        harness.mouse_move((1., 20.));
+        harness.mouse_button_press(None);
+        harness.mouse_move((90., 20.));
+        harness.mouse_button_release(None);
+
+        assert_eq!(harness.focused_widget_id(), None);
+        assert_eq!(harness.get_widget(label_tag).selection_range(), None);
+    }
+
+    #[test]
+    fn overflow_modes() {
+        fn overflowing_label(overflow: TextOverflow) -> NewWidget<SizedBox> {
+            SizedBox::new(
+                Label::new("The quick brown fox jumps over the lazy dog")
+                    .overflow(overflow)
+                    .prepare(),
+            )
+            .width(120.px())
+            .prepare()
+        }
+
+        for (overflow, name) in [
+            (TextOverflow::Clip, "label_overflow_clip"),
+            (TextOverflow::Ellipsis, "label_overflow_ellipsis"),
+            (TextOverflow::Fade, "label_overflow_fade"),
+        ] {
+            let mut harness = TestHarness::create_with_size(
+                test_property_set(),
+                overflowing_label(overflow),
+                (140, 40),
+            );
+            assert_render_snapshot!(harness, name);
+        }
+    }
+
+    #[test]
+    fn ellipsis_fits_in_label() {
+        let label = Label::new("The quick brown fox jumps over the lazy dog")
+            .overflow(TextOverflow::Ellipsis)
+            .prepare();
+        let mut harness = TestHarness::create_with_size(test_property_set(), label, (120, 40));
+        let _ = harness.render();
+
+        harness.edit_root_widget(|label| {
+            let label = &mut *label.widget;
+            let layout = &label.layouts[label.active_layout].layout;
+            let ellipsis_width = f64::from(label.ellipsis.as_ref().unwrap().width());
+            assert!(ellipsis_width > 0.);
+
+            let cut_x = truncation_point(layout, 0., 120. - ellipsis_width, false);
+            assert!(cut_x > 0.);
+            assert!(cut_x + ellipsis_width <= 120.);
+        });
+    }
+
+    #[test]
+    fn rtl_ellipsis_keeps_the_start_of_the_text() {
+        let label = Label::new("مرحبا بالعالم، هذا نص طويل لا يتسع في السطر")
+            .overflow(TextOverflow::Ellipsis)
+            .prepare();
+        let mut harness = TestHarness::create_with_size(test_property_set(), label, (120, 40));
+        let _ = harness.render();
+
+        harness.edit_root_widget(|label| {
+            let label = &mut *label.widget;
+            let layout = &label.layouts[label.active_layout].layout;
+            let ellipsis_width = f64::from(label.ellipsis.as_ref().unwrap().width());
+            let layout_width = f64::from(layout.width());
+            assert!(layout_width > 120.);
+
+            // With the end of the line on the label's right edge, the left part is cut.
+            let offset = 120. - layout_width;
+            let cut_x = truncation_point(layout, offset, ellipsis_width, true);
+            assert!(cut_x >= ellipsis_width);
+            assert!(cut_x < 120.);
+        });
+    }
+
     #[test]
     fn edit_label() {
         let image_1 = {
@@ -841,4 +1917,25 @@ mod tests {
         // We don't use assert_eq because we don't want rich assert
         assert!(image_1 == image_2);
     }
+
+    #[test]
+    fn heading_label_accessibility() {
+        let label = Label::new("Chapter one").heading_level(2).prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), label, (200, 40));
+        let _ = harness.render();
+
+        let node = harness.access_node(harness.root_id()).unwrap();
+        assert_eq!(node.data().role(), Role::Heading);
+        assert_eq!(node.data().level(), Some(2));
+
+        harness.edit_root_widget(|mut label| {
+            Label::set_heading_level(&mut label, None);
+        });
+        let _ = harness.render();
+
+        let node = harness.access_node(harness.root_id()).unwrap();
+        assert_eq!(node.data().role(), Role::Label);
+        assert_eq!(node.data().level(), None);
+    }
 }
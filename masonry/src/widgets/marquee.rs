@@ -0,0 +1,299 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::{
+    AccessCtx, ArcStr, ChildrenIds, ComposeCtx, LayoutCtx, MeasureCtx, NewWidget, NoAction,
+    PaintCtx, PropertiesMut, PropertiesRef, RegisterCtx, Update, UpdateCtx, Widget, WidgetId,
+    WidgetMut, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Point, Size, Vec2};
+use crate::layout::{LayoutSize, LenReq, Length, SizeDef};
+use crate::util::Sanitize;
+use crate::widgets::Label;
+
+/// A single line of text which scrolls horizontally when it doesn't fit.
+///
+/// When the text is wider than the marquee, it continuously scrolls to the left,
+/// and re-enters from the right once it has scrolled out of view.
+/// When the text fits, it stays static.
+/// The text is clipped to the marquee's bounds.
+///
+/// The text is displayed by an inner [`Label`], which can be styled as usual.
+pub struct Marquee {
+    label: WidgetPod<Label>,
+    /// Scrolling speed, in logical pixels per second.
+    speed: f64,
+    pause_on_hover: bool,
+    /// How far the text is scrolled to the left.
+    offset: f64,
+    text_width: f64,
+    width: f64,
+}
+
+// --- MARK: BUILDERS
+impl Marquee {
+    /// The default scrolling speed, in logical pixels per second.
+    pub const DEFAULT_SPEED: f64 = 50.;
+
+    /// Creates a new `Marquee` displaying the given text.
+    pub fn new(text: impl Into<ArcStr>) -> Self {
+        Self::from_label(Label::new(text).prepare())
+    }
+
+    /// Creates a new `Marquee` displaying the given label.
+    pub fn from_label(label: NewWidget<Label>) -> Self {
+        Self {
+            label: label.to_pod(),
+            speed: Self::DEFAULT_SPEED,
+            pause_on_hover: false,
+            offset: 0.,
+            text_width: 0.,
+            width: 0.,
+        }
+    }
+
+    /// Builder-style method to set the scrolling speed, in logical pixels per second.
+    ///
+    /// The speed must be finite and non-negative.
+    pub fn with_speed(mut self, speed: f64) -> Self {
+        self.speed = speed.sanitize("speed");
+        self
+    }
+
+    /// Builder-style method to set whether scrolling pauses while the marquee is hovered.
+    pub fn with_pause_on_hover(mut self, pause_on_hover: bool) -> Self {
+        self.pause_on_hover = pause_on_hover;
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl Marquee {
+    /// Returns the scrolling speed, in logical pixels per second.
+    pub fn speed(&self) -> f64 {
+        self.speed
+    }
+
+    /// Returns how far the text is currently scrolled to the left.
+    ///
+    /// This is negative while the text re-enters from the right.
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    /// Returns `true` if the text is wider than the marquee, and therefore scrolls.
+    pub fn is_scrolling(&self) -> bool {
+        self.text_width > self.width
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Marquee {
+    /// Sets the scrolling speed.
+    ///
+    /// See [`with_speed`](Self::with_speed) for details.
+    pub fn set_speed(this: &mut WidgetMut<'_, Self>, speed: f64) {
+        this.widget.speed = speed.sanitize("speed");
+    }
+
+    /// Sets whether scrolling pauses while the marquee is hovered.
+    pub fn set_pause_on_hover(this: &mut WidgetMut<'_, Self>, pause_on_hover: bool) {
+        this.widget.pause_on_hover = pause_on_hover;
+        this.ctx.request_anim_frame();
+    }
+
+    /// Sets the displayed text and restarts scrolling from the start.
+    pub fn set_text(this: &mut WidgetMut<'_, Self>, text: impl Into<ArcStr>) {
+        Label::set_text(&mut Self::label_mut(this), text);
+        this.widget.offset = 0.;
+        this.ctx.request_compose();
+    }
+
+    /// Returns a mutable reference to the inner label.
+    pub fn label_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, Label> {
+        this.ctx.get_mut(&mut this.widget.label)
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Marquee {
+    type Action = NoAction;
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        // Layout requests a frame again once the text overflows.
+        if !self.is_scrolling() {
+            return;
+        }
+        if self.pause_on_hover && ctx.has_hovered() {
+            // Resumed when the pointer leaves.
+            return;
+        }
+        ctx.request_anim_frame();
+
+        let delta = self.speed * (interval as f64) / 1e9;
+        let period = self.text_width + self.width;
+        self.offset = (self.offset + self.width + delta).rem_euclid(period) - self.width;
+        ctx.request_compose();
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.label);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::ChildHoveredChanged(false) if self.is_scrolling() => {
+                ctx.request_anim_frame();
+            }
+            _ => (),
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        // Like a scroll area, the marquee can be shrunk down to nothing.
+        if axis == Axis::Horizontal && len_req == LenReq::MinContent {
+            return Length::ZERO;
+        }
+
+        // The text is never wrapped, so we always ask for its max-content length.
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+        let auto_length = LenReq::MaxContent.into();
+        let length = ctx.compute_length(&mut self.label, auto_length, context_size, axis, None);
+        match len_req {
+            LenReq::FitContent(space) if axis == Axis::Horizontal => length.min(space),
+            _ => length,
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let text_size = ctx.compute_size(&mut self.label, SizeDef::MAX, size.into());
+        ctx.run_layout(&mut self.label, text_size);
+        let y = (size.height - text_size.height) / 2.;
+        ctx.place_child(&mut self.label, Point::new(0., y));
+        ctx.derive_baselines(&self.label);
+        ctx.set_clip_path(size.to_rect());
+
+        self.text_width = text_size.width;
+        self.width = size.width;
+        if self.is_scrolling() {
+            // Covers the text or the size changing, after which the text starts to overflow.
+            ctx.request_anim_frame();
+        } else {
+            self.offset = 0.;
+        }
+    }
+
+    fn compose(&mut self, ctx: &mut ComposeCtx<'_>) {
+        ctx.set_animated_child_scroll_translation(&mut self.label, Vec2::new(-self.offset, 0.));
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Marquee
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_clips_children();
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.label.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Marquee", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+
+    #[test]
+    fn long_text_scrolls() {
+        let marquee = Marquee::new("This text is far too long to fit in the marquee")
+            .with_speed(100.)
+            .prepare();
+        let mut harness = TestHarness::create_with_size(test_property_set(), marquee, (50, 20));
+        assert!(harness.root_widget().is_scrolling());
+        assert_eq!(harness.root_widget().offset(), 0.);
+
+        harness.animate_ms(100);
+        assert_eq!(harness.root_widget().offset(), 10.);
+
+        harness.animate_ms(200);
+        assert_eq!(harness.root_widget().offset(), 30.);
+    }
+
+    #[test]
+    fn short_text_is_static() {
+        let marquee = Marquee::new("Hi").prepare();
+        let mut harness = TestHarness::create_with_size(test_property_set(), marquee, (200, 20));
+        assert!(!harness.root_widget().is_scrolling());
+
+        harness.animate_ms(500);
+        assert_eq!(harness.root_widget().offset(), 0.);
+        // Nothing scrolls, so the marquee lets the app go idle.
+        assert!(!harness.needs_anim());
+
+        // Once the text overflows, the marquee starts animating again.
+        harness.edit_root_widget(|mut marquee| {
+            Marquee::set_text(
+                &mut marquee,
+                "This text is far too long to fit in the marquee",
+            );
+        });
+        assert!(harness.root_widget().is_scrolling());
+        assert!(harness.needs_anim());
+        harness.animate_ms(100);
+        assert!(harness.root_widget().offset() > 0.);
+    }
+
+    #[test]
+    fn pause_on_hover() {
+        let marquee = Marquee::new("This text is far too long to fit in the marquee")
+            .with_speed(100.)
+            .with_pause_on_hover(true)
+            .prepare();
+        let mut harness = TestHarness::create_with_size(test_property_set(), marquee, (50, 20));
+
+        harness.mouse_move((10., 10.));
+        harness.animate_ms(100);
+        assert_eq!(harness.root_widget().offset(), 0.);
+
+        harness.mouse_move((100., 100.));
+        harness.animate_ms(100);
+        assert_eq!(harness.root_widget().offset(), 10.);
+    }
+}
@@ -11,17 +11,23 @@
 mod align;
 mod badge;
 mod badged;
+mod brush_box;
 mod button;
 mod canvas;
 mod checkbox;
 mod collapse_panel;
 mod disclosure_button;
 mod divider;
+mod either;
 mod flex;
+mod frame;
 mod grid;
+mod hoverable;
 mod image;
 mod indexed_stack;
+mod keyboard_shortcut;
 mod label;
+mod marquee;
 mod pagination;
 mod passthrough;
 mod portal;
@@ -40,9 +46,13 @@ mod split;
 mod step_input;
 mod svg;
 mod switch;
+mod tabs;
 mod text_area;
 mod text_input;
+mod toast_overlay;
+mod transition;
 mod variable_label;
+mod viewport;
 mod virtual_scroll;
 mod zstack;
 
@@ -52,17 +62,23 @@ mod zstack;
 pub use self::align::*;
 pub use self::badge::*;
 pub use self::badged::*;
+pub use self::brush_box::*;
 pub use self::button::*;
 pub use self::canvas::*;
 pub use self::checkbox::*;
 pub use self::collapse_panel::*;
 pub use self::disclosure_button::*;
 pub use self::divider::*;
+pub use self::either::*;
 pub use self::flex::*;
+pub use self::frame::*;
 pub use self::grid::*;
+pub use self::hoverable::*;
 pub use self::image::*;
 pub use self::indexed_stack::*;
+pub use self::keyboard_shortcut::*;
 pub use self::label::*;
+pub use self::marquee::*;
 pub use self::pagination::*;
 pub use self::passthrough::*;
 pub use self::portal::*;
@@ -81,8 +97,12 @@ pub use self::split::*;
 pub use self::step_input::*;
 pub use self::svg::*;
 pub use self::switch::*;
+pub use self::tabs::*;
 pub use self::text_area::*;
 pub use self::text_input::*;
+pub use self::toast_overlay::*;
+pub use self::transition::*;
 pub use self::variable_label::*;
+pub use self::viewport::*;
 pub use self::virtual_scroll::*;
 pub use self::zstack::*;
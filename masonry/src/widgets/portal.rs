@@ -8,7 +8,7 @@ use tracing::{Span, trace_span};
 
 use crate::core::{
     AccessCtx, AccessEvent, ChildrenIds, ComposeCtx, EventCtx, FromDynWidget, LayoutCtx,
-    MeasureCtx, NewWidget, NoAction, PaintCtx, PointerEvent, PointerScrollEvent, PropertiesMut,
+    MeasureCtx, NewWidget, PaintCtx, PointerEvent, PointerScrollEvent, PropertiesMut,
     PropertiesRef, Property, RegisterCtx, TextEvent, Update, UpdateCtx, UsesProperty, Widget,
     WidgetId, WidgetMut, WidgetPod,
 };
@@ -42,6 +42,9 @@ const SCROLLBAR_ANIM_OVER_MILLIS: f32 = 300.;
 /// When nested inside another scrolling container, child scroll widgets should call
 /// [`EventCtx::set_handled`](crate::core::EventCtx::set_handled) after scrolling to prevent
 /// accidental double-scrolling due to event bubbling.
+///
+/// If enabled with [`scroll_actions`](Self::scroll_actions), emits a [`PortalScrolled`] action
+/// whenever the user scrolls the viewport.
 pub struct Portal<W: Widget + ?Sized> {
     child: WidgetPod<W>,
     content_size: Size,
@@ -54,6 +57,7 @@ pub struct Portal<W: Widget + ?Sized> {
     constrain_horizontal: bool,
     constrain_vertical: bool,
     must_fill: bool,
+    scroll_actions: bool,
     scrollbar_horizontal: WidgetPod<ScrollBar>,
     scrollbar_horizontal_visible: bool,
     scrollbar_vertical: WidgetPod<ScrollBar>,
@@ -61,6 +65,29 @@ pub struct Portal<W: Widget + ?Sized> {
     nanos_since_last_pointer_move: Option<u64>,
 }
 
+/// The viewport of a [`Portal`] was scrolled by the user.
+///
+/// This is not emitted when the viewport is moved programmatically,
+/// e.g. with [`Portal::set_viewport_pos`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PortalScrolled {
+    /// The new scrolling position, see [`Portal::get_viewport_pos`].
+    pub viewport_pos: Point,
+    /// The size of the visible area.
+    pub viewport_size: Size,
+    /// The size of the scrolled content, see [`Portal::content_size`].
+    pub content_size: Size,
+}
+
+impl PortalScrolled {
+    /// Returns the distance between the bottom of the viewport and the bottom of the content.
+    ///
+    /// This is useful for loading more content when the user nears the end of a list.
+    pub fn remaining_height(&self) -> f64 {
+        (self.content_size.height - self.viewport_pos.y - self.viewport_size.height).max(0.)
+    }
+}
+
 // --- MARK: BUILDERS
 impl<W: Widget + ?Sized> Portal<W> {
     /// Creates a scrolling container the given child widget.
@@ -72,6 +99,7 @@ impl<W: Widget + ?Sized> Portal<W> {
             constrain_horizontal: false,
             constrain_vertical: false,
             must_fill: false,
+            scroll_actions: false,
             // TODO - remove (TODO: why?)
             scrollbar_horizontal: WidgetPod::new(ScrollBar::new(Axis::Horizontal, 0.0, 0.0)),
             scrollbar_horizontal_visible: false,
@@ -122,6 +150,14 @@ impl<W: Widget + ?Sized> Portal<W> {
         self.must_fill = must_fill;
         self
     }
+
+    /// Builder-style method to set whether to emit [`PortalScrolled`] actions.
+    ///
+    /// The default is `false`.
+    pub fn scroll_actions(mut self, scroll_actions: bool) -> Self {
+        self.scroll_actions = scroll_actions;
+        self
+    }
 }
 
 pub(crate) fn compute_pan_range(mut viewport: Range<f64>, target: Range<f64>) -> Range<f64> {
@@ -194,10 +230,22 @@ impl<W: Widget + ?Sized> Portal<W> {
         if changed {
             ctx.request_compose();
             self.update_scrollbars_from_viewport(ctx, portal_size, content_size);
+            self.submit_scrolled(ctx, portal_size, content_size);
         }
         changed
     }
 
+    fn submit_scrolled(&self, ctx: &mut EventCtx<'_>, portal_size: Size, content_size: Size) {
+        if !self.scroll_actions {
+            return;
+        }
+        ctx.submit_action::<PortalScrolled>(PortalScrolled {
+            viewport_pos: self.viewport_pos,
+            viewport_size: portal_size,
+            content_size,
+        });
+    }
+
     fn pan_viewport_by_event_ctx(
         &mut self,
         ctx: &mut EventCtx<'_>,
@@ -254,6 +302,7 @@ impl<W: Widget + ?Sized> Portal<W> {
         if changed {
             ctx.request_compose();
             self.update_scrollbars_from_viewport(ctx, portal_size, content_size);
+            self.submit_scrolled(ctx, portal_size, content_size);
         }
 
         changed
@@ -264,6 +313,11 @@ impl<W: Widget + ?Sized> Portal<W> {
         self.viewport_pos
     }
 
+    /// Returns the size of the child, as of the last layout.
+    pub fn content_size(&self) -> Size {
+        self.content_size
+    }
+
     // TODO - rename
     fn set_viewport_pos_raw(&mut self, portal_size: Size, content_size: Size, pos: Point) -> bool {
         let viewport_max_pos = (content_size - portal_size).max(Size::ZERO);
@@ -355,6 +409,13 @@ impl<W: Widget + FromDynWidget + ?Sized> Portal<W> {
         this.ctx.request_layout();
     }
 
+    /// Sets whether to emit [`PortalScrolled`] actions.
+    ///
+    /// See [`Portal::scroll_actions`] for more details.
+    pub fn set_scroll_actions(this: &mut WidgetMut<'_, Self>, scroll_actions: bool) {
+        this.widget.scroll_actions = scroll_actions;
+    }
+
     /// Sets the scrolling "position" of the container.
     ///
     /// A position of zero means no scrolling at all.
@@ -409,7 +470,7 @@ impl<W: Widget> UsesProperty<AutoHideScrollBar> for Portal<W> {}
 
 // --- MARK: IMPL WIDGET
 impl<W: Widget + FromDynWidget + ?Sized> Widget for Portal<W> {
-    type Action = NoAction;
+    type Action = PortalScrolled;
 
     fn on_pointer_event(
         &mut self,
@@ -921,7 +982,7 @@ mod tests {
     use crate::core::WidgetTag;
     use crate::core::keyboard::{Key, NamedKey};
     use crate::layout::AsUnit;
-    use crate::properties::Dimensions;
+    use crate::properties::{Dimensions, Gap};
     use crate::testing::{ModularWidget, TestHarness, assert_render_snapshot};
     use crate::theme::test_property_set;
     use crate::widgets::{Button, Flex, SizedBox};
@@ -1017,6 +1078,183 @@ mod tests {
         assert_render_snapshot!(harness, "portal_scrolled_button_into_view");
     }
 
+    #[test]
+    fn tab_focus_scrolls_into_view() {
+        let top_tag = WidgetTag::named("top-button");
+        let bottom_tag = WidgetTag::named("bottom-button");
+
+        let widget = Portal::new(
+            Flex::column()
+                .with_fixed(NewWidget::new(Button::with_text("Top")).with_tag(top_tag))
+                .with_fixed_spacer(500.px())
+                .with_fixed(NewWidget::new(Button::with_text("Bottom")).with_tag(bottom_tag))
+                .prepare(),
+        )
+        .prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (200, 200));
+        let top_id = harness.get_widget(top_tag).id();
+        let bottom_id = harness.get_widget(bottom_tag).id();
+
+        harness.focus_on(Some(top_id));
+        assert_eq!(harness.root_widget().get_viewport_pos(), Point::ORIGIN);
+
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::Tab)));
+        assert_eq!(harness.focused_widget_id(), Some(bottom_id));
+        assert!(harness.root_widget().get_viewport_pos().y > 0.);
+    }
+
+    #[test]
+    fn tall_flex_content_size() {
+        let flex_tag = WidgetTag::named("flex");
+        let flex = (0..10).fold(Flex::column(), |flex, _| {
+            flex.with_fixed(SizedBox::empty().size(50.px(), 50.px()).prepare())
+        });
+        let flex = NewWidget::new(flex)
+            .with_tag(flex_tag)
+            .with_props(Gap::ZERO);
+        let widget = Portal::new(flex).prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (200, 200));
+
+        // The flex column keeps its full height instead of being clamped to the viewport.
+        let flex_height = harness.get_widget(flex_tag).ctx().border_box().height();
+        assert_eq!(flex_height, 500.);
+        assert_eq!(harness.root_widget().content_size().height, flex_height);
+
+        harness.edit_root_widget(|mut portal| {
+            Portal::pan_viewport_by(&mut portal, Vec2::new(0., 99999.));
+        });
+        assert_eq!(
+            harness.root_widget().get_viewport_pos(),
+            Point::new(0., 300.)
+        );
+    }
+
+    #[test]
+    fn scroll_emits_action() {
+        let widget = Portal::new(Flex::column().with_fixed_spacer(1000.px()).prepare())
+            .scroll_actions(true)
+            .prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (200, 200));
+        let portal_id = harness.root_id();
+        assert_eq!(harness.root_widget().content_size().height, 1000.);
+
+        harness.mouse_move((100., 100.));
+        harness.mouse_wheel(Vec2::new(0., -750.));
+
+        let (action, id) = harness.pop_action::<PortalScrolled>().unwrap();
+        assert_eq!(id, portal_id);
+        assert_eq!(action.viewport_pos, Point::new(0., 750.));
+        assert_eq!(action.viewport_size, Size::new(200., 200.));
+        assert!(action.remaining_height() < 100.);
+        assert_eq!(
+            harness.root_widget().get_viewport_pos(),
+            action.viewport_pos
+        );
+
+        // Programmatic changes don't emit actions.
+        harness.edit_root_widget(|mut portal| {
+            Portal::set_viewport_pos(&mut portal, Point::ORIGIN);
+        });
+        assert!(harness.pop_action_erased().is_none());
+
+        // Scrolling doesn't emit actions once they are disabled.
+        harness.edit_root_widget(|mut portal| {
+            Portal::set_scroll_actions(&mut portal, false);
+        });
+        harness.mouse_wheel(Vec2::new(0., -100.));
+        assert!(harness.root_widget().get_viewport_pos().y > 0.);
+        assert!(harness.pop_action_erased().is_none());
+    }
+
+    #[test]
+    fn scroll_clamped_to_content() {
+        let widget = Portal::new(Flex::column().with_fixed_spacer(1000.px()).prepare()).prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (200, 200));
+
+        // Scrolling up from the top does nothing.
+        harness.mouse_move((100., 100.));
+        harness.mouse_wheel(Vec2::new(0., 300.));
+        assert_eq!(harness.root_widget().get_viewport_pos(), Point::ORIGIN);
+        assert!(harness.pop_action::<PortalScrolled>().is_none());
+
+        // Scrolling down past the bottom stops at the end of the content.
+        harness.mouse_wheel(Vec2::new(0., -5000.));
+        assert_eq!(
+            harness.root_widget().get_viewport_pos(),
+            Point::new(0., 800.)
+        );
+
+        harness.edit_root_widget(|mut portal| {
+            Portal::set_viewport_pos(&mut portal, Point::new(-50., -50.));
+        });
+        assert_eq!(harness.root_widget().get_viewport_pos(), Point::ORIGIN);
+    }
+
+    #[test]
+    fn keyboard_scroll_by_page_and_line() {
+        let widget = Portal::new(Flex::column().with_fixed_spacer(1000.px()).prepare()).prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (200, 200));
+        harness.focus_on(Some(harness.root_id()));
+        let mut press = |key: NamedKey| {
+            harness.process_text_event(TextEvent::key_down(Key::Named(key)));
+            harness.root_widget().get_viewport_pos()
+        };
+
+        // PageDown advances by the viewport height.
+        assert_eq!(press(NamedKey::PageDown), Point::new(0., 200.));
+        assert_eq!(press(NamedKey::PageDown), Point::new(0., 400.));
+        assert_eq!(press(NamedKey::PageDown), Point::new(0., 600.));
+        assert_eq!(press(NamedKey::PageDown), Point::new(0., 800.));
+        // ... and is clamped at the bottom.
+        assert_eq!(press(NamedKey::PageDown), Point::new(0., 800.));
+
+        assert_eq!(press(NamedKey::ArrowUp), Point::new(0., 680.));
+        assert_eq!(press(NamedKey::PageUp), Point::new(0., 480.));
+        assert_eq!(press(NamedKey::Home), Point::ORIGIN);
+        assert_eq!(press(NamedKey::End), Point::new(0., 800.));
+    }
+
+    #[test]
+    fn pan_viewport_to_target() {
+        let widget = Portal::new(Flex::column().with_fixed_spacer(1000.px()).prepare()).prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (200, 200));
+
+        // A target below the viewport is brought in at the bottom edge.
+        harness.edit_root_widget(|mut portal| {
+            let changed = Portal::pan_viewport_to(&mut portal, Rect::new(0., 500., 50., 550.));
+            assert!(changed);
+        });
+        assert_eq!(
+            harness.root_widget().get_viewport_pos(),
+            Point::new(0., 350.)
+        );
+
+        // A target which is already visible doesn't move the viewport.
+        harness.edit_root_widget(|mut portal| {
+            let changed = Portal::pan_viewport_to(&mut portal, Rect::new(0., 400., 50., 450.));
+            assert!(!changed);
+        });
+        assert_eq!(
+            harness.root_widget().get_viewport_pos(),
+            Point::new(0., 350.)
+        );
+
+        // A target above the viewport is brought in at the top edge.
+        harness.edit_root_widget(|mut portal| {
+            Portal::pan_viewport_to(&mut portal, Rect::new(0., 100., 50., 150.));
+        });
+        assert_eq!(
+            harness.root_widget().get_viewport_pos(),
+            Point::new(0., 100.)
+        );
+    }
+
     #[test]
     fn autohidden_scrollbar() {
         let widget = Portal::new(Flex::column().with_fixed_spacer(1000.px()).prepare())
@@ -11,7 +11,7 @@ use crate::core::{
     AccessCtx, ArcStr, ChildrenIds, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
     PrePaintProps, PropertiesMut, PropertiesRef, Property, PropertySet, RegisterCtx, Update,
     UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod, paint_background, paint_border,
-    paint_box_shadow,
+    paint_box_shadow, paint_inset_shadow, set_numeric_range,
 };
 use crate::imaging::Painter;
 use crate::kurbo::{Axis, Size};
@@ -187,8 +187,9 @@ impl Widget for ProgressBar {
         let cache = ctx.property_cache();
         let p = PrePaintProps::fetch(props, cache);
 
-        paint_box_shadow(painter, bbox, p.box_shadow, p.corner_radius);
+        paint_box_shadow(painter, bbox, p.box_shadow, p.box_shadows, p.corner_radius);
         paint_background(painter, bbox, p.background, p.border_width, p.corner_radius);
+        paint_inset_shadow(painter, bbox, p.box_shadow, p.box_shadows, p.corner_radius);
         // We need to delay painting the border until after we paint the filled bar area.
     }
 
@@ -207,7 +208,7 @@ impl Widget for ProgressBar {
         let progress = self.progress.unwrap_or(1.);
         if progress > 0. {
             // The bar width is without the borders.
-            let bar_width = border_box.width() - 2. * border_width.width.get();
+            let bar_width = border_box.width() - border_width.length(Axis::Horizontal).get();
             if bar_width > 0. {
                 let bar_color = props.get::<BarColor>(cache).0;
                 // Paint with a gradient so we get a straight line slice of the rounded rect.
@@ -246,11 +247,7 @@ impl Widget for ProgressBar {
         _props: &PropertiesRef<'_>,
         node: &mut Node,
     ) {
-        node.set_min_numeric_value(0.0);
-        node.set_max_numeric_value(1.0);
-        if let Some(value) = self.progress {
-            node.set_numeric_value(value);
-        }
+        set_numeric_range(node, self.progress, 0.0, 1.0);
     }
 
     fn children_ids(&self) -> ChildrenIds {
@@ -19,7 +19,8 @@ use crate::widgets::TextArea;
 /// selected within.
 ///
 /// The text can also be copied from, but cannot be modified by the user.
-/// Note that copying is not yet implemented.
+/// When focused, `Ctrl+A` (`Cmd+A` on macOS) selects all of the text, and `Ctrl+C` copies
+/// the selection. The selection is cleared when the prose loses focus.
 ///
 /// At runtime, most properties of the text will be set using [`text_mut`](Self::text_mut).
 /// This is because `Prose` largely serves as a wrapper around a [`TextArea`].
@@ -205,7 +206,8 @@ impl Widget for Prose {
 mod tests {
     use super::*;
     use crate::TextAlign;
-    use crate::core::PropertySet;
+    use crate::core::keyboard::{Key, Modifiers};
+    use crate::core::{PropertySet, WidgetTag};
     use crate::layout::AsUnit;
     use crate::parley::StyleProperty;
     use crate::properties::Gap;
@@ -270,4 +272,36 @@ mod tests {
 
         assert_render_snapshot!(harness, "prose_alignment_flex");
     }
+
+    #[test]
+    #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
+    fn select_all_and_copy() {
+        const TEXT: &str = "This text is long enough to wrap over several lines";
+        let text_tag = WidgetTag::named("text");
+        let prose = Prose::from_text_area(
+            TextArea::new_immutable(TEXT)
+                .with_word_wrap(true)
+                .prepare()
+                .with_tag(text_tag),
+        )
+        .prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), prose, (60, 200));
+        let text_id = harness.get_widget(text_tag).id();
+        harness.focus_on(Some(text_id));
+
+        let action_mod = if cfg!(target_os = "macos") {
+            Modifiers::META
+        } else {
+            Modifiers::CONTROL
+        };
+        harness.keyboard_key(Key::Character("a".into()), action_mod);
+        harness.keyboard_key(Key::Character("c".into()), action_mod);
+
+        assert_eq!(harness.get_widget(text_tag).selected_text(), Some(TEXT));
+        assert_eq!(harness.clipboard_contents(), TEXT);
+
+        harness.focus_on(None);
+        assert_eq!(harness.get_widget(text_tag).selected_text(), None);
+    }
 }
@@ -2,9 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::any::TypeId;
+use std::sync::Arc;
 
 use accesskit::{Node, Role, Toggled};
 use include_doc_path::include_doc_path;
+use masonry_core::anymore::AnyDebug;
 use masonry_core::debug_panic;
 use tracing::{Span, trace, trace_span};
 
@@ -12,14 +14,14 @@ use crate::core::{
     AccessCtx, AccessEvent, ArcStr, ChildrenIds, EventCtx, LayoutCtx, MeasureCtx, NewWidget,
     PaintCtx, PointerEvent, PrePaintProps, PropertiesMut, PropertiesRef, RegisterCtx, TextEvent,
     Update, UpdateCtx, UsesProperty, Widget, WidgetId, WidgetMut, WidgetPod, keyboard::Key,
-    paint_background, paint_box_shadow,
+    paint_background, paint_box_shadow, paint_inset_shadow,
 };
 use crate::imaging::Painter;
 use crate::kurbo::{Axis, Cap, Circle, Dashes, Join, Point, Size, Stroke};
 use crate::layout::{LayoutSize, LenReq, Length, SizeDef};
 use crate::properties::{BorderColor, BorderWidth, CheckmarkColor, CheckmarkStrokeWidth};
 use crate::theme;
-use crate::widgets::{Label, RadioGroup};
+use crate::widgets::{Label, RadioGroup, RadioGroupChanged};
 
 /// A radio button that can be toggled.
 ///
@@ -30,11 +32,15 @@ use crate::widgets::{Label, RadioGroup};
 )]
 ///
 /// Emits [`RadioButtonSelected`] when selected.
+///
+/// A value can be attached to the button with [`with_value`](Self::with_value),
+/// which its [`RadioGroup`] reports in [`RadioGroupChanged`].
 pub struct RadioButton {
     selected: bool,
     // FIXME - Remove label child, have this widget only be a box with a checkmark.
     label: WidgetPod<Label>,
     parent_group: Option<WidgetId>,
+    value: Option<Arc<dyn AnyDebug + Send + Sync>>,
 }
 
 impl RadioButton {
@@ -44,6 +50,7 @@ impl RadioButton {
             selected: checked,
             label: WidgetPod::new(Label::new(text)),
             parent_group: None,
+            value: None,
         }
     }
 
@@ -53,8 +60,17 @@ impl RadioButton {
             selected: checked,
             label: label.to_pod(),
             parent_group: None,
+            value: None,
         }
     }
+
+    /// Builder-style method for attaching a value to this button.
+    ///
+    /// The value is reported in [`RadioGroupChanged`] when the user selects this button.
+    pub fn with_value(mut self, value: Arc<dyn AnyDebug + Send + Sync>) -> Self {
+        self.value = Some(value);
+        self
+    }
 }
 
 // --- MARK: WIDGETMUT
@@ -71,10 +87,20 @@ impl RadioButton {
         let self_id = this.ctx.widget_id();
         this.ctx.mutate_later(parent_id, move |mut group| {
             let group = group.downcast::<RadioGroup>();
-            Self::update_group(group, self_id, checked);
+            Self::update_group(group, self_id, checked, None);
         });
     }
 
+    /// Set or clear the value attached to this button.
+    ///
+    /// See [`with_value`](Self::with_value) for details.
+    pub fn set_value(
+        this: &mut WidgetMut<'_, Self>,
+        value: Option<Arc<dyn AnyDebug + Send + Sync>>,
+    ) {
+        this.widget.value = value;
+    }
+
     /// Set the text.
     ///
     /// We enforce this to be an `ArcStr` to make the allocation explicit.
@@ -107,13 +133,41 @@ impl RadioButton {
             return;
         };
         let self_id = ctx.widget_id();
+        let value = self.value.clone();
         ctx.mutate_later(parent_id, move |mut group| {
             let group = group.downcast::<RadioGroup>();
-            Self::update_group(group, self_id, true);
+            Self::update_group(group, self_id, true, Some(value));
+        });
+    }
+
+    /// Selects this button on behalf of the user, as [`select`](Self::select) does.
+    pub(crate) fn select_mut(this: &mut WidgetMut<'_, Self>) {
+        this.widget.selected = true;
+        this.ctx
+            .submit_action::<RadioButtonSelected>(RadioButtonSelected);
+        this.ctx.request_render();
+
+        let Some(parent_id) = this.widget.parent_group else {
+            return;
+        };
+        let self_id = this.ctx.widget_id();
+        let value = this.widget.value.clone();
+        this.ctx.mutate_later(parent_id, move |mut group| {
+            let group = group.downcast::<RadioGroup>();
+            Self::update_group(group, self_id, true, Some(value));
         });
     }
 
-    fn update_group(mut group: WidgetMut<'_, RadioGroup>, self_id: WidgetId, selected: bool) {
+    /// Updates the selection of `group` after this button changed.
+    ///
+    /// If the user selected the button, `user_value` holds its value, and the group
+    /// emits [`RadioGroupChanged`] if the selection changed.
+    fn update_group(
+        mut group: WidgetMut<'_, RadioGroup>,
+        self_id: WidgetId,
+        selected: bool,
+        user_value: Option<Option<Arc<dyn AnyDebug + Send + Sync>>>,
+    ) {
         let selected_button = group.widget.selected_button;
         if let Some(button_id) = selected_button
             && button_id != self_id
@@ -126,7 +180,17 @@ impl RadioButton {
                 button.widget.selected = false;
                 button.ctx.request_render();
             });
-            // TODO - Submit action from RadioGroup?
+        }
+        if selected
+            && selected_button != Some(self_id)
+            && let Some(value) = user_value
+        {
+            group
+                .ctx
+                .submit_action::<RadioGroupChanged>(RadioGroupChanged {
+                    selected: self_id,
+                    value,
+                });
         }
 
         if let Some(button_id) = selected_button
@@ -209,6 +273,13 @@ impl Widget for RadioButton {
                 };
 
                 self.parent_group = Some(id);
+                let self_id = ctx.widget_id();
+                if self.selected {
+                    ctx.mutate_later(id, move |mut group| {
+                        let group = group.downcast::<RadioGroup>();
+                        Self::update_group(group, self_id, true, None);
+                    });
+                }
             }
             Update::HoveredChanged(_)
             | Update::ActiveChanged(_)
@@ -298,8 +369,9 @@ impl Widget for RadioButton {
         let cache = ctx.property_cache();
         let p = PrePaintProps::fetch(props, cache);
 
-        paint_box_shadow(painter, bbox, p.box_shadow, p.corner_radius);
+        paint_box_shadow(painter, bbox, p.box_shadow, p.box_shadows, p.corner_radius);
         paint_background(painter, bbox, p.background, p.border_width, p.corner_radius);
+        paint_inset_shadow(painter, bbox, p.box_shadow, p.box_shadows, p.corner_radius);
 
         // Paint focus indicator around the entire widget (box + label)
         if ctx.is_focus_target() || ctx.is_hovered() {
@@ -344,11 +416,11 @@ impl Widget for RadioButton {
 
         let border_circle = Circle::new(
             check_size.to_rect().center(),
-            (check_side - border_width.width.get()) * 0.5,
+            (check_side - border_width.max_width().get()) * 0.5,
         );
 
         // Paint the radio button border
-        let border_stroke = Stroke::new(border_width.width.get());
+        let border_stroke = Stroke::new(border_width.max_width().get());
         painter
             .stroke(border_circle, &border_stroke, border_color.color)
             .draw();
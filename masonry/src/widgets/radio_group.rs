@@ -1,23 +1,52 @@
 // Copyright 2026 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use std::sync::Arc;
+
 use accesskit::{Node, Role};
+use masonry_core::anymore::AnyDebug;
 use tracing::{Span, trace_span};
 
+use crate::core::keyboard::{Key, NamedKey};
 use crate::core::{
-    AccessCtx, ChildrenIds, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx, PropertiesRef,
-    RegisterCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+    AccessCtx, ChildrenIds, EventCtx, LayoutCtx, MeasureCtx, NewWidget, PaintCtx, PropertiesMut,
+    PropertiesRef, RegisterCtx, TextEvent, Widget, WidgetId, WidgetMut, WidgetPod, WidgetRef,
 };
 use crate::imaging::Painter;
 use crate::kurbo::{Axis, Point, Size};
 use crate::layout::{LenReq, Length};
+use crate::widgets::{Flex, RadioButton};
 
 /// A radio group container that holds radio buttons.
+///
+/// The group owns the selection: selecting one of its [`RadioButton`]s deselects the others.
+/// The buttons can be nested anywhere inside the group's child, or laid out
+/// in a [`Flex`] with [`column`](Self::column) and [`row`](Self::row).
+///
+/// When one of its buttons is focused, the arrow keys move the focus and selection
+/// to the next or previous button, wrapping around at the ends.
+/// Disabled and stashed buttons are skipped.
+///
+/// Emits [`RadioGroupChanged`] when the user selects a different button.
 pub struct RadioGroup {
     pub(crate) child: WidgetPod<dyn Widget>,
     pub(crate) selected_button: Option<WidgetId>,
 }
 
+/// The action type emitted by [`RadioGroup`] when the user selects a different radio button.
+///
+/// This is not emitted when the selection is changed with [`RadioButton::set_checked`].
+#[derive(Debug, Clone)]
+pub struct RadioGroupChanged {
+    /// The newly selected radio button.
+    pub selected: WidgetId,
+    /// The value of the newly selected radio button, if it has one.
+    ///
+    /// See [`RadioButton::with_value`].
+    pub value: Option<Arc<dyn AnyDebug + Send + Sync>>,
+}
+
+// --- MARK: BUILDERS
 impl RadioGroup {
     /// Create a new `RadioGroup`.
     pub fn new(child: NewWidget<impl Widget + ?Sized>) -> Self {
@@ -26,6 +55,58 @@ impl RadioGroup {
             selected_button: None,
         }
     }
+
+    /// Create a new `RadioGroup` with the given buttons laid out in a column.
+    pub fn column(buttons: impl IntoIterator<Item = NewWidget<RadioButton>>) -> Self {
+        Self::with_flex(Flex::column(), buttons)
+    }
+
+    /// Create a new `RadioGroup` with the given buttons laid out in a row.
+    pub fn row(buttons: impl IntoIterator<Item = NewWidget<RadioButton>>) -> Self {
+        Self::with_flex(Flex::row(), buttons)
+    }
+
+    fn with_flex(flex: Flex, buttons: impl IntoIterator<Item = NewWidget<RadioButton>>) -> Self {
+        let flex = buttons.into_iter().fold(flex, Flex::with_fixed);
+        Self::new(flex.prepare())
+    }
+}
+
+// --- MARK: METHODS
+impl RadioGroup {
+    /// Returns the id of the currently selected radio button, if any.
+    pub fn selected(&self) -> Option<WidgetId> {
+        self.selected_button
+    }
+}
+
+impl RadioGroupChanged {
+    /// Returns the value of the newly selected radio button, if it has one of type `T`.
+    pub fn value<T: AnyDebug>(&self) -> Option<&T> {
+        let value: &(dyn AnyDebug + Send + Sync) = self.value.as_deref()?;
+        value.downcast_ref::<T>()
+    }
+}
+
+// --- MARK: HELPERS
+/// Collects the radio buttons in `widget` which can be reached with the arrow keys,
+/// in tree order.
+///
+/// Disabled and stashed buttons are skipped, as are the buttons of nested groups.
+fn collect_navigable_buttons(widget: WidgetRef<'_, dyn Widget>, buttons: &mut Vec<WidgetId>) {
+    if widget.ctx().is_disabled() || widget.ctx().is_stashed() {
+        return;
+    }
+    if widget.downcast::<RadioButton>().is_some() {
+        buttons.push(widget.id());
+        return;
+    }
+    if widget.downcast::<RadioGroup>().is_some() {
+        return;
+    }
+    for child in widget.children() {
+        collect_navigable_buttons(child, buttons);
+    }
 }
 
 // --- MARK: WIDGETMUT
@@ -39,9 +120,45 @@ impl RadioGroup {
 
 // --- MARK: IMPL WIDGET
 impl Widget for RadioGroup {
-    type Action = NoAction;
+    type Action = RadioGroupChanged;
 
-    // TODO: navigation shortcuts.
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        let TextEvent::Keyboard(event) = event else {
+            return;
+        };
+        if !event.state.is_down() {
+            return;
+        }
+        let forward = match &event.key {
+            Key::Named(NamedKey::ArrowDown | NamedKey::ArrowRight) => true,
+            Key::Named(NamedKey::ArrowUp | NamedKey::ArrowLeft) => false,
+            _ => return,
+        };
+        let target = ctx.target();
+        let mut buttons = Vec::new();
+        collect_navigable_buttons(ctx.get(self.child.id()), &mut buttons);
+        let Some(idx) = buttons.iter().position(|id| *id == target) else {
+            return;
+        };
+
+        let len = buttons.len();
+        let next_idx = if forward {
+            (idx + 1) % len
+        } else {
+            (idx + len - 1) % len
+        };
+        let next_id = buttons[next_idx];
+        ctx.set_focus(next_id);
+        ctx.mutate_later(next_id, |mut button| {
+            RadioButton::select_mut(&mut button.downcast());
+        });
+        ctx.set_handled();
+    }
 
     fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
         ctx.register_child(&mut self.child);
@@ -93,4 +210,129 @@ impl Widget for RadioGroup {
     }
 }
 
-// TODO: make tests.
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{CollectionWidget, WidgetTag};
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+    use crate::widgets::{FlexVisibility, RadioButtonSelected};
+
+    #[test]
+    fn arrow_keys_move_selection() {
+        let tags = [
+            WidgetTag::unique(),
+            WidgetTag::unique(),
+            WidgetTag::unique(),
+        ];
+        let buttons = ["Banana", "Apple", "Lime"]
+            .into_iter()
+            .zip(tags)
+            .map(|(text, tag)| {
+                NewWidget::new(RadioButton::new(false, text).with_value(Arc::new(text)))
+                    .with_tag(tag)
+            });
+        let group = RadioGroup::column(buttons).prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), group, (200, 200));
+        let group_id = harness.root_id();
+        let ids = tags.map(|tag| harness.get_widget(tag).id());
+        assert_eq!(harness.root_widget().selected(), None);
+
+        harness.focus_on(Some(ids[0]));
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::ArrowDown)));
+        assert_eq!(harness.focused_widget_id(), Some(ids[1]));
+        assert_eq!(harness.root_widget().selected(), Some(ids[1]));
+        assert_eq!(
+            harness.pop_action::<RadioButtonSelected>(),
+            Some((RadioButtonSelected, ids[1]))
+        );
+        let (changed, source) = harness.pop_action::<RadioGroupChanged>().unwrap();
+        assert_eq!(source, group_id);
+        assert_eq!(changed.selected, ids[1]);
+        assert_eq!(changed.value::<&str>(), Some(&"Apple"));
+
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::ArrowUp)));
+        assert_eq!(harness.focused_widget_id(), Some(ids[0]));
+        assert_eq!(harness.root_widget().selected(), Some(ids[0]));
+
+        // Moving past the first button wraps around to the last one.
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::ArrowUp)));
+        assert_eq!(harness.focused_widget_id(), Some(ids[2]));
+        assert_eq!(harness.root_widget().selected(), Some(ids[2]));
+    }
+
+    #[test]
+    fn arrow_keys_skip_unavailable_buttons() {
+        let tags = [
+            WidgetTag::unique(),
+            WidgetTag::unique(),
+            WidgetTag::unique(),
+            WidgetTag::unique(),
+        ];
+        let buttons = ["Banana", "Apple", "Lime", "Cherry"]
+            .into_iter()
+            .zip(tags)
+            .map(|(text, tag)| NewWidget::new(RadioButton::new(false, text)).with_tag(tag));
+        let group = RadioGroup::column(buttons).prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), group, (200, 200));
+        let ids = tags.map(|tag| harness.get_widget(tag).id());
+
+        // Disabled and stashed buttons are skipped.
+        harness.set_disabled(tags[1], true);
+        harness.edit_root_widget(|mut group| {
+            let mut flex = RadioGroup::child_mut(&mut group);
+            let mut flex = flex.downcast::<Flex>();
+            Flex::set_child_visibility(&mut flex, 2, FlexVisibility::Hidden);
+        });
+        harness.focus_on(Some(ids[0]));
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::ArrowDown)));
+        assert_eq!(harness.focused_widget_id(), Some(ids[3]));
+        assert_eq!(harness.root_widget().selected(), Some(ids[3]));
+
+        // Removed buttons are skipped.
+        harness.set_disabled(tags[1], false);
+        harness.edit_root_widget(|mut group| {
+            let mut flex = RadioGroup::child_mut(&mut group);
+            let mut flex = flex.downcast::<Flex>();
+            Flex::remove(&mut flex, 1);
+        });
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::ArrowDown)));
+        assert_eq!(harness.focused_widget_id(), Some(ids[0]));
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::ArrowUp)));
+        assert_eq!(harness.focused_widget_id(), Some(ids[3]));
+    }
+
+    #[test]
+    fn arrow_keys_follow_tree_order() {
+        let tags = [
+            WidgetTag::unique(),
+            WidgetTag::unique(),
+            WidgetTag::unique(),
+        ];
+        let group = RadioGroup::column([
+            NewWidget::new(RadioButton::new(false, "Banana")).with_tag(tags[0]),
+            NewWidget::new(RadioButton::new(false, "Lime")).with_tag(tags[2]),
+        ])
+        .prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), group, (200, 200));
+
+        // A button added later, but placed between the others.
+        harness.edit_root_widget(|mut group| {
+            let mut flex = RadioGroup::child_mut(&mut group);
+            let mut flex = flex.downcast::<Flex>();
+            let apple = NewWidget::new(RadioButton::new(false, "Apple")).with_tag(tags[1]);
+            Flex::insert_fixed(&mut flex, 1, apple);
+        });
+        let ids = tags.map(|tag| harness.get_widget(tag).id());
+
+        harness.focus_on(Some(ids[0]));
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::ArrowDown)));
+        assert_eq!(harness.focused_widget_id(), Some(ids[1]));
+        harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::ArrowDown)));
+        assert_eq!(harness.focused_widget_id(), Some(ids[2]));
+    }
+}
@@ -5,7 +5,7 @@ use std::any::TypeId;
 
 use accesskit::{Node, Role};
 use include_doc_path::include_doc_path;
-use tracing::{Span, trace_span};
+use tracing::{Span, trace_span, warn};
 
 use crate::core::{
     AccessCtx, ChildrenIds, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx, PropertiesRef,
@@ -14,7 +14,7 @@ use crate::core::{
 use crate::imaging::Painter;
 use crate::kurbo::{Axis, Point, Size};
 use crate::layout::{LayoutSize, LenReq, Length};
-use crate::properties::{BorderWidth, Padding};
+use crate::properties::{BorderWidth, CornerRadius, Padding};
 
 /// A widget with bi-directional size enforcement.
 ///
@@ -34,6 +34,13 @@ use crate::properties::{BorderWidth, Padding};
 /// the `SizedBox` child's measurement result as the size for `SizedBox`.
 /// Set [`Dimensions::MAX`] for `SizedBox` to ensure there is no explicit size from props either.
 ///
+/// The adopted size can be limited with [`min_width`](Self::min_width),
+/// [`max_width`](Self::max_width), [`min_height`](Self::min_height),
+/// and [`max_height`](Self::max_height).
+/// A child which measures larger than the maximum is clamped to it, while a smaller one
+/// keeps its own size. The minimum takes precedence over the maximum.
+/// These limits don't apply to an explicit size, which wins over them.
+///
 /// ## Child's size
 ///
 /// Whatever content-box size `SizedBox` ends up getting from its parent for layout,
@@ -50,8 +57,15 @@ use crate::properties::{BorderWidth, Padding};
 /// When adopting the child's size, that size will be expanded by the `SizedBox` border and padding.
 /// The size forced on the child is shrunk by the `SizedBox` border and padding.
 ///
+/// ## Clipping
+///
+/// Children can still paint outside of the box, e.g. text that doesn't fit.
+/// With [`clip`](Self::clip) enabled, their painting is cropped to the inside of the border,
+/// which follows the rounded corners of the [`CornerRadius`] property.
+///
 /// [`Dimensions`]: crate::properties::Dimensions
 /// [`Dimensions::MAX`]: crate::properties::Dimensions::MAX
+/// [`CornerRadius`]: crate::properties::CornerRadius
 #[doc = concat!(
     "![Box with blue border, pink background and a child label](",
     include_doc_path!("screenshots/sized_box_label_box_with_padding.png"),
@@ -61,6 +75,11 @@ pub struct SizedBox {
     child: Option<WidgetPod<dyn Widget>>,
     width: Option<Length>,
     height: Option<Length>,
+    min_width: Option<Length>,
+    min_height: Option<Length>,
+    max_width: Option<Length>,
+    max_height: Option<Length>,
+    clip: bool,
 }
 
 // --- MARK: BUILDERS
@@ -71,6 +90,11 @@ impl SizedBox {
             child: Some(child.erased().to_pod()),
             width: None,
             height: None,
+            min_width: None,
+            min_height: None,
+            max_width: None,
+            max_height: None,
+            clip: false,
         }
     }
 
@@ -83,6 +107,11 @@ impl SizedBox {
             child: None,
             width: None,
             height: None,
+            min_width: None,
+            min_height: None,
+            max_width: None,
+            max_height: None,
+            clip: false,
         }
     }
 
@@ -120,6 +149,42 @@ impl SizedBox {
         self.height = height;
         self
     }
+
+    /// Returns the container with a minimum adopted `width`.
+    pub fn min_width(mut self, width: Length) -> Self {
+        self.min_width = Some(width);
+        self.warn_if_exact(Axis::Horizontal);
+        self
+    }
+
+    /// Returns the container with a maximum adopted `width`.
+    pub fn max_width(mut self, width: Length) -> Self {
+        self.max_width = Some(width);
+        self.warn_if_exact(Axis::Horizontal);
+        self
+    }
+
+    /// Returns the container with a minimum adopted `height`.
+    pub fn min_height(mut self, height: Length) -> Self {
+        self.min_height = Some(height);
+        self.warn_if_exact(Axis::Vertical);
+        self
+    }
+
+    /// Returns the container with a maximum adopted `height`.
+    pub fn max_height(mut self, height: Length) -> Self {
+        self.max_height = Some(height);
+        self.warn_if_exact(Axis::Vertical);
+        self
+    }
+
+    /// Returns the container with its child's painting clipped to the inside of its border.
+    ///
+    /// The default is `false`, i.e. the child can paint outside of the box.
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
 }
 
 // --- MARK: METHODS
@@ -131,6 +196,29 @@ impl SizedBox {
             Axis::Vertical => self.height,
         }
     }
+
+    /// Returns the minimum adopted length of the given `axis`.
+    pub const fn min_length(&self, axis: Axis) -> Option<Length> {
+        match axis {
+            Axis::Horizontal => self.min_width,
+            Axis::Vertical => self.min_height,
+        }
+    }
+
+    /// Returns the maximum adopted length of the given `axis`.
+    pub const fn max_length(&self, axis: Axis) -> Option<Length> {
+        match axis {
+            Axis::Horizontal => self.max_width,
+            Axis::Vertical => self.max_height,
+        }
+    }
+
+    fn warn_if_exact(&self, axis: Axis) {
+        let has_limit = self.min_length(axis).is_some() || self.max_length(axis).is_some();
+        if cfg!(debug_assertions) && has_limit && self.length(axis).is_some() {
+            warn!("SizedBox has both an exact and a min/max {axis:?} length, the exact one wins");
+        }
+    }
 }
 
 // --- MARK: WIDGETMUT
@@ -200,6 +288,50 @@ impl SizedBox {
         this.ctx.request_layout();
     }
 
+    /// Sets the container's minimum adopted width.
+    ///
+    /// `None` means there is no minimum.
+    pub fn set_min_width(this: &mut WidgetMut<'_, Self>, width: Option<Length>) {
+        this.widget.min_width = width;
+        this.widget.warn_if_exact(Axis::Horizontal);
+        this.ctx.request_layout();
+    }
+
+    /// Sets the container's maximum adopted width.
+    ///
+    /// `None` means there is no maximum.
+    pub fn set_max_width(this: &mut WidgetMut<'_, Self>, width: Option<Length>) {
+        this.widget.max_width = width;
+        this.widget.warn_if_exact(Axis::Horizontal);
+        this.ctx.request_layout();
+    }
+
+    /// Sets the container's minimum adopted height.
+    ///
+    /// `None` means there is no minimum.
+    pub fn set_min_height(this: &mut WidgetMut<'_, Self>, height: Option<Length>) {
+        this.widget.min_height = height;
+        this.widget.warn_if_exact(Axis::Vertical);
+        this.ctx.request_layout();
+    }
+
+    /// Sets the container's maximum adopted height.
+    ///
+    /// `None` means there is no maximum.
+    pub fn set_max_height(this: &mut WidgetMut<'_, Self>, height: Option<Length>) {
+        this.widget.max_height = height;
+        this.widget.warn_if_exact(Axis::Vertical);
+        this.ctx.request_layout();
+    }
+
+    /// Sets whether the child's painting is clipped to the inside of the border.
+    ///
+    /// See [`clip`](Self::clip) for details.
+    pub fn set_clip(this: &mut WidgetMut<'_, Self>, clip: bool) {
+        this.widget.clip = clip;
+        this.ctx.request_layout();
+    }
+
     /// Returns mutable reference to the child widget, if any.
     pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> Option<WidgetMut<'t, dyn Widget>> {
         let child = this.widget.child.as_mut()?;
@@ -241,8 +373,16 @@ impl Widget for SizedBox {
                 .saturating_sub(padding_length);
         }
 
-        // Otherwise measure the child
-        if let Some(child) = self.child.as_mut() {
+        // Otherwise measure the child, and clamp its length to our limits
+        let to_content = |length: Length| {
+            length
+                .saturating_sub(border_length)
+                .saturating_sub(padding_length)
+        };
+        let min_length = self.min_length(axis).map(to_content);
+        let max_length = self.max_length(axis).map(to_content);
+
+        let length = if let Some(child) = self.child.as_mut() {
             let cross = axis.cross();
             let cross_length = cross_length.or_else(|| {
                 // Can't use self.length() due to borrow checker stupidity,
@@ -264,10 +404,23 @@ impl Widget for SizedBox {
             ctx.compute_length(child, auto_length, context_size, axis, cross_length)
         } else {
             Length::ZERO
+        };
+        let length = max_length.map_or(length, |max| length.min(max));
+        min_length.map_or(length, |min| length.max(min))
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, props: &PropertiesRef<'_>, size: Size) {
+        if self.clip {
+            let cache = ctx.property_cache();
+            let border = props.get::<BorderWidth>(cache);
+            let corner_radius = props.get::<CornerRadius>(cache);
+            let border_box = size.to_rect() + ctx.border_box_insets();
+            let clip = border.bg_rect(border_box, corner_radius);
+            ctx.set_rounded_clip_path(clip);
+        } else {
+            ctx.clear_clip_path();
         }
-    }
 
-    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
         let Some(child) = self.child.as_mut() else {
             // No child, so no layout work beyond resetting baselines
             ctx.clear_baselines();
@@ -316,14 +469,15 @@ impl Widget for SizedBox {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::PropertySet;
+    use crate::core::{PropertySet, StyleProperty, WidgetTag};
+    use crate::kurbo::Rect;
     use crate::layout::{AsUnit, UnitPoint};
     use crate::palette;
     use crate::properties::types::Gradient;
-    use crate::properties::{Background, BorderColor, CornerRadius};
+    use crate::properties::{Background, BorderColor, BoxShadow, BoxShadows};
     use crate::testing::{TestHarness, assert_failing_render_snapshot, assert_render_snapshot};
     use crate::theme::test_property_set;
-    use crate::widgets::Label;
+    use crate::widgets::{Flex, Label};
 
     // TODO - Add WidgetMut tests
 
@@ -379,6 +533,121 @@ mod tests {
         assert_render_snapshot!(harness, "sized_box_label_box_with_size");
     }
 
+    #[test]
+    fn oversized_child_clip() {
+        let mut box_props = PropertySet::new();
+        box_props.insert(Background::Color(palette::css::LIGHT_BLUE));
+        box_props.insert(BorderColor::new(palette::css::BLUE));
+        box_props.insert(BorderWidth::all(2.px()));
+        box_props.insert(CornerRadius::all(12.px()));
+
+        let label = Label::new("Thumbnail")
+            .with_style(StyleProperty::FontSize(40.))
+            .prepare();
+        let widget = SizedBox::new(label)
+            .size(60.px(), 40.px())
+            .prepare()
+            .with_props(box_props);
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (100, 100));
+        assert_render_snapshot!(harness, "sized_box_oversized_child_unclipped");
+
+        harness.edit_root_widget(|mut sized_box| SizedBox::set_clip(&mut sized_box, true));
+        assert_render_snapshot!(harness, "sized_box_oversized_child_clipped");
+    }
+
+    #[test]
+    fn bottom_border_only() {
+        let mut box_props = PropertySet::new();
+        box_props.insert(BorderColor::new(palette::css::BLUE));
+        box_props.insert(BorderWidth::bottom(3.px()));
+
+        let widget = SizedBox::new(Label::new("hello").prepare())
+            .width(60.px())
+            .height(30.px())
+            .prepare()
+            .with_props(box_props);
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (100, 100));
+
+        // Only the bottom edge insets the content box.
+        let sized_box = harness.root_widget();
+        let border_box = sized_box.ctx().border_box();
+        let content_box = sized_box.ctx().content_box();
+        assert_eq!(content_box.origin(), border_box.origin());
+        assert_eq!(content_box.width(), border_box.width());
+        assert_eq!(border_box.height() - content_box.height(), 3.);
+
+        assert_render_snapshot!(harness, "sized_box_bottom_border_only");
+    }
+
+    #[test]
+    fn box_shadow_card() {
+        let gray = palette::css::GRAY.with_alpha(0.5);
+        let mut card_props = PropertySet::new();
+        card_props.insert(Background::Color(palette::css::WHITE));
+        card_props.insert(CornerRadius::all(8.px()));
+        card_props.insert(BoxShadows::new([
+            BoxShadow::new(gray, (1., 1.)).blur(1.px()),
+            BoxShadow::new(gray, (6., 6.)).blur(8.px()).spread(2.),
+        ]));
+
+        let card_tag = WidgetTag::named("card");
+        let widget = Flex::row()
+            .with_fixed(
+                SizedBox::empty()
+                    .size(60.px(), 40.px())
+                    .prepare()
+                    .with_tag(card_tag)
+                    .with_props(card_props),
+            )
+            .prepare()
+            .with_props(Padding::all(20.px()));
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (120, 100));
+
+        // The paint box covers the softest shadow, which extends further down-right.
+        let paint_box = harness.get_widget(card_tag).ctx().paint_box();
+        assert_eq!(paint_box, Rect::new(-4., -4., 76., 56.));
+
+        assert_render_snapshot!(harness, "sized_box_box_shadow_card");
+    }
+
+    #[test]
+    fn min_max_clamp_child() {
+        let large = WidgetTag::unique();
+        let small = WidgetTag::unique();
+        let clamped = |child_size: Length, tag| {
+            SizedBox::new(SizedBox::empty().size(child_size, child_size).prepare())
+                .min_width(20.px())
+                .max_width(50.px())
+                .max_height(50.px())
+                .prepare()
+                .with_tag(tag)
+        };
+        let widget = Flex::row()
+            .with_fixed(clamped(80.px(), large))
+            .with_fixed(clamped(30.px(), small))
+            .prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (200, 100));
+
+        // A child larger than the maximum is clamped.
+        let size = |harness: &TestHarness<Flex>, tag: WidgetTag<SizedBox>| {
+            harness.get_widget(tag).ctx().border_box().size()
+        };
+        assert_eq!(size(&harness, large), Size::new(50., 50.));
+        // A smaller child is left alone.
+        assert_eq!(size(&harness, small), Size::new(30., 30.));
+
+        // The minimum wins over the maximum.
+        harness.edit_widget(small, |mut sized_box| {
+            SizedBox::set_min_width(&mut sized_box, Some(40.px()));
+            SizedBox::set_max_width(&mut sized_box, Some(35.px()));
+        });
+        assert_eq!(size(&harness, small).width, 40.);
+    }
+
     #[test]
     fn label_box_with_padding() {
         let mut box_props = PropertySet::new();
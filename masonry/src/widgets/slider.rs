@@ -13,7 +13,7 @@ use crate::core::{
     AccessCtx, AccessEvent, ChildrenIds, EventCtx, LayoutCtx, MeasureCtx, PaintCtx,
     PointerButtonEvent, PointerEvent, PointerUpdate, PrePaintProps, PropertiesMut, PropertiesRef,
     Property, RegisterCtx, TextEvent, Update, UpdateCtx, UsesProperty, Widget, WidgetId, WidgetMut,
-    paint_background, paint_box_shadow,
+    paint_background, paint_box_shadow, paint_inset_shadow, set_numeric_range,
 };
 use crate::imaging::{Composite, GroupRef, Painter};
 use crate::kurbo::{Axis, Circle, Rect, Size, Stroke};
@@ -344,8 +344,9 @@ impl Widget for Slider {
         let cache = ctx.property_cache();
         let p = PrePaintProps::fetch(props, cache);
 
-        paint_box_shadow(painter, bbox, p.box_shadow, p.corner_radius);
+        paint_box_shadow(painter, bbox, p.box_shadow, p.box_shadows, p.corner_radius);
         paint_background(painter, bbox, p.background, p.border_width, p.corner_radius);
+        paint_inset_shadow(painter, bbox, p.box_shadow, p.box_shadows, p.corner_radius);
 
         if ctx.is_focus_target() || ctx.is_hovered() {
             // TODO: Replace this custom implementation with the general paint_border()
@@ -451,9 +452,7 @@ impl Widget for Slider {
     ) {
         node.set_orientation(Orientation::Horizontal);
         node.set_value(self.value.to_string());
-        node.set_numeric_value(self.value);
-        node.set_min_numeric_value(self.min);
-        node.set_max_numeric_value(self.max);
+        set_numeric_range(node, Some(self.value), self.min, self.max);
         if let Some(step) = self.step {
             node.set_numeric_value_step(step);
         }
@@ -537,6 +536,35 @@ mod tests {
         assert_render_snapshot!(harness, "slider_keyboard_moved");
     }
 
+    #[test]
+    fn slider_range_clamping() {
+        let widget = Slider::new(0.0, 100.0, 150.0).prepare();
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (200, 32));
+        let slider_id = harness.root_id();
+
+        let node = harness.access_node(slider_id).unwrap();
+        assert_eq!(node.role(), Role::Slider);
+        assert_eq!(node.data().numeric_value(), Some(100.0));
+        assert_eq!(node.data().min_numeric_value(), Some(0.0));
+        assert_eq!(node.data().max_numeric_value(), Some(100.0));
+
+        harness.edit_root_widget(|mut slider| Slider::set_value(&mut slider, -20.0));
+        let _ = harness.render();
+        let node = harness.access_node(slider_id).unwrap();
+        assert_eq!(node.data().numeric_value(), Some(0.0));
+
+        // Narrowing the range pulls the value back into it.
+        harness.edit_root_widget(|mut slider| Slider::set_range(&mut slider, 20.0, 50.0));
+        let _ = harness.render();
+        let node = harness.access_node(slider_id).unwrap();
+        assert_eq!(node.data().numeric_value(), Some(20.0));
+        assert_eq!(node.data().min_numeric_value(), Some(20.0));
+        assert_eq!(node.data().max_numeric_value(), Some(50.0));
+
+        // Programmatic changes don't emit actions.
+        assert!(harness.pop_action::<SliderMoved>().is_none());
+    }
+
     #[test]
     fn slider_disabled_state() {
         let mut widget = Slider::new(0.0, 100.0, 50.0).prepare();
@@ -159,10 +159,12 @@ impl Widget for Spinner {
 // --- MARK: TESTS
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use super::*;
     use crate::core::{NewWidget, PropertySet};
     use crate::palette;
-    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::testing::{TestHarness, assert_render_snapshot, assert_render_snapshot_sequence};
     use crate::theme::test_property_set;
 
     #[test]
@@ -179,6 +181,22 @@ mod tests {
         assert_render_snapshot!(harness, "spinner_1100ms");
     }
 
+    #[test]
+    fn spinner_animation_sequence() {
+        let spinner = NewWidget::new(Spinner::new());
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), spinner, (100, 100));
+        assert_render_snapshot_sequence!(
+            harness,
+            "spinner_sequence",
+            3,
+            Duration::from_millis(100)
+        );
+
+        let frames = harness.render_sequence(3, Duration::from_millis(100));
+        assert_eq!(frames.len(), 3);
+    }
+
     #[test]
     fn edit_spinner() {
         let image_1 = {
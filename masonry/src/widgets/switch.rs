@@ -233,7 +233,7 @@ impl Widget for Switch {
         let track_width = track_width.get();
         let track_height = track_height.get();
         let thumb_radius = thumb_radius_val.get();
-        let border_width = props.get::<BorderWidth>(cache).width.get();
+        let border_width = props.get::<BorderWidth>(cache).max_width().get();
         let corner_radius = props.get::<CornerRadius>(cache).radius.get();
         let thumb_color = props.get::<ThumbColor>(cache).0;
 
@@ -0,0 +1,922 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace, trace_span};
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::{
+    AccessCtx, AccessEvent, ActionCtx, ArcStr, ChildrenIds, ErasedAction, EventCtx, LayoutCtx,
+    MeasureCtx, MutateCtx, NewWidget, NoAction, PaintCtx, PointerEvent, PropertiesMut,
+    PropertiesRef, RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut,
+    WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Point, Rect, Size};
+use crate::layout::{LayoutSize, LenReq, Length, SizeDef};
+use crate::theme;
+use crate::widgets::Label;
+
+/// Horizontal padding on each side of a tab's title.
+const HEADER_PADDING: Length = Length::const_px(8.);
+/// Thickness of the line marking the selected tab.
+const INDICATOR_HEIGHT: f64 = 2.;
+
+/// A tabbed interface, with a row of tab headers above the selected tab's content.
+///
+/// The user switches tabs by clicking a header, or with the arrow keys when a header is focused.
+/// The selected header is underlined.
+///
+/// Only the selected tab's content is laid out and painted.
+/// The content of the other tabs is [stashed](crate::doc::masonry_concepts#stashed),
+/// which preserves its state until the tab is selected again.
+///
+/// Emits [`TabChanged`] when the user selects a different tab.
+pub struct Tabs {
+    list: WidgetPod<TabList>,
+    // The ids of the headers in `list`.
+    headers: Vec<WidgetId>,
+    panels: Vec<WidgetPod<TabPanel>>,
+    // Note: selected must be 0 if there are no tabs
+    selected: usize,
+}
+
+/// The action type emitted by [`Tabs`] when the user selects a different tab.
+///
+/// This is not emitted when the selection is changed with [`Tabs::set_selected`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TabChanged(pub usize);
+
+// --- MARK: BUILDERS
+impl Tabs {
+    /// Creates a new `Tabs` with no tabs.
+    pub fn new() -> Self {
+        Self {
+            list: WidgetPod::new(TabList {
+                headers: Vec::new(),
+            }),
+            headers: Vec::new(),
+            panels: Vec::new(),
+            selected: 0,
+        }
+    }
+
+    /// Builder-style method to add a tab with the given title and content.
+    pub fn with_tab(
+        mut self,
+        title: impl Into<ArcStr>,
+        content: NewWidget<impl Widget + ?Sized>,
+    ) -> Self {
+        let panel = self.push_panel(content);
+        let selected = self.headers.len() == self.selected;
+        let header = TabHeader::new_pod(title.into(), panel, selected);
+        self.headers.push(header.id());
+        self.list
+            .pending_widget_mut()
+            .expect("list is only added to the tree once Tabs is")
+            .headers
+            .push(header);
+        self
+    }
+
+    /// Builder-style method to set the selected tab.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    /// If there are no tabs, the index 0 is accepted.
+    pub fn with_selected(mut self, idx: usize) -> Self {
+        self.check_index(idx, "with_selected");
+        self.selected = idx;
+        self
+    }
+}
+
+impl Default for Tabs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// --- MARK: METHODS
+impl Tabs {
+    /// Returns the index of the selected tab.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Returns the number of tabs.
+    pub fn tab_count(&self) -> usize {
+        self.headers.len()
+    }
+
+    /// Adds the panel of a new tab, and returns its id.
+    fn push_panel(&mut self, content: NewWidget<impl Widget + ?Sized>) -> WidgetId {
+        let panel = WidgetPod::new(TabPanel {
+            child: content.erased().to_pod(),
+        });
+        let id = panel.id();
+        self.panels.push(panel);
+        id
+    }
+
+    #[track_caller]
+    fn check_index(&self, idx: usize, method: &str) {
+        if self.headers.is_empty() {
+            assert!(
+                idx == 0,
+                "Called {method} on empty Tabs with non-zero index {idx}"
+            );
+        } else {
+            assert!(
+                idx < self.headers.len(),
+                "Called {method} with invalid index {idx}"
+            );
+        }
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Tabs {
+    /// Sets the selected tab.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    /// If there are no tabs, the index 0 is accepted.
+    pub fn set_selected(this: &mut WidgetMut<'_, Self>, idx: usize) {
+        this.widget.check_index(idx, "set_selected");
+        this.widget.selected = idx;
+        this.widget.sync_headers(&mut this.ctx);
+        this.ctx.request_layout();
+    }
+
+    /// Adds a tab with the given title and content at the end.
+    pub fn add_tab(
+        this: &mut WidgetMut<'_, Self>,
+        title: impl Into<ArcStr>,
+        content: NewWidget<impl Widget + ?Sized>,
+    ) {
+        let panel = this.widget.push_panel(content);
+        let selected = this.widget.headers.len() == this.widget.selected;
+        let header = TabHeader::new_pod(title.into(), panel, selected);
+        this.widget.headers.push(header.id());
+        TabList::add_header(&mut this.ctx.get_mut(&mut this.widget.list), header);
+        this.ctx.children_changed();
+    }
+
+    /// Removes the tab at the given index.
+    ///
+    /// If the selected tab is removed, the first tab is selected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn remove_tab(this: &mut WidgetMut<'_, Self>, idx: usize) {
+        this.widget.headers.remove(idx);
+        let panel = this.widget.panels.remove(idx);
+        TabList::remove_header(&mut this.ctx.get_mut(&mut this.widget.list), idx);
+        this.ctx.remove_child(panel);
+        if idx == this.widget.selected {
+            // This is valid even if we are removing the last tab,
+            // since `selected` must be 0 in that case
+            this.widget.selected = 0;
+        } else if this.widget.selected > idx {
+            this.widget.selected -= 1;
+        }
+        this.widget.sync_headers(&mut this.ctx);
+        this.ctx.request_layout();
+    }
+
+    /// Sets the title of the tab at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn set_title(this: &mut WidgetMut<'_, Self>, idx: usize, title: impl Into<ArcStr>) {
+        TabList::set_title(
+            &mut this.ctx.get_mut(&mut this.widget.list),
+            idx,
+            title.into(),
+        );
+    }
+
+    /// Returns a mutable reference to the panel of the tab at the given index.
+    ///
+    /// Use [`TabPanel::child_mut`] to access the tab's content.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn panel_mut<'t>(this: &'t mut WidgetMut<'_, Self>, idx: usize) -> WidgetMut<'t, TabPanel> {
+        this.ctx.get_mut(&mut this.widget.panels[idx])
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Tabs {
+    type Action = TabChanged;
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        let TextEvent::Keyboard(event) = event else {
+            return;
+        };
+        if !event.state.is_down() {
+            return;
+        }
+        let forward = match &event.key {
+            Key::Named(NamedKey::ArrowRight) => true,
+            Key::Named(NamedKey::ArrowLeft) => false,
+            _ => return,
+        };
+        let target = ctx.target();
+        let Some(idx) = self.headers.iter().position(|id| *id == target) else {
+            return;
+        };
+
+        let len = self.headers.len();
+        let next_idx = if forward {
+            (idx + 1) % len
+        } else {
+            (idx + len - 1) % len
+        };
+        ctx.set_focus(self.headers[next_idx]);
+        self.select(ctx, next_idx);
+        ctx.set_handled();
+    }
+
+    fn on_action(
+        &mut self,
+        ctx: &mut ActionCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        action: &ErasedAction,
+        source: WidgetId,
+    ) {
+        if action.is::<TabHeaderPressed>()
+            && let Some(idx) = self.headers.iter().position(|id| *id == source)
+        {
+            self.select(ctx, idx);
+            ctx.set_handled();
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::WidgetAdded => {
+                self.sync_headers(ctx);
+            }
+            _ => (),
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.list);
+        for panel in &mut self.panels {
+            ctx.register_child(panel);
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        // The headers are laid out in a row, above the panel.
+        let [headers_width, headers_height] = [Axis::Horizontal, Axis::Vertical].map(|list_axis| {
+            let auto_length = LenReq::MaxContent.into();
+            let context_size = LayoutSize::maybe(list_axis.cross(), None);
+            ctx.compute_length(&mut self.list, auto_length, context_size, list_axis, None)
+        });
+
+        let Some(panel) = self.panels.get_mut(self.selected) else {
+            return match axis {
+                Axis::Horizontal => headers_width,
+                Axis::Vertical => headers_height,
+            };
+        };
+        let (auto_length, cross_space) = match axis {
+            Axis::Horizontal => (
+                len_req.into(),
+                cross_length.map(|l| l.saturating_sub(headers_height)),
+            ),
+            Axis::Vertical => (len_req.reduce(headers_height).into(), cross_length),
+        };
+        let context_size = LayoutSize::maybe(axis.cross(), cross_space);
+        let panel_length = ctx.compute_length(panel, auto_length, context_size, axis, cross_space);
+
+        match axis {
+            Axis::Horizontal => headers_width.max(panel_length),
+            Axis::Vertical => headers_height.saturating_add(panel_length),
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let list_size = ctx.compute_size(&mut self.list, SizeDef::fit(size), size.into());
+        ctx.run_layout(&mut self.list, list_size);
+        ctx.place_child(&mut self.list, Point::ORIGIN);
+        let header_height = list_size.height;
+
+        // TODO: move set_stashed to a different layout pass when possible
+        for (idx, panel) in self.panels.iter_mut().enumerate() {
+            ctx.set_stashed(panel, idx != self.selected);
+        }
+        let Some(panel) = self.panels.get_mut(self.selected) else {
+            return;
+        };
+        let space = Size::new(size.width, (size.height - header_height).max(0.));
+        let panel_size = ctx.compute_size(panel, SizeDef::fit(space), space.into());
+        ctx.run_layout(panel, panel_size);
+        ctx.place_child(panel, Point::new(0., header_height));
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        std::iter::once(self.list.id())
+            .chain(self.panels.iter().map(WidgetPod::id))
+            .collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Tabs", id = id.trace())
+    }
+}
+
+// --- MARK: HELPERS
+impl Tabs {
+    /// Selects the tab at `idx` on behalf of the user.
+    fn select(&mut self, ctx: &mut impl SyncCtx, idx: usize) {
+        if idx == self.selected {
+            return;
+        }
+        self.selected = idx;
+        self.sync_headers(ctx);
+        ctx.request_layout();
+        ctx.submit_tab_changed(TabChanged(idx));
+    }
+
+    /// Marks the header of the selected tab, and unmarks the others.
+    fn sync_headers(&mut self, ctx: &mut impl SyncCtx) {
+        let selected = self.selected;
+        ctx.mutate_child_later(&mut self.list, move |mut list| {
+            TabList::set_selected(&mut list, selected);
+        });
+    }
+}
+
+// --- MARK: SYNCCTX
+
+/// Collections of context methods required to sync the tab headers.
+trait SyncCtx {
+    fn mutate_child_later(
+        &mut self,
+        child: &mut WidgetPod<TabList>,
+        f: impl FnOnce(WidgetMut<'_, TabList>) + Send + 'static,
+    );
+    fn request_layout(&mut self);
+    fn submit_tab_changed(&mut self, action: TabChanged);
+}
+
+macro_rules! impl_sync_ctx {
+    ($($ctx:ty),*) => {$(
+        impl SyncCtx for $ctx {
+            fn mutate_child_later(
+                &mut self,
+                child: &mut WidgetPod<TabList>,
+                f: impl FnOnce(WidgetMut<'_, TabList>) + Send + 'static,
+            ) {
+                self.mutate_child_later(child, f);
+            }
+
+            fn request_layout(&mut self) {
+                self.request_layout();
+            }
+
+            fn submit_tab_changed(&mut self, action: TabChanged) {
+                self.submit_action::<TabChanged>(action);
+            }
+        }
+    )*};
+}
+
+impl_sync_ctx!(MutateCtx<'_>, ActionCtx<'_>, EventCtx<'_>, UpdateCtx<'_>);
+
+// --- MARK: TAB LIST
+
+/// The row of tab headers, exposed to screen readers as a tab list.
+struct TabList {
+    headers: Vec<WidgetPod<TabHeader>>,
+}
+
+impl TabList {
+    fn add_header(this: &mut WidgetMut<'_, Self>, header: WidgetPod<TabHeader>) {
+        this.widget.headers.push(header);
+        this.ctx.children_changed();
+    }
+
+    fn remove_header(this: &mut WidgetMut<'_, Self>, idx: usize) {
+        let header = this.widget.headers.remove(idx);
+        this.ctx.remove_child(header);
+    }
+
+    fn set_title(this: &mut WidgetMut<'_, Self>, idx: usize, title: ArcStr) {
+        let mut header = this.ctx.get_mut(&mut this.widget.headers[idx]);
+        Label::set_text(&mut header.ctx.get_mut(&mut header.widget.label), title);
+    }
+
+    fn set_selected(this: &mut WidgetMut<'_, Self>, selected: usize) {
+        for (idx, header) in this.widget.headers.iter_mut().enumerate() {
+            TabHeader::set_selected(&mut this.ctx.get_mut(header), idx == selected);
+        }
+    }
+}
+
+impl Widget for TabList {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for header in &mut self.headers {
+            ctx.register_child(header);
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        _cross_length: Option<Length>,
+    ) -> Length {
+        let mut length = Length::ZERO;
+        for header in &mut self.headers {
+            let auto_length = LenReq::MaxContent.into();
+            let context_size = LayoutSize::maybe(axis.cross(), None);
+            let header_length = ctx.compute_length(header, auto_length, context_size, axis, None);
+            length = match axis {
+                Axis::Horizontal => length.saturating_add(header_length),
+                Axis::Vertical => length.max(header_length),
+            };
+        }
+        length
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        // The headers share a common height, so that their indicators line up.
+        let mut x = 0.;
+        for header in &mut self.headers {
+            let space = Size::new((size.width - x).max(0.), size.height);
+            let header_size = ctx.compute_size(header, SizeDef::fit(space), size.into());
+            ctx.run_layout(header, Size::new(header_size.width, size.height));
+            ctx.place_child(header, Point::new(x, 0.));
+            x += header_size.width;
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::TabList
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.headers.iter().map(WidgetPod::id).collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("TabList", id = id.trace())
+    }
+}
+
+// --- MARK: TAB HEADER
+
+/// The action emitted by a [`TabHeader`] when pressed, handled by its [`Tabs`].
+#[derive(Debug)]
+struct TabHeaderPressed;
+
+/// The clickable title of a tab.
+struct TabHeader {
+    label: WidgetPod<Label>,
+    /// The panel of this tab.
+    panel: WidgetId,
+    selected: bool,
+}
+
+impl TabHeader {
+    // The selection can still change before the header is added to the tree,
+    // so the headers are synced again in `Update::WidgetAdded`.
+    fn new_pod(title: ArcStr, panel: WidgetId, selected: bool) -> WidgetPod<Self> {
+        WidgetPod::new(Self {
+            label: WidgetPod::new(Label::new(title)),
+            panel,
+            selected,
+        })
+    }
+
+    fn set_selected(this: &mut WidgetMut<'_, Self>, selected: bool) {
+        if this.widget.selected != selected {
+            this.widget.selected = selected;
+            // Selected state impacts appearance and accessibility node
+            this.ctx.request_render();
+        }
+    }
+}
+
+impl Widget for TabHeader {
+    type Action = TabHeaderPressed;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::Down { .. } => {
+                ctx.capture_pointer();
+            }
+            PointerEvent::Up { .. } if ctx.is_active() && ctx.is_hovered() => {
+                trace!("TabHeader {:?} pressed", ctx.widget_id());
+                ctx.submit_action::<Self::Action>(TabHeaderPressed);
+            }
+            _ => (),
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        match event {
+            TextEvent::Keyboard(event)
+                if event.state.is_up()
+                    && (matches!(&event.key, Key::Character(c) if c == " ")
+                        || event.key == Key::Named(NamedKey::Enter)) =>
+            {
+                ctx.submit_action::<Self::Action>(TabHeaderPressed);
+            }
+            _ => (),
+        }
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        if event.action == accesskit::Action::Click {
+            ctx.submit_action::<Self::Action>(TabHeaderPressed);
+        }
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.label);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::HoveredChanged(_) | Update::FocusChanged(_) => {
+                ctx.request_paint_only();
+            }
+            _ => {}
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        _cross_length: Option<Length>,
+    ) -> Length {
+        let context_size = LayoutSize::maybe(axis.cross(), None);
+        let auto_length = LenReq::MaxContent.into();
+        let label_length =
+            ctx.compute_length(&mut self.label, auto_length, context_size, axis, None);
+        match axis {
+            Axis::Horizontal => {
+                label_length.saturating_add(HEADER_PADDING.saturating_add(HEADER_PADDING))
+            }
+            Axis::Vertical => label_length.saturating_add(Length::const_px(2. * INDICATOR_HEIGHT)),
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let label_size = ctx.compute_size(&mut self.label, SizeDef::MAX, size.into());
+        ctx.run_layout(&mut self.label, label_size);
+        let origin = Point::new(
+            (size.width - label_size.width) / 2.,
+            (size.height - INDICATOR_HEIGHT - label_size.height) / 2.,
+        );
+        ctx.place_child(&mut self.label, origin);
+        ctx.derive_baselines(&self.label);
+    }
+
+    fn paint(
+        &mut self,
+        ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        painter: &mut Painter<'_>,
+    ) {
+        let size = ctx.content_box().size();
+        let indicator = Rect::new(0., size.height - INDICATOR_HEIGHT, size.width, size.height);
+        if self.selected {
+            painter.fill(indicator, theme::ACCENT_COLOR).draw();
+        } else if ctx.is_hovered() || ctx.is_focus_target() {
+            painter.fill(indicator, theme::ZYNC_600).draw();
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Tab
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.add_action(accesskit::Action::Click);
+        node.set_selected(self.selected);
+        node.push_controlled(self.panel.into());
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.label.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("TabHeader", id = id.trace())
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        self.selected.then(|| "selected".to_string())
+    }
+}
+
+// --- MARK: TAB PANEL
+
+/// The container of a [`Tabs`]' tab content.
+///
+/// This is created by `Tabs` for each of its tabs, and is exposed to screen readers as a tab panel.
+pub struct TabPanel {
+    child: WidgetPod<dyn Widget>,
+}
+
+impl TabPanel {
+    /// Returns a mutable reference to the tab's content.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+}
+
+impl Widget for TabPanel {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        ctx.redirect_measurement(&mut self.child, axis, cross_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.run_layout(&mut self.child, size);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        ctx.derive_baselines(&self.child);
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::TabPanel
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.child.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("TabPanel", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Modifiers, WidgetTag};
+    use crate::testing::{Record, Recorder, TestHarness, TestWidgetExt};
+    use crate::theme::test_property_set;
+    use crate::widgets::SizedBox;
+    use accesskit::NodeId;
+
+    fn three_tabs() -> (Tabs, [WidgetTag<Recorder<SizedBox>>; 3]) {
+        let tags = [
+            WidgetTag::unique(),
+            WidgetTag::unique(),
+            WidgetTag::unique(),
+        ];
+        let tabs = ["One", "Two", "Three"].into_iter().zip(tags).fold(
+            Tabs::new(),
+            |tabs, (title, tag)| {
+                let content = NewWidget::new(SizedBox::empty().record()).with_tag(tag);
+                tabs.with_tab(title, content)
+            },
+        );
+        (tabs, tags)
+    }
+
+    fn header_id(harness: &TestHarness<Tabs>, idx: usize) -> WidgetId {
+        harness.root_widget().inner().headers[idx]
+    }
+
+    #[test]
+    fn switch_on_click() {
+        let (tabs, tags) = three_tabs();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), tabs.prepare(), (300, 100));
+        let tabs_id = harness.root_id();
+        assert_eq!(harness.root_widget().selected(), 0);
+
+        harness.mouse_click_on(header_id(&harness, 2), None);
+        assert_eq!(harness.root_widget().selected(), 2);
+        assert_eq!(
+            harness.pop_action::<TabChanged>(),
+            Some((TabChanged(2), tabs_id))
+        );
+        assert!(harness.get_widget(tags[0]).ctx().is_stashed());
+        assert!(!harness.get_widget(tags[2]).ctx().is_stashed());
+
+        let _ = harness.render();
+        let header = harness.access_node(header_id(&harness, 2)).unwrap();
+        assert!(header.data().is_selected().unwrap_or(false));
+
+        // Clicking the selected tab doesn't emit an action.
+        harness.mouse_click_on(header_id(&harness, 2), None);
+        assert_eq!(harness.pop_action::<TabChanged>(), None);
+    }
+
+    #[test]
+    fn switch_with_arrow_keys() {
+        let (tabs, _) = three_tabs();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), tabs.prepare(), (300, 100));
+
+        harness.focus_on(Some(header_id(&harness, 0)));
+        harness.keyboard_key(Key::Named(NamedKey::ArrowRight), Modifiers::empty());
+        assert_eq!(harness.root_widget().selected(), 1);
+        assert_eq!(harness.focused_widget_id(), Some(header_id(&harness, 1)));
+
+        // Moving past the first tab wraps around to the last one.
+        harness.keyboard_key(Key::Named(NamedKey::ArrowLeft), Modifiers::empty());
+        harness.keyboard_key(Key::Named(NamedKey::ArrowLeft), Modifiers::empty());
+        assert_eq!(harness.root_widget().selected(), 2);
+        assert_eq!(harness.focused_widget_id(), Some(header_id(&harness, 2)));
+    }
+
+    #[test]
+    fn accessibility_tree() {
+        let (tabs, tags) = three_tabs();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), tabs.prepare(), (300, 100));
+
+        // The headers are grouped in a tab list, which is a sibling of the panels.
+        // Only the panel of the selected tab is in the tree, as the others are stashed.
+        let header = harness.access_node(header_id(&harness, 0)).unwrap();
+        let list = header.parent().unwrap();
+        assert_eq!(list.role(), Role::TabList);
+        let list_children: Vec<_> = list.children().map(|node| node.locate().0).collect();
+        let header_ids: Vec<_> = (0..3)
+            .map(|idx| NodeId::from(header_id(&harness, idx)))
+            .collect();
+        assert_eq!(list_children, header_ids);
+
+        let panel = harness
+            .access_node(harness.get_widget(tags[0]).id())
+            .unwrap()
+            .parent()
+            .unwrap();
+        assert_eq!(panel.role(), Role::TabPanel);
+        assert_eq!(
+            list.parent().unwrap().locate().0,
+            NodeId::from(harness.root_id())
+        );
+        assert_eq!(
+            panel.parent().unwrap().locate().0,
+            NodeId::from(harness.root_id())
+        );
+
+        // Each header controls the panel of its tab.
+        assert_eq!(header.data().controls(), &[panel.locate().0]);
+
+        // A tab added later controls its own panel too.
+        let tag = WidgetTag::unique();
+        harness.edit_root_widget(|mut tabs| {
+            let content = NewWidget::new(SizedBox::empty().record()).with_tag(tag);
+            Tabs::add_tab(&mut tabs, "Four", content);
+        });
+        harness.edit_root_widget(|mut tabs| Tabs::set_selected(&mut tabs, 3));
+        let _ = harness.render();
+        let panel_id = harness
+            .access_node(harness.get_widget(tag).id())
+            .unwrap()
+            .parent()
+            .unwrap()
+            .locate()
+            .0;
+        let header = harness.access_node(header_id(&harness, 3)).unwrap();
+        assert_eq!(header.parent().unwrap().role(), Role::TabList);
+        assert_eq!(header.data().controls(), &[panel_id]);
+    }
+
+    #[test]
+    fn only_selected_tab_paints() {
+        let (tabs, tags) = three_tabs();
+        let tabs = tabs.with_selected(1).prepare();
+        let mut harness = TestHarness::create_with_size(test_property_set(), tabs, (300, 100));
+
+        let _ = harness.render();
+        let painted = |records: Vec<Record>| records.iter().any(|r| matches!(r, Record::Paint));
+        assert!(!painted(harness.take_records_of(tags[0])));
+        assert!(painted(harness.take_records_of(tags[1])));
+        assert!(!painted(harness.take_records_of(tags[2])));
+
+        harness.edit_root_widget(|mut tabs| Tabs::set_selected(&mut tabs, 2));
+        let _ = harness.render();
+        assert!(!painted(harness.take_records_of(tags[0])));
+        assert!(!painted(harness.take_records_of(tags[1])));
+        assert!(painted(harness.take_records_of(tags[2])));
+    }
+}
@@ -242,6 +242,11 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         self.editor.text()
     }
 
+    /// Returns the currently selected text, or `None` if the selection is collapsed.
+    pub fn selected_text(&self) -> Option<&str> {
+        self.editor.selected_text()
+    }
+
     /// Check if this text area holds nothing, including IME preedit content.
     pub fn is_empty(&self) -> bool {
         self.editor.raw_text().is_empty()
@@ -859,7 +864,12 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                 let _ = self.editor.edit_styles();
                 ctx.request_layout();
             }
-            Update::FocusChanged(_) => {
+            Update::FocusChanged(focused) => {
+                // Read-only text only shows a selection while it has focus, like in a web page.
+                if !EDITABLE && !focused {
+                    let (fctx, lctx) = ctx.text_contexts();
+                    self.editor.driver(fctx, lctx).collapse_selection();
+                }
                 ctx.request_render();
             }
             Update::DisabledChanged(_) => {
@@ -1116,7 +1126,7 @@ mod tests {
     use masonry_testing::TestHarnessParams;
 
     use super::*;
-    use crate::core::{KeyboardEvent, Modifiers, NewWidget, PropertySet};
+    use crate::core::{KeyboardEvent, Modifiers, NewWidget, PointerButton, PropertySet};
     use crate::palette;
     use crate::testing::TestHarness;
     use crate::theme::test_property_set;
@@ -1291,4 +1301,56 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn shift_click_extends_selection() {
+        let area = NewWidget::new(TextArea::new_editable("hello world"));
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), area, (200, 40));
+
+        // Place the cursor at the end of the text.
+        harness.mouse_move((190., 20.));
+        harness.mouse_button_press(Some(PointerButton::Primary));
+        harness.mouse_button_release(Some(PointerButton::Primary));
+
+        // Shift-click at the start of the text to select everything in between.
+        harness.set_modifiers(Modifiers::SHIFT);
+        harness.mouse_move((1., 20.));
+        harness.mouse_button_press(Some(PointerButton::Primary));
+        harness.mouse_button_release(Some(PointerButton::Primary));
+        harness.set_modifiers(Modifiers::empty());
+
+        harness.keyboard_type_chars("X");
+        assert_eq!(harness.root_widget().text().to_string(), "X");
+    }
+
+    #[test]
+    fn type_into_focused() {
+        let area = NewWidget::new(TextArea::new_editable(""));
+        let mut harness = TestHarness::create_with_size(test_property_set(), area, (200, 40));
+
+        let id = harness.root_id();
+        harness.focus_on(Some(id));
+        harness.text_input("abc");
+        harness.keyboard_key(Key::Named(NamedKey::Backspace), Modifiers::empty());
+        assert_eq!(harness.root_widget().text().to_string(), "ab");
+    }
+
+    #[test]
+    #[should_panic(expected = "no widget is focused")]
+    fn type_without_focus() {
+        let area = NewWidget::new(TextArea::new_editable(""));
+        let mut harness = TestHarness::create_with_size(test_property_set(), area, (200, 40));
+
+        harness.text_input("abc");
+    }
+
+    #[test]
+    #[should_panic(expected = "no widget is focused")]
+    fn key_without_focus() {
+        let area = NewWidget::new(TextArea::new_editable(""));
+        let mut harness = TestHarness::create_with_size(test_property_set(), area, (200, 40));
+
+        harness.keyboard_key(Key::Named(NamedKey::Backspace), Modifiers::empty());
+    }
 }
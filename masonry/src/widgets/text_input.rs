@@ -11,7 +11,7 @@ use crate::core::{
     AccessCtx, ArcStr, ChildrenIds, EventCtx, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
     PointerButton, PointerButtonEvent, PointerEvent, PrePaintProps, PropertiesMut, PropertiesRef,
     RegisterCtx, TextEvent, Update, UpdateCtx, UsesProperty, Widget, WidgetId, WidgetMut,
-    WidgetPod, paint_background, paint_border, paint_box_shadow,
+    WidgetPod, paint_background, paint_border, paint_box_shadow, paint_inset_shadow,
 };
 use crate::imaging::Painter;
 use crate::kurbo::{Axis, Point, Size};
@@ -321,8 +321,9 @@ impl Widget for TextInput {
         let cache = ctx.property_cache();
         let p = PrePaintProps::fetch(props, cache);
 
-        paint_box_shadow(painter, bbox, p.box_shadow, p.corner_radius);
+        paint_box_shadow(painter, bbox, p.box_shadow, p.box_shadows, p.corner_radius);
         paint_background(painter, bbox, p.background, p.border_width, p.corner_radius);
+        paint_inset_shadow(painter, bbox, p.box_shadow, p.box_shadows, p.corner_radius);
         paint_border(
             painter,
             bbox,
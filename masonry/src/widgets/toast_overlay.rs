@@ -0,0 +1,364 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use accesskit::{Live, Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::{
+    AccessCtx, ArcStr, ChildrenIds, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
+    PropertiesMut, PropertiesRef, RegisterCtx, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Axis, Point, Rect, RoundedRect, Size};
+use crate::layout::{LayoutSize, LenReq, Length, SizeDef};
+use crate::peniko::Color;
+use crate::widgets::Label;
+use crate::{palette, theme};
+
+/// Maximum width of a single toast, including its padding.
+const TOAST_MAX_WIDTH: f64 = 300.;
+/// Padding between a toast's edge and its text.
+const TOAST_PADDING: f64 = 8.;
+/// Distance between toasts, and between toasts and the overlay's edge.
+const TOAST_MARGIN: f64 = 10.;
+/// Corner radius of a toast's background.
+const TOAST_RADIUS: f64 = 4.;
+/// Duration of the slide-in and slide-out animations, in nanoseconds.
+const TOAST_ANIM_NS: u64 = 200_000_000;
+
+/// How important a [`NotificationToast`] is, which determines its background color.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToastSeverity {
+    /// A neutral informational message.
+    #[default]
+    Info,
+    /// An operation completed successfully.
+    Success,
+    /// Something may need the user's attention.
+    Warning,
+    /// An operation failed.
+    Error,
+}
+
+impl ToastSeverity {
+    fn color(self) -> Color {
+        match self {
+            Self::Info => theme::ACCENT_COLOR,
+            Self::Success => palette::css::SEA_GREEN,
+            Self::Warning => palette::css::DARK_GOLDENROD,
+            Self::Error => palette::css::FIREBRICK,
+        }
+    }
+}
+
+/// A transient message shown by a [`ToastOverlay`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct NotificationToast {
+    /// The text of the toast.
+    pub text: ArcStr,
+    /// How important the toast is.
+    pub severity: ToastSeverity,
+    /// How long the toast stays on screen, including its animations.
+    pub duration: Duration,
+}
+
+impl NotificationToast {
+    /// Creates a new informational toast which is shown for four seconds.
+    pub fn new(text: impl Into<ArcStr>) -> Self {
+        Self {
+            text: text.into(),
+            severity: ToastSeverity::Info,
+            duration: Duration::from_secs(4),
+        }
+    }
+
+    /// Sets the severity of the toast.
+    pub fn with_severity(mut self, severity: ToastSeverity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Sets how long the toast stays on screen.
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+/// A toast which is currently shown.
+struct ActiveToast {
+    label: WidgetPod<Label>,
+    severity: ToastSeverity,
+    /// Time since the toast was pushed, in nanoseconds.
+    age: u64,
+    /// Total time the toast is shown for, in nanoseconds.
+    lifetime: u64,
+    /// The background rect, computed during layout.
+    rect: Rect,
+}
+
+impl ActiveToast {
+    /// Returns how far the toast has slid onto the screen, from `0.0` to `1.0`.
+    fn visibility(&self) -> f64 {
+        let anim = TOAST_ANIM_NS.min(self.lifetime / 2).max(1);
+        let slide_in = self.age.min(anim) as f64 / anim as f64;
+        let slide_out = self.lifetime.saturating_sub(self.age).min(anim) as f64 / anim as f64;
+        slide_in.min(slide_out)
+    }
+}
+
+/// A widget that shows stacked [`NotificationToast`]s over its content.
+///
+/// Toasts are pushed with [`push_toast`](Self::push_toast) and appear in the
+/// bottom-right corner, with the newest toast at the bottom.
+/// Each toast slides in, stays for its duration, then slides out and is removed,
+/// with the remaining toasts reflowing to fill the gap.
+///
+/// The `ToastOverlay` widget's size is determined solely by its content.
+pub struct ToastOverlay {
+    content: WidgetPod<dyn Widget>,
+    toasts: Vec<ActiveToast>,
+}
+
+// --- MARK: BUILDERS
+impl ToastOverlay {
+    /// Creates a new `ToastOverlay` with no toasts.
+    pub fn new(content: NewWidget<impl Widget + ?Sized>) -> Self {
+        Self {
+            content: content.erased().to_pod(),
+            toasts: Vec::new(),
+        }
+    }
+}
+
+// --- MARK: METHODS
+impl ToastOverlay {
+    /// Returns the number of toasts currently shown, including those sliding out.
+    pub fn toast_count(&self) -> usize {
+        self.toasts.len()
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl ToastOverlay {
+    /// Replaces the content widget with a new one.
+    pub fn set_content(this: &mut WidgetMut<'_, Self>, content: NewWidget<impl Widget + ?Sized>) {
+        this.ctx.remove_child(std::mem::replace(
+            &mut this.widget.content,
+            content.erased().to_pod(),
+        ));
+    }
+
+    /// Returns a mutable reference to the content widget.
+    pub fn content_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.content)
+    }
+
+    /// Shows a new toast, which will be removed once its duration has elapsed.
+    pub fn push_toast(this: &mut WidgetMut<'_, Self>, toast: NotificationToast) {
+        let lifetime = u64::try_from(toast.duration.as_nanos()).unwrap_or(u64::MAX);
+        this.widget.toasts.push(ActiveToast {
+            label: Label::new(toast.text).prepare().to_pod(),
+            severity: toast.severity,
+            age: 0,
+            lifetime,
+            rect: Rect::ZERO,
+        });
+        this.ctx.children_changed();
+        this.ctx.request_anim_frame();
+    }
+
+    /// Removes all toasts immediately, without animating them out.
+    pub fn clear_toasts(this: &mut WidgetMut<'_, Self>) {
+        for toast in std::mem::take(&mut this.widget.toasts) {
+            this.ctx.remove_child(toast.label);
+        }
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for ToastOverlay {
+    type Action = NoAction;
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        for toast in &mut self.toasts {
+            toast.age = toast.age.saturating_add(interval);
+        }
+        for toast in self
+            .toasts
+            .extract_if(.., |toast| toast.age >= toast.lifetime)
+        {
+            ctx.remove_child(toast.label);
+        }
+
+        if !self.toasts.is_empty() {
+            ctx.request_anim_frame();
+        }
+        ctx.request_layout();
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.content);
+        for toast in &mut self.toasts {
+            ctx.register_child(&mut toast.label);
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        let auto_length = len_req.into();
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+        ctx.compute_length(
+            &mut self.content,
+            auto_length,
+            context_size,
+            axis,
+            cross_length,
+        )
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let content_size = ctx.compute_size(&mut self.content, SizeDef::fit(size), size.into());
+        ctx.run_layout(&mut self.content, content_size);
+        ctx.place_child(&mut self.content, Point::ORIGIN);
+        ctx.derive_baselines(&self.content);
+        // Toasts slide in from beyond our bounds, so keep them from painting there.
+        ctx.set_clip_path(size.to_rect());
+
+        let max_toast_width = TOAST_MAX_WIDTH.min(size.width - 2. * TOAST_MARGIN);
+        let label_space = Size::new(
+            (max_toast_width - 2. * TOAST_PADDING).max(0.),
+            (size.height - 2. * (TOAST_MARGIN + TOAST_PADDING)).max(0.),
+        );
+
+        // Stack toasts upwards from the bottom-right corner, newest at the bottom.
+        let mut bottom = size.height - TOAST_MARGIN;
+        for toast in self.toasts.iter_mut().rev() {
+            let label_size =
+                ctx.compute_size(&mut toast.label, SizeDef::fit(label_space), size.into());
+            ctx.run_layout(&mut toast.label, label_size);
+
+            let toast_size = Size::new(
+                label_size.width + 2. * TOAST_PADDING,
+                label_size.height + 2. * TOAST_PADDING,
+            );
+            let visibility = toast.visibility();
+            // Slide in from beyond the right edge.
+            let x = size.width - (toast_size.width + TOAST_MARGIN) * visibility;
+            let y = bottom - toast_size.height;
+            toast.rect = Rect::from_origin_size((x, y), toast_size);
+            ctx.place_child(
+                &mut toast.label,
+                (x + TOAST_PADDING, y + TOAST_PADDING).into(),
+            );
+
+            // Scale the stacking offset so that neighbours reflow smoothly.
+            bottom -= (toast_size.height + TOAST_MARGIN) * visibility;
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        painter: &mut Painter<'_>,
+    ) {
+        for toast in &self.toasts {
+            let background = RoundedRect::from_rect(toast.rect, TOAST_RADIUS);
+            painter.fill(background, toast.severity.color()).draw();
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_live(Live::Polite);
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        let mut ids = ChildrenIds::from_slice(&[self.content.id()]);
+        ids.extend(self.toasts.iter().map(|toast| toast.label.id()));
+        ids
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("ToastOverlay", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+    use crate::widgets::SizedBox;
+
+    #[test]
+    fn toast_expires() {
+        let widget = ToastOverlay::new(SizedBox::empty().prepare()).prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (400, 300));
+
+        harness.edit_root_widget(|mut overlay| {
+            ToastOverlay::push_toast(
+                &mut overlay,
+                NotificationToast::new("Saved")
+                    .with_severity(ToastSeverity::Success)
+                    .with_duration(Duration::from_millis(1000)),
+            );
+        });
+        assert_eq!(harness.root_widget().toast_count(), 1);
+
+        harness.animate_ms(500);
+        assert_eq!(harness.root_widget().toast_count(), 1);
+
+        harness.animate_ms(600);
+        assert_eq!(harness.root_widget().toast_count(), 0);
+    }
+
+    #[test]
+    fn toasts_expire_independently() {
+        let widget = ToastOverlay::new(SizedBox::empty().prepare()).prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (400, 300));
+
+        harness.edit_root_widget(|mut overlay| {
+            ToastOverlay::push_toast(
+                &mut overlay,
+                NotificationToast::new("Short").with_duration(Duration::from_millis(500)),
+            );
+            ToastOverlay::push_toast(
+                &mut overlay,
+                NotificationToast::new("Long").with_duration(Duration::from_millis(2000)),
+            );
+        });
+        assert_eq!(harness.root_widget().toast_count(), 2);
+
+        harness.animate_ms(1000);
+        assert_eq!(harness.root_widget().toast_count(), 1);
+
+        harness.animate_ms(1500);
+        assert_eq!(harness.root_widget().toast_count(), 0);
+    }
+}
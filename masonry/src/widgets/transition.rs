@@ -0,0 +1,412 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::{
+    AccessCtx, ActionCtx, ChildrenIds, ErasedAction, LayoutCtx, MeasureCtx, NewWidget, NoAction,
+    PaintCtx, PropertiesMut, PropertiesRef, RegisterCtx, Update, UpdateCtx, Widget, WidgetId,
+    WidgetMut, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Affine, Axis, Point, Size, Vec2};
+use crate::layout::{LayoutSize, LenReq, Length, SizeDef};
+
+/// How the child of a [`Transition`] animates in and out.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransitionKind {
+    /// The child fades in and out.
+    Fade,
+    /// The child slides in from the given offset, and slides out towards it.
+    Slide(Vec2),
+    /// The child grows from its center, and shrinks back towards it.
+    Scale,
+}
+
+/// A widget that animates its child in when it's added, and out when it's removed.
+///
+/// The child is only detached from the widget tree once its exit animation is done,
+/// so it keeps being painted in the meantime.
+/// For that to work, the child must be removed with [`set_child`](Self::set_child)
+/// rather than by removing the `Transition` itself.
+///
+/// The `Transition` widget's size is determined solely by its current child.
+pub struct Transition {
+    kind: TransitionKind,
+    /// Duration of the enter and exit animations, in nanoseconds.
+    duration: u64,
+    /// The child set with [`with_child`](Self::with_child), until the `Transition`
+    /// is added to the widget tree and it becomes `child`.
+    ///
+    /// Until then, builder methods can still change the child's animation.
+    initial_child: Option<NewWidget<TransitionFrame>>,
+    child: Option<WidgetPod<TransitionFrame>>,
+    /// Previous children which are still animating out.
+    exiting: Vec<WidgetPod<TransitionFrame>>,
+}
+
+/// Wraps each child of a [`Transition`], and animates its own transform and opacity.
+struct TransitionFrame {
+    child: WidgetPod<dyn Widget>,
+    kind: TransitionKind,
+    duration: u64,
+    /// How far the frame is on screen, from `0.0` to `1.0`.
+    visibility: f64,
+    exiting: bool,
+}
+
+/// Submitted by a [`TransitionFrame`] once its exit animation is done.
+#[derive(Debug)]
+struct ExitFinished;
+
+// --- MARK: BUILDERS
+impl Transition {
+    /// Creates a new `Transition` with no child, and a 200ms animation.
+    pub fn new(kind: TransitionKind) -> Self {
+        Self {
+            kind,
+            duration: 200_000_000,
+            initial_child: None,
+            child: None,
+            exiting: Vec::new(),
+        }
+    }
+
+    /// Builder-style method to set the child, which animates in once added to the widget tree.
+    pub fn with_child(mut self, child: NewWidget<impl Widget + ?Sized>) -> Self {
+        self.initial_child = Some(self.new_frame(child));
+        self
+    }
+
+    /// Builder-style method to set the duration of the enter and exit animations.
+    ///
+    /// This applies to the child set with [`with_child`](Self::with_child),
+    /// whether it was set before or after calling this method.
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        if let Some(child) = &mut self.initial_child {
+            child.widget.duration = self.duration;
+        }
+        self
+    }
+
+    fn new_frame(&self, child: NewWidget<impl Widget + ?Sized>) -> NewWidget<TransitionFrame> {
+        TransitionFrame {
+            child: child.erased().to_pod(),
+            kind: self.kind,
+            duration: self.duration,
+            visibility: 0.,
+            exiting: false,
+        }
+        .prepare()
+    }
+}
+
+// --- MARK: METHODS
+impl Transition {
+    /// Returns the number of previous children which are still animating out.
+    pub fn exiting_count(&self) -> usize {
+        self.exiting.len()
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Transition {
+    /// Replaces the child.
+    ///
+    /// The previous child animates out, and the new one, if any, animates in.
+    pub fn set_child(
+        this: &mut WidgetMut<'_, Self>,
+        child: Option<NewWidget<impl Widget + ?Sized>>,
+    ) {
+        let new_child = child.map(|child| this.widget.new_frame(child).to_pod());
+        if let Some(mut old_child) = std::mem::replace(&mut this.widget.child, new_child) {
+            TransitionFrame::exit(&mut this.ctx.get_mut(&mut old_child));
+            this.widget.exiting.push(old_child);
+        }
+        this.ctx.children_changed();
+    }
+
+    /// Sets the kind of animation used for children added from now on.
+    pub fn set_kind(this: &mut WidgetMut<'_, Self>, kind: TransitionKind) {
+        this.widget.kind = kind;
+    }
+
+    /// Sets the duration of animations started from now on.
+    pub fn set_duration(this: &mut WidgetMut<'_, Self>, duration: Duration) {
+        this.widget.duration = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+    }
+
+    /// Runs `f` with a mutable reference to the child, if any, and returns its result.
+    ///
+    /// Children which are animating out can't be accessed.
+    pub fn with_child_mut<R>(
+        this: &mut WidgetMut<'_, Self>,
+        f: impl FnOnce(WidgetMut<'_, dyn Widget>) -> R,
+    ) -> Option<R> {
+        let frame = this.widget.child.as_mut()?;
+        let mut frame = this.ctx.get_mut(frame);
+        let child = &mut frame.widget.child;
+        Some(f(frame.ctx.get_mut(child)))
+    }
+}
+
+impl TransitionFrame {
+    fn exit(this: &mut WidgetMut<'_, Self>) {
+        this.widget.exiting = true;
+        this.ctx.request_anim_frame();
+    }
+
+    /// Applies the transform and opacity for the current visibility.
+    fn apply(&self, ctx: &mut UpdateCtx<'_>) {
+        let hidden = 1. - self.visibility;
+        match self.kind {
+            TransitionKind::Fade => ctx.set_opacity(self.visibility as f32),
+            TransitionKind::Slide(offset) => ctx.set_transform(Affine::translate(offset * hidden)),
+            TransitionKind::Scale => {
+                let center = ctx.border_box().size().to_vec2() / 2.;
+                ctx.set_transform(
+                    Affine::translate(center)
+                        * Affine::scale(self.visibility)
+                        * Affine::translate(-center),
+                );
+            }
+        }
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Transition {
+    type Action = NoAction;
+
+    fn on_action(
+        &mut self,
+        ctx: &mut ActionCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        action: &ErasedAction,
+        source: WidgetId,
+    ) {
+        if action.is::<ExitFinished>()
+            && let Some(idx) = self.exiting.iter().position(|frame| frame.id() == source)
+        {
+            ctx.remove_child(self.exiting.remove(idx));
+            ctx.set_handled();
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        if let Some(initial_child) = self.initial_child.take() {
+            self.child = Some(initial_child.to_pod());
+        }
+        if let Some(child) = &mut self.child {
+            ctx.register_child(child);
+        }
+        for frame in &mut self.exiting {
+            ctx.register_child(frame);
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        let Some(child) = &mut self.child else {
+            return Length::ZERO;
+        };
+        let auto_length = len_req.into();
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+        ctx.compute_length(child, auto_length, context_size, axis, cross_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        // Exiting children are laid out like the current child, and painted under it.
+        for frame in self.exiting.iter_mut().chain(&mut self.child) {
+            let frame_size = ctx.compute_size(frame, SizeDef::fit(size), size.into());
+            ctx.run_layout(frame, frame_size);
+            ctx.place_child(frame, Point::ORIGIN);
+        }
+        if let Some(child) = &self.child {
+            ctx.derive_baselines(child);
+        } else {
+            ctx.clear_baselines();
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.exiting
+            .iter()
+            .chain(&self.child)
+            .map(|frame| frame.id())
+            .collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Transition", id = id.trace())
+    }
+}
+
+impl Widget for TransitionFrame {
+    type Action = ExitFinished;
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        let step = interval as f64 / self.duration.max(1) as f64;
+        if self.exiting {
+            self.visibility = (self.visibility - step).max(0.);
+        } else {
+            self.visibility = (self.visibility + step).min(1.);
+        }
+        self.apply(ctx);
+
+        if self.exiting && self.visibility == 0. {
+            ctx.submit_action::<Self::Action>(ExitFinished);
+        } else if self.exiting || self.visibility < 1. {
+            ctx.request_anim_frame();
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::WidgetAdded = event {
+            self.apply(ctx);
+            ctx.request_anim_frame();
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        let auto_length = len_req.into();
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+        ctx.compute_length(
+            &mut self.child,
+            auto_length,
+            context_size,
+            axis,
+            cross_length,
+        )
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let child_size = ctx.compute_size(&mut self.child, SizeDef::fit(size), size.into());
+        ctx.run_layout(&mut self.child, child_size);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        ctx.derive_baselines(&self.child);
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        if self.exiting {
+            node.set_hidden();
+        }
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.child.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("TransitionFrame", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::WidgetTag;
+    use crate::palette;
+    use crate::properties::Background;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+    use crate::widgets::SizedBox;
+
+    #[test]
+    fn removed_child_fades_out() {
+        let child_tag = WidgetTag::named("child");
+        let child = NewWidget::new(SizedBox::empty())
+            .with_props(Background::Color(palette::css::RED))
+            .with_tag(child_tag);
+        // The duration applies to the child, even though it's set afterwards.
+        let widget = Transition::new(TransitionKind::Fade)
+            .with_child(child)
+            .with_duration(Duration::from_millis(100))
+            .prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (50, 50));
+        harness.animate_ms(100);
+        let child_id = harness.get_widget(child_tag).id();
+        let frame_id = harness.root_widget().children_ids()[0];
+        assert_eq!(harness.get_widget_with_id(frame_id).ctx().opacity(), 1.);
+
+        harness.edit_root_widget(|mut transition| {
+            Transition::set_child(&mut transition, None::<NewWidget<SizedBox>>);
+        });
+        harness.animate_ms(50);
+
+        // The child is still in the tree and painted, halfway faded out.
+        assert_eq!(harness.root_widget().exiting_count(), 1);
+        assert!(harness.try_get_widget(child_id).is_some());
+        assert_eq!(harness.get_widget_with_id(frame_id).ctx().opacity(), 0.5);
+        let _ = harness.render();
+
+        harness.animate_ms(50);
+        assert_eq!(harness.root_widget().exiting_count(), 0);
+        assert!(harness.try_get_widget(child_id).is_none());
+    }
+}
@@ -0,0 +1,296 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+
+use crate::core::{
+    AccessCtx, ChildrenIds, ComposeCtx, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
+    PropertiesRef, RegisterCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::imaging::Painter;
+use crate::kurbo::{Affine, Axis, Point, Size, Vec2};
+use crate::layout::{LayoutSize, LenReq, Length, SizeDef};
+use crate::util::Sanitize;
+
+/// A widget which shows its child through a pan and zoom transform.
+///
+/// This is meant for canvases such as diagram or map editors.
+/// The child is laid out at its max-content size, then scaled by the zoom level,
+/// and offset by the pan, both in the viewport's content-box coordinate space.
+/// Content outside the viewport is clipped.
+///
+/// Pointer events are mapped back through the transform, so the child receives
+/// positions in its own (content-space) coordinates, e.g. through [`EventCtx::local_position`].
+///
+/// The viewport owns the child's [transform](crate::core::WidgetOptions::transform),
+/// which means any transform set on the child is overwritten.
+///
+/// This widget doesn't handle any input itself;
+/// apps are expected to update the pan and zoom in response to gestures.
+///
+/// [`EventCtx::local_position`]: crate::core::EventCtx::local_position
+pub struct Viewport {
+    child: WidgetPod<dyn Widget>,
+    pan: Vec2,
+    zoom: f64,
+    content_size: Size,
+    viewport_size: Size,
+}
+
+// --- MARK: BUILDERS
+impl Viewport {
+    /// The smallest zoom level a `Viewport` can have.
+    ///
+    /// Smaller values are clamped to this, so the transform always stays invertible.
+    pub const MIN_ZOOM: f64 = 0.01;
+
+    /// Creates a new `Viewport` with no pan and a zoom level of `1.0`.
+    pub fn new(child: NewWidget<impl Widget + ?Sized>) -> Self {
+        Self {
+            child: child.erased().to_pod(),
+            pan: Vec2::ZERO,
+            zoom: 1.0,
+            content_size: Size::ZERO,
+            viewport_size: Size::ZERO,
+        }
+    }
+
+    /// Builder-style method to set the pan offset.
+    ///
+    /// This is where the child's origin appears, in the viewport's coordinate space.
+    pub fn with_pan(mut self, pan: Vec2) -> Self {
+        self.pan = pan;
+        self
+    }
+
+    /// Builder-style method to set the zoom level.
+    ///
+    /// The zoom level must be finite and non-negative, and is clamped to at least [`MIN_ZOOM`](Self::MIN_ZOOM).
+    pub fn with_zoom(mut self, zoom: f64) -> Self {
+        self.zoom = zoom.sanitize("zoom").max(Self::MIN_ZOOM);
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl Viewport {
+    /// Returns the pan offset.
+    pub fn pan(&self) -> Vec2 {
+        self.pan
+    }
+
+    /// Returns the zoom level.
+    pub fn zoom(&self) -> f64 {
+        self.zoom
+    }
+
+    /// Returns the transform from the child's coordinate space to the viewport's.
+    pub fn transform(&self) -> Affine {
+        Affine::scale(self.zoom).then_translate(self.pan)
+    }
+
+    /// Maps a point in the viewport's coordinate space to the child's coordinate space.
+    pub fn to_content(&self, point: Point) -> Point {
+        self.transform().inverse() * point
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Viewport {
+    /// Sets the pan offset.
+    ///
+    /// See [`with_pan`](Self::with_pan) for details.
+    pub fn set_pan(this: &mut WidgetMut<'_, Self>, pan: Vec2) {
+        this.widget.pan = pan;
+        this.ctx.request_compose();
+    }
+
+    /// Sets the zoom level, keeping the child's origin in place.
+    ///
+    /// See [`with_zoom`](Self::with_zoom) for details.
+    pub fn set_zoom(this: &mut WidgetMut<'_, Self>, zoom: f64) {
+        this.widget.zoom = zoom.sanitize("zoom").max(Self::MIN_ZOOM);
+        // The max-content size of the viewport depends on the zoom level.
+        this.ctx.request_layout();
+        this.ctx.request_compose();
+    }
+
+    /// Sets the zoom level, keeping the content under `point` in place.
+    ///
+    /// `point` is in the viewport's coordinate space, e.g. the position of the pointer.
+    pub fn zoom_to_point(this: &mut WidgetMut<'_, Self>, zoom: f64, point: Point) {
+        let content_point = this.widget.to_content(point);
+        Self::set_zoom(this, zoom);
+        this.widget.pan = point.to_vec2() - content_point.to_vec2() * this.widget.zoom;
+    }
+
+    /// Zooms and pans so the whole child fits in the viewport, centered.
+    ///
+    /// This uses the sizes from the last layout pass, so it has no effect before the
+    /// viewport has been laid out.
+    pub fn fit_content(this: &mut WidgetMut<'_, Self>) {
+        let content = this.widget.content_size;
+        let viewport = this.widget.viewport_size;
+        if content.is_zero_area() || viewport.is_zero_area() {
+            return;
+        }
+        let zoom = (viewport.width / content.width).min(viewport.height / content.height);
+        Self::set_zoom(this, zoom);
+        let zoom = this.widget.zoom;
+        this.widget.pan = Vec2::new(
+            (viewport.width - content.width * zoom) / 2.,
+            (viewport.height - content.height * zoom) / 2.,
+        );
+    }
+
+    /// Returns a mutable reference to the child.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Viewport {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<Length>,
+    ) -> Length {
+        match len_req {
+            // Like a scroll area, the viewport can be shrunk down to nothing.
+            LenReq::MinContent => Length::ZERO,
+            LenReq::MaxContent => {
+                let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+                let length =
+                    ctx.compute_length(&mut self.child, len_req.into(), context_size, axis, None);
+                Length::px(length.get() * self.zoom)
+            }
+            LenReq::FitContent(space) => space,
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let content_size = ctx.compute_size(&mut self.child, SizeDef::MAX, size.into());
+        ctx.run_layout(&mut self.child, content_size);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+        ctx.set_clip_path(size.to_rect());
+
+        self.content_size = content_size;
+        self.viewport_size = size;
+    }
+
+    fn compose(&mut self, ctx: &mut ComposeCtx<'_>) {
+        let transform = self.transform();
+        ctx.set_child_transform(&mut self.child, transform);
+    }
+
+    fn paint(
+        &mut self,
+        _ctx: &mut PaintCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _painter: &mut Painter<'_>,
+    ) {
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.child.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Viewport", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{PointerButton, PointerEvent, WidgetTag};
+    use crate::layout::AsUnit;
+    use crate::testing::{ModularWidget, TestHarness};
+    use crate::theme::test_property_set;
+
+    fn click_target() -> NewWidget<ModularWidget<Option<Point>>> {
+        ModularWidget::new(None)
+            .pointer_event_fn(|state, ctx, _, event| {
+                if let PointerEvent::Down(event) = event {
+                    *state = Some(ctx.local_position(event.state.position));
+                }
+            })
+            .measure_fn(|_, _, _, _, _, _| 100.px())
+            .prepare()
+    }
+
+    #[test]
+    fn click_in_content_space() {
+        let target_tag = WidgetTag::named("target");
+        let viewport = Viewport::new(click_target().with_tag(target_tag))
+            .with_pan(Vec2::new(10., 20.))
+            .with_zoom(2.)
+            .prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), viewport, (200, 200));
+
+        harness.mouse_move((50., 60.));
+        harness.mouse_button_press(Some(PointerButton::Primary));
+        assert_eq!(
+            harness.get_widget(target_tag).state,
+            Some(Point::new(20., 20.))
+        );
+
+        // Zooming around a point keeps the content under it in place.
+        harness.edit_root_widget(|mut viewport| {
+            Viewport::zoom_to_point(&mut viewport, 4., Point::new(50., 60.));
+        });
+        harness.mouse_button_release(Some(PointerButton::Primary));
+        harness.mouse_button_press(Some(PointerButton::Primary));
+        assert_eq!(
+            harness.get_widget(target_tag).state,
+            Some(Point::new(20., 20.))
+        );
+
+        // A click outside of the transformed content doesn't reach it.
+        harness.edit_root_widget(|mut viewport| {
+            Viewport::set_pan(&mut viewport, Vec2::new(100., 100.));
+        });
+        harness.edit_widget(target_tag, |target| target.widget.state = None);
+        harness.mouse_button_release(Some(PointerButton::Primary));
+        harness.mouse_button_press(Some(PointerButton::Primary));
+        assert_eq!(harness.get_widget(target_tag).state, None);
+    }
+
+    #[test]
+    fn fit_content() {
+        let viewport = Viewport::new(click_target()).prepare();
+        let mut harness = TestHarness::create_with_size(test_property_set(), viewport, (200, 50));
+
+        harness.edit_root_widget(|mut viewport| {
+            Viewport::fit_content(&mut viewport);
+        });
+        let viewport = harness.root_widget();
+        assert_eq!(viewport.zoom(), 0.5);
+        assert_eq!(viewport.pan(), Vec2::new(75., 0.));
+    }
+}
@@ -16,6 +16,7 @@ use crate::layout::{LayoutSize, LenReq, Length, SizeDef, UnitPoint};
 struct Child {
     widget: WidgetPod<dyn Widget>,
     alignment: ChildAlignment,
+    z_index: i32,
 }
 
 /// An option specifying how a child widget is aligned within a [`ZStack`].
@@ -35,7 +36,11 @@ impl From<UnitPoint> for ChildAlignment {
 
 impl Child {
     fn new(widget: WidgetPod<dyn Widget>, alignment: ChildAlignment) -> Self {
-        Self { widget, alignment }
+        Self {
+            widget,
+            alignment,
+            z_index: 0,
+        }
     }
 
     fn update_alignment(&mut self, alignment: ChildAlignment) {
@@ -48,6 +53,13 @@ impl Child {
 /// The alignment of how the children are placed can be specified globally using [`with_alignment`][Self::with_alignment].
 /// Each child can additionally override the global alignment using [`ChildAlignment::SelfAligned`].
 ///
+/// Children are stacked in the order they were added, later children on top.
+/// A child can be given an explicit z-index with [`with_z_index`][Self::with_z_index]:
+/// children with a higher z-index are painted on top and hit first by the pointer.
+/// Children with the same z-index, which is `0` by default, keep their insertion order.
+/// The z-index doesn't change the child indices used by the [`CollectionWidget`] methods,
+/// nor the focus and accessibility order, which follow insertion order.
+///
 #[doc = concat!(
     "![Red foreground widget on top of blue background widget](",
     include_doc_path!("screenshots/zstack_alignment_default.png"),
@@ -56,6 +68,8 @@ impl Child {
 pub struct ZStack {
     children: Vec<Child>,
     alignment: UnitPoint,
+    /// Child ids sorted by z-index, rebuilt whenever children or z-indices change.
+    paint_order: ChildrenIds,
 }
 
 // --- MARK: DEFAULT
@@ -64,6 +78,7 @@ impl Default for ZStack {
         Self {
             children: Vec::default(),
             alignment: UnitPoint::CENTER,
+            paint_order: ChildrenIds::new(),
         }
     }
 }
@@ -89,10 +104,41 @@ impl ZStack {
     ) -> Self {
         let child = Child::new(child.erased().to_pod(), alignment.into());
         self.children.push(child);
+        self.update_paint_order();
+        self
+    }
+
+    /// Appends a child widget to the `ZStack` with the given z-index.
+    ///
+    /// Children with a higher z-index are stacked on top of children with a lower one.
+    /// See [`with`][Self::with] for children with the default z-index of `0`.
+    pub fn with_z_index(
+        mut self,
+        child: NewWidget<impl Widget + ?Sized>,
+        alignment: impl Into<ChildAlignment>,
+        z_index: i32,
+    ) -> Self {
+        let mut child = Child::new(child.erased().to_pod(), alignment.into());
+        child.z_index = z_index;
+        self.children.push(child);
+        self.update_paint_order();
         self
     }
 }
 
+// --- MARK: METHODS
+impl ZStack {
+    fn update_paint_order(&mut self) {
+        // The sort is stable, which keeps insertion order for ties.
+        let mut children: Vec<_> = self.children.iter().collect();
+        children.sort_by_key(|child| child.z_index);
+        self.paint_order = children
+            .into_iter()
+            .map(|child| child.widget.id())
+            .collect();
+    }
+}
+
 // --- MARK: WIDGETMUT
 impl ZStack {
     /// Changes the alignment of the `ZStack`.
@@ -102,6 +148,19 @@ impl ZStack {
         this.widget.alignment = alignment.into();
         this.ctx.request_layout();
     }
+
+    /// Sets the z-index of the child at the given index.
+    ///
+    /// See also [`with_z_index`][Self::with_z_index].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn set_z_index(this: &mut WidgetMut<'_, Self>, idx: usize, z_index: i32) {
+        this.widget.children[idx].z_index = z_index;
+        this.widget.update_paint_order();
+        this.ctx.request_paint_only();
+    }
 }
 
 // --- MARK: COLLECTIONWIDGET
@@ -134,6 +193,7 @@ impl CollectionWidget<ChildAlignment> for ZStack {
     ) {
         let child = Child::new(child.erased().to_pod(), params.into());
         this.widget.children.push(child);
+        this.widget.update_paint_order();
         this.ctx.children_changed();
     }
 
@@ -150,6 +210,7 @@ impl CollectionWidget<ChildAlignment> for ZStack {
     ) {
         let child = Child::new(child.erased().to_pod(), params.into());
         this.widget.children.insert(idx, child);
+        this.widget.update_paint_order();
         this.ctx.children_changed();
     }
 
@@ -166,6 +227,7 @@ impl CollectionWidget<ChildAlignment> for ZStack {
     ) {
         let child = Child::new(child.erased().to_pod(), params.into());
         let old_child = std::mem::replace(&mut this.widget.children[idx], child);
+        this.widget.update_paint_order();
         this.ctx.remove_child(old_child.widget);
     }
 
@@ -187,6 +249,7 @@ impl CollectionWidget<ChildAlignment> for ZStack {
     /// Panics if `a` or `b` are out of bounds.
     fn swap(this: &mut WidgetMut<'_, Self>, a: usize, b: usize) {
         this.widget.children.swap(a, b);
+        this.widget.update_paint_order();
         this.ctx.children_changed();
     }
 
@@ -197,6 +260,7 @@ impl CollectionWidget<ChildAlignment> for ZStack {
     /// Panics if `idx` is out of bounds.
     fn remove(this: &mut WidgetMut<'_, Self>, idx: usize) {
         let child = this.widget.children.remove(idx);
+        this.widget.update_paint_order();
         this.ctx.remove_child(child.widget);
     }
 
@@ -205,6 +269,7 @@ impl CollectionWidget<ChildAlignment> for ZStack {
         for child in this.widget.children.drain(..) {
             this.ctx.remove_child(child.widget);
         }
+        this.widget.paint_order.clear();
     }
 }
 
@@ -288,11 +353,14 @@ impl Widget for ZStack {
     fn children_ids(&self) -> ChildrenIds {
         self.children
             .iter()
-            .map(|child| &child.widget)
-            .map(|widget_pod| widget_pod.id())
+            .map(|child| child.widget.id())
             .collect()
     }
 
+    fn paint_order_ids(&self) -> ChildrenIds {
+        self.paint_order.clone()
+    }
+
     fn accessibility_role(&self) -> Role {
         Role::GenericContainer
     }
@@ -392,4 +460,56 @@ mod tests {
         let mut harness = TestHarness::create(test_property_set(), widget);
         assert_render_snapshot!(harness, "zstack_alignments_self_aligned");
     }
+
+    #[test]
+    fn z_index_order() {
+        let square = |color, side: f64| {
+            SizedBox::empty()
+                .size(side.px(), side.px())
+                .prepare()
+                .with_props(Background::Color(color))
+        };
+        let front = square(palette::css::BLUE, 40.);
+        let back = square(palette::css::RED, 80.);
+        let tied = square(palette::css::GREEN, 20.);
+        let (front_id, back_id, tied_id) = (front.id(), back.id(), tied.id());
+
+        // The red square is added last, but paints behind the blue one.
+        // The green one ties with the blue one, and stays on top of it.
+        let widget = ZStack::new()
+            .with_z_index(front, ChildAlignment::ParentAligned, 1)
+            .with_z_index(back, ChildAlignment::ParentAligned, -1)
+            .with_z_index(tied, ChildAlignment::ParentAligned, 1)
+            .prepare();
+
+        let mut harness = TestHarness::create_with_size(test_property_set(), widget, (100, 100));
+        assert_render_snapshot!(harness, "zstack_z_index_order");
+
+        // The z-index only changes the paint order, children keep their insertion order.
+        let root_id = harness.root_id();
+        let zstack = harness.get_widget_with_id(root_id);
+        assert_eq!(
+            zstack.children_ids().as_slice(),
+            &[front_id, back_id, tied_id]
+        );
+        assert_eq!(
+            zstack.paint_order_ids().as_slice(),
+            &[back_id, front_id, tied_id]
+        );
+        let widget_at = |harness: &TestHarness<ZStack>, pos: (f64, f64)| {
+            harness
+                .get_widget_with_id(root_id)
+                .find_widget_under_pointer(pos.into())
+                .map(|widget| widget.id())
+        };
+        assert_eq!(widget_at(&harness, (50., 50.)), Some(tied_id));
+        assert_eq!(widget_at(&harness, (35., 35.)), Some(front_id));
+        assert_eq!(widget_at(&harness, (15., 15.)), Some(back_id));
+
+        // Raising the red square puts it on top.
+        harness.edit_root_widget(|mut zstack| {
+            ZStack::set_z_index(&mut zstack, 1, 2);
+        });
+        assert_eq!(widget_at(&harness, (50., 50.)), Some(back_id));
+    }
 }
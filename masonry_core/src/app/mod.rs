@@ -6,6 +6,7 @@
 mod layer_stack;
 mod render_root;
 mod tracing_backend;
+mod tree_ops;
 mod visual_layers;
 
 pub use render_root::{RenderRoot, RenderRootOptions, RenderRootSignal, WindowSizePolicy};
@@ -13,6 +14,9 @@ pub use tracing_backend::{
     TracingSubscriberHasBeenSetError, default_tracing_subscriber, try_init_test_tracing,
     try_init_tracing,
 };
+pub use tree_ops::TreeOp;
 pub use visual_layers::{VisualLayer, VisualLayerKind, VisualLayerPlan};
 
+pub use crate::passes::mutate::try_mutate_widget;
+
 pub(crate) use render_root::{MutateCallback, RenderRootState};
@@ -15,13 +15,14 @@ use parley::{FontContext, LayoutContext};
 use tracing::{debug, info_span, warn};
 use tree_arena::{ArenaMut, TreeArena};
 
-use crate::app::VisualLayerPlan;
 use crate::app::layer_stack::LayerStack;
+use crate::app::{TreeOp, VisualLayerPlan};
 use crate::core::{
-    AccessCtx, AccessEvent, BrushIndex, CursorIcon, DefaultProperties, ErasedAction, FromDynWidget,
-    Handled, Ime, LayerType, NewWidget, PointerEvent, PropertiesRef, PropertyArena, QueryCtx,
-    ResizeDirection, TextEvent, Widget, WidgetArena, WidgetArenaNode, WidgetId, WidgetMut,
-    WidgetPod, WidgetRef, WidgetState, WidgetTag, WidgetTagInner, WindowEvent,
+    AccessCtx, AccessEvent, BrushIndex, CollectionWidget, CursorIcon, DefaultProperties,
+    ErasedAction, FromDynWidget, Handled, Ime, InputEvent, LayerType, NewWidget, PointerEvent,
+    PointerId, PropertiesRef, PropertyArena, QueryCtx, ResizeDirection, TextEvent, VelocityTracker,
+    Widget, WidgetArena, WidgetArenaNode, WidgetId, WidgetMut, WidgetPod, WidgetRef, WidgetState,
+    WidgetTag, WidgetTagInner, WindowEvent,
 };
 use crate::imaging::record::Scene;
 use crate::passes::accessibility::run_accessibility_pass;
@@ -32,7 +33,9 @@ use crate::passes::event::{
     run_on_access_event_pass, run_on_pointer_event_pass, run_on_text_event_pass,
 };
 use crate::passes::layout::run_layout_pass;
-use crate::passes::mutate::{mutate_widget, run_mutate_pass};
+use crate::passes::mutate::{
+    merge_state_to_root, mutate_widget, mutate_widget_unmerged, run_mutate_pass,
+};
 use crate::passes::paint::run_paint_pass;
 use crate::passes::update::{
     run_update_disabled_pass, run_update_focus_pass, run_update_focusable_pass,
@@ -87,6 +90,12 @@ pub(crate) struct RenderRootState {
     /// Last mouse position. Updated by `on_pointer_event` pass, used by other passes.
     pub(crate) last_mouse_pos: Option<LogicalPosition<f64>>,
 
+    /// Recent positions of the pointer, used to compute its velocity.
+    pub(crate) pointer_velocity: VelocityTracker,
+
+    /// The pointer whose positions are tracked in `pointer_velocity`.
+    pub(crate) pointer_velocity_id: Option<PointerId>,
+
     /// Currently focused widget.
     pub(crate) focused_widget: Option<WidgetId>,
 
@@ -173,6 +182,12 @@ pub(crate) struct RenderRootState {
 
     /// Whether to paint widget's bounding boxes and other visual helpers.
     pub(crate) debug_paint: bool,
+
+    /// Input events handled since the last `redraw`.
+    pub(crate) pending_input_events: Vec<InputEvent>,
+
+    /// Number of input events handled between the last two calls to `redraw`.
+    pub(crate) handled_input_events_last_frame: usize,
 }
 
 pub(crate) struct MutateCallback {
@@ -342,6 +357,8 @@ impl RenderRoot {
                 size_policy,
                 size,
                 last_mouse_pos: None,
+                pointer_velocity: VelocityTracker::new(),
+                pointer_velocity_id: None,
                 focused_widget: None,
                 focused_path: Vec::new(),
                 next_focused_widget: None,
@@ -377,6 +394,8 @@ impl RenderRoot {
                 access_tree_active: false,
                 scale_factor,
                 debug_paint,
+                pending_input_events: Vec::new(),
+                handled_input_events_last_frame: 0,
             },
             property_arena: PropertyArena::new(default_properties),
             widget_arena: WidgetArena {
@@ -449,6 +468,37 @@ impl RenderRoot {
         self.run_rewrite_passes();
     }
 
+    /// Forces a complete relayout and repaint of the entire widget tree.
+    ///
+    /// Masonry normally only re-runs layout and paint for widgets which requested it,
+    /// and caches measurements in between.
+    /// This marks every widget for layout, compose and paint, and clears every measurement cache.
+    /// The work happens on the next [`redraw`](Self::redraw).
+    ///
+    /// This is an escape hatch for global changes which incremental invalidation
+    /// doesn't pick up, and shouldn't be needed in normal operation.
+    pub fn invalidate_all(&mut self) {
+        fn request_layout_all_in(node: ArenaMut<'_, WidgetArenaNode>) {
+            let children = node.children;
+            let widget = &mut *node.item.widget;
+            let state = &mut node.item.state;
+
+            state.request_layout = true;
+            state.set_needs_layout(true);
+            state.request_compose = true;
+            state.needs_compose = true;
+
+            let id = state.id;
+            recurse_on_children(id, widget, children, |node| {
+                request_layout_all_in(node);
+            });
+        }
+
+        let root_node = self.widget_arena.get_node_mut(self.root_id());
+        request_layout_all_in(root_node);
+        self.request_render_all();
+    }
+
     pub(crate) fn root_id(&self) -> WidgetId {
         self.layer_stack.id()
     }
@@ -547,6 +597,9 @@ impl RenderRoot {
     /// Handles a pointer event.
     pub fn handle_pointer_event(&mut self, event: PointerEvent) -> Handled {
         let _span = info_span!("pointer_event");
+        self.global_state
+            .pending_input_events
+            .push(InputEvent::Pointer(event.clone()));
         let handled = run_on_pointer_event_pass(self, &event);
         run_update_pointer_pass(self);
         self.run_rewrite_passes();
@@ -557,6 +610,9 @@ impl RenderRoot {
     /// Handles a text event.
     pub fn handle_text_event(&mut self, event: TextEvent) -> Handled {
         let _span = info_span!("text_event");
+        self.global_state
+            .pending_input_events
+            .push(InputEvent::Text(event.clone()));
         let handled = run_on_text_event_pass(self, &event);
         run_update_focus_pass(self);
 
@@ -573,6 +629,9 @@ impl RenderRoot {
     /// Handles an accesskit event.
     pub fn handle_access_event(&mut self, event: ActionRequest) {
         let _span = info_span!("access_event");
+        self.global_state
+            .pending_input_events
+            .push(InputEvent::Access(event.clone()));
         if event.target_tree != TreeId::ROOT {
             warn!(
                 "Received ActionRequest for an unknown tree. {:?}",
@@ -612,6 +671,8 @@ impl RenderRoot {
     /// Returns the current visual-layer plan and, if accessibility is active, a tree update.
     pub fn redraw(&mut self) -> (VisualLayerPlan, Option<TreeUpdate>) {
         self.run_rewrite_passes();
+        self.global_state.handled_input_events_last_frame =
+            std::mem::take(&mut self.global_state.pending_input_events).len();
 
         let access_tree_active = self.global_state.access_tree_active;
 
@@ -757,6 +818,44 @@ impl RenderRoot {
         res
     }
 
+    /// Applies a batch of structural changes to [`CollectionWidget`]s of type `W`.
+    ///
+    /// The ops are applied in order. Unlike calling [`edit_widget`](Self::edit_widget) once per op,
+    /// widget state is merged up the tree once per modified parent after all ops have been applied,
+    /// and the rewrite passes only run once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an op's parent isn't in the tree or isn't a `W`, or if an index is out of bounds.
+    #[track_caller]
+    pub fn apply_tree_ops<W: CollectionWidget<Params>, Params>(
+        &mut self,
+        ops: impl IntoIterator<Item = TreeOp<Params>>,
+    ) {
+        let mut parents = Vec::new();
+        for op in ops {
+            let parent = op.parent();
+            if !self.widget_arena.has(parent) {
+                panic!("Could not find widget {parent} in tree.");
+            }
+            mutate_widget_unmerged(self, parent, |mut widget_mut| {
+                op.apply(&mut widget_mut.downcast::<W>());
+            });
+            if !parents.contains(&parent) {
+                parents.push(parent);
+            }
+        }
+
+        for parent in parents {
+            // A later op may have removed this parent.
+            if self.widget_arena.has(parent) {
+                merge_state_to_root(self, parent);
+            }
+        }
+
+        self.run_rewrite_passes();
+    }
+
     /// Adds a new layer at the end of the stack, with the given widget as its root, at the given position.
     ///
     /// The given `pos` must be in the window's coordinate space.
@@ -811,6 +910,11 @@ impl RenderRoot {
         self.global_state.size
     }
 
+    /// Returns the current scale factor of the window.
+    pub fn scale_factor(&self) -> f64 {
+        self.global_state.scale_factor
+    }
+
     pub(crate) fn get_kurbo_size(&self) -> Size {
         let size = self
             .global_state
@@ -958,6 +1062,11 @@ impl RenderRoot {
         self.global_state.focused_widget
     }
 
+    /// Returns the [`WidgetId`] of the [focus fallback](crate::doc::masonry_concepts#focus-fallback).
+    pub fn focus_fallback(&self) -> Option<WidgetId> {
+        self.global_state.focus_fallback
+    }
+
     /// Returns the [`WidgetId`] of the widget which [captures pointer events](crate::doc::masonry_concepts#pointer-capture).
     pub fn pointer_capture_target(&self) -> Option<WidgetId> {
         self.global_state.pointer_capture_target
@@ -993,10 +1102,31 @@ impl RenderRoot {
     }
 
     /// Returns true if the widget tree is waiting for an animation frame.
+    ///
+    /// The platform should keep sending [`WindowEvent::AnimFrame`] events while this is true.
     pub fn needs_anim(&self) -> bool {
         self.root_state().needs_anim
     }
 
+    /// Returns the input events passed to this `RenderRoot` since the last call to [`redraw`](Self::redraw),
+    /// in the order they were received.
+    ///
+    /// Input events are pointer, text, and access events.
+    /// They're processed as soon as they're passed to `RenderRoot`, so these are the events
+    /// whose effects haven't been drawn yet, rather than events waiting to be processed.
+    ///
+    /// This is meant for debugging input issues, e.g. events which seem to be dropped or reordered.
+    pub fn pending_input_events(&self) -> &[InputEvent] {
+        &self.global_state.pending_input_events
+    }
+
+    /// Returns the number of input events handled between the last two calls to [`redraw`](Self::redraw).
+    ///
+    /// See [`Self::pending_input_events`] for details.
+    pub fn handled_input_events_last_frame(&self) -> usize {
+        self.global_state.handled_input_events_last_frame
+    }
+
     /// Returns true if the accessibility tree needs to be rebuilt.
     ///
     /// This will be inhibited if `access_tree_active` is false.
@@ -0,0 +1,96 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::core::{CollectionWidget, NewWidget, Widget, WidgetId, WidgetMut};
+
+/// A structural change to the children of a [`CollectionWidget`].
+///
+/// A sequence of these can be applied in one go with [`RenderRoot::apply_tree_ops`],
+/// which is cheaper than editing each parent separately.
+/// This is meant for frameworks reconciling the widget tree from a declarative diff.
+///
+/// Each op targets the `parent` widget with the given id, and child indices are interpreted
+/// at the time the op is applied, i.e. after all previous ops in the batch.
+///
+/// [`RenderRoot::apply_tree_ops`]: crate::app::RenderRoot::apply_tree_ops
+#[derive(Debug)]
+pub enum TreeOp<Params> {
+    /// Inserts `child` at index `idx` of `parent`.
+    Insert {
+        /// The collection widget to modify.
+        parent: WidgetId,
+        /// The index the new child will have.
+        idx: usize,
+        /// The new child.
+        child: NewWidget<dyn Widget>,
+        /// The layout params of the new child.
+        params: Params,
+    },
+    /// Removes the child at index `idx` of `parent`.
+    Remove {
+        /// The collection widget to modify.
+        parent: WidgetId,
+        /// The index of the child to remove.
+        idx: usize,
+    },
+    /// Moves the child at index `from` of `parent` to index `to`, shifting the children in between.
+    Move {
+        /// The collection widget to modify.
+        parent: WidgetId,
+        /// The current index of the child.
+        from: usize,
+        /// The index the child will have after the move.
+        to: usize,
+    },
+    /// Replaces the child at index `idx` of `parent` with `child`.
+    Replace {
+        /// The collection widget to modify.
+        parent: WidgetId,
+        /// The index of the child to replace.
+        idx: usize,
+        /// The new child.
+        child: NewWidget<dyn Widget>,
+        /// The layout params of the new child.
+        params: Params,
+    },
+}
+
+impl<Params> TreeOp<Params> {
+    /// Returns the id of the widget this op modifies.
+    pub fn parent(&self) -> WidgetId {
+        match self {
+            Self::Insert { parent, .. }
+            | Self::Remove { parent, .. }
+            | Self::Move { parent, .. }
+            | Self::Replace { parent, .. } => *parent,
+        }
+    }
+
+    /// Applies this op to the given parent widget.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds.
+    pub(crate) fn apply<W: CollectionWidget<Params>>(self, this: &mut WidgetMut<'_, W>) {
+        match self {
+            Self::Insert {
+                idx, child, params, ..
+            } => W::insert(this, idx, child, params),
+            Self::Remove { idx, .. } => W::remove(this, idx),
+            Self::Move { from, to, .. } => {
+                if from < to {
+                    for i in from..to {
+                        W::swap(this, i, i + 1);
+                    }
+                } else {
+                    for i in (to..from).rev() {
+                        W::swap(this, i, i + 1);
+                    }
+                }
+            }
+            Self::Replace {
+                idx, child, params, ..
+            } => W::set(this, idx, child, params),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Helpers for filling the accessibility nodes of widgets.
+
+use accesskit::{HasPopup, Node};
+
+/// Reports a numeric `value` within the `min..=max` range on a widget's accessibility `node`.
+///
+/// This is meant for widgets like sliders, spinners and progress bars.
+/// If `value` is `None` or NaN, only the range is reported, e.g. for an indeterminate progress bar.
+/// Otherwise `value` is clamped to the range.
+/// Widgets whose value changes in steps, e.g. when the user presses an arrow key,
+/// should also report the step with [`Node::set_numeric_value_step`].
+///
+/// If `min` is greater than `max`, or either of them is NaN, nothing is reported.
+///
+/// # Panics
+///
+/// If debug assertions are on, panics if `min` is greater than `max`, or either of them is NaN.
+pub fn set_numeric_range(node: &mut Node, value: Option<f64>, min: f64, max: f64) {
+    if min.is_nan() || max.is_nan() || min > max {
+        debug_panic!("set_numeric_range: invalid range {min}..={max}");
+        return;
+    }
+    node.set_min_numeric_value(min);
+    node.set_max_numeric_value(max);
+    if let Some(value) = value
+        && !value.is_nan()
+    {
+        node.set_numeric_value(value.clamp(min, max));
+    }
+}
+
+/// Advertises on a widget's accessibility `node` that the widget has a context menu.
+///
+/// This lets assistive technologies open the menu without a pointer, by sending
+/// a [`ShowContextMenu`] action to the widget.
+/// The widget should handle that action in [`Widget::on_access_event`] the same way
+/// it handles a secondary click, and mark the event as handled.
+///
+/// [`ShowContextMenu`]: accesskit::Action::ShowContextMenu
+/// [`Widget::on_access_event`]: crate::core::Widget::on_access_event
+pub fn declare_context_menu(node: &mut Node) {
+    node.set_has_popup(HasPopup::Menu);
+    node.add_action(accesskit::Action::ShowContextMenu);
+}
+
+/// Reports on a widget's accessibility `node` whether the content it controls is expanded.
+///
+/// This is meant for widgets like disclosure buttons and tree items.
+/// It also advertises the action which toggles the state, [`Collapse`] when expanded
+/// and [`Expand`] otherwise.
+/// The widget should handle these actions in [`Widget::on_access_event`],
+/// and request an accessibility update when its state changes.
+///
+/// [`Expand`]: accesskit::Action::Expand
+/// [`Collapse`]: accesskit::Action::Collapse
+/// [`Widget::on_access_event`]: crate::core::Widget::on_access_event
+pub fn set_expanded(node: &mut Node, expanded: bool) {
+    node.set_expanded(expanded);
+    if expanded {
+        node.add_action(accesskit::Action::Collapse);
+    } else {
+        node.add_action(accesskit::Action::Expand);
+    }
+}
@@ -11,7 +11,7 @@
 use std::any::{Any, TypeId};
 use std::collections::hash_map::Entry;
 
-use accesskit::{NodeId, TreeUpdate};
+use accesskit::{Node, NodeId, TreeUpdate};
 use anymore::AnyDebug;
 use dpi::{LogicalPosition, PhysicalPosition};
 use parley::{FontContext, LayoutContext};
@@ -21,11 +21,12 @@ use tree_arena::{ArenaMut, ArenaMutList, ArenaRefList};
 use crate::app::{MutateCallback, RenderRootSignal, RenderRootState};
 use crate::core::{
     AllowRawMut, BrushIndex, ClassSet, ErasedAction, FromDynWidget, LayerType, NewWidget,
-    PaintLayerMode, PropertiesMut, PropertiesRef, PropertyArena, PropertyCache, PropertyStackId,
-    ResizeDirection, Widget, WidgetArenaNode, WidgetId, WidgetMut, WidgetPod, WidgetRef,
-    WidgetState,
+    Offscreen, PaintLayerMode, PropertiesMut, PropertiesRef, PropertyArena, PropertyCache,
+    PropertyStackId, ResizeDirection, StyleSet, Widget, WidgetArenaNode, WidgetId, WidgetMut,
+    WidgetPod, WidgetRef, WidgetState,
 };
-use crate::kurbo::{Affine, Axis, Insets, Point, Rect, Size, Vec2};
+use crate::imaging::Painter;
+use crate::kurbo::{Affine, Axis, Insets, Point, Rect, RoundedRect, Size, Vec2};
 use crate::layout::{LayoutSize, LenDef, Length, SizeDef};
 use crate::passes::layout::{place_widget, resolve_length, resolve_size, run_layout_on};
 use crate::peniko::Color;
@@ -256,6 +257,20 @@ impl QueryCtx<'_> {
     pub fn property_cache(&self) -> &PropertyCache {
         &self.widget_state.property_cache
     }
+
+    /// Returns how many times the `layout` method was called on this widget.
+    ///
+    /// This is a debugging aid, mostly useful to check that layout isn't re-run needlessly.
+    pub fn layout_count(&self) -> u32 {
+        self.widget_state.layout_count
+    }
+
+    /// Returns how many times the `paint` method was called on this widget.
+    ///
+    /// This is a debugging aid, mostly useful to check that paint isn't re-run needlessly.
+    pub fn paint_count(&self) -> u32 {
+        self.widget_state.paint_count
+    }
 }
 
 impl_context_method!(
@@ -388,6 +403,39 @@ impl<'w> QueryCtx<'w> {
     }
 }
 
+impl EventCtx<'_> {
+    /// Returns a [`WidgetRef`] to a child widget.
+    ///
+    /// This lets a container look at its descendants while handling an event,
+    /// e.g. to find which of them the focus should move to.
+    pub fn get(&self, child: WidgetId) -> WidgetRef<'_, dyn Widget> {
+        let child_node = self.children.item(child).expect("get: child not found");
+        let child_type_id = (*child_node.item.widget).type_id();
+        let child_stack = self
+            .property_arena
+            .get(child_node.item.state.property_stack_id, child_type_id);
+        let child_ctx = QueryCtx {
+            global_state: self.global_state,
+            widget_state: &child_node.item.state,
+            properties: PropertiesRef {
+                local: &child_node.item.properties,
+                default_map: self
+                    .property_arena
+                    .default_properties
+                    .for_widget(child_type_id),
+                stack: child_stack,
+                class_set: &child_node.item.class_set,
+            },
+            children: child_node.children,
+            property_arena: self.property_arena,
+        };
+        WidgetRef {
+            ctx: child_ctx,
+            widget: &*child_node.item.widget,
+        }
+    }
+}
+
 // Methods for all exclusive context types (i.e. those which have exclusive access to the global state).
 impl_context_method!(
     MutateCtx<'_>,
@@ -482,6 +530,18 @@ impl EventCtx<'_> {
         self.global_state.needs_pointer_pass = true;
     }
 
+    /// Returns the current velocity of the pointer, in logical pixels per second.
+    ///
+    /// This is estimated from the timestamps of recent pointer events, and is reset
+    /// on every [`Down`] event, which makes it suitable for fling and swipe gestures.
+    /// Only the pointer which sent the latest event is tracked: the velocity is also
+    /// reset when events start coming from another pointer, e.g. a second touch point.
+    ///
+    /// [`Down`]: ui_events::pointer::PointerEvent::Down
+    pub fn pointer_velocity(&self) -> Vec2 {
+        self.global_state.pointer_velocity.velocity()
+    }
+
     /// The widget originally targeted by the event.
     ///
     /// This will be different from [`widget_id`](Self::widget_id) during event bubbling.
@@ -589,6 +649,43 @@ impl AccessCtx<'_> {
         // TODO - Return from a pool disjoint from widget ids.
         WidgetId::next().into()
     }
+
+    /// Reports the number of rows and columns of a table or grid on the widget's accessibility `node`.
+    ///
+    /// The `node` should have a table role, e.g. [`Role::Table`] or [`Role::Grid`].
+    /// Its cells report their coordinates with [`set_table_cell`](Self::set_table_cell).
+    ///
+    /// [`Role::Table`]: accesskit::Role::Table
+    /// [`Role::Grid`]: accesskit::Role::Grid
+    pub fn set_table_dimensions(&mut self, node: &mut Node, rows: usize, columns: usize) {
+        node.set_row_count(rows);
+        node.set_column_count(columns);
+    }
+
+    /// Reports the coordinates of a table cell on its accessibility `node`.
+    ///
+    /// `row` and `column` are the zero-based indices of the cell's top-left corner,
+    /// and `row_span` and `column_span` are the number of rows and columns it covers.
+    ///
+    /// The `node` should have a cell role, e.g. [`Role::Cell`], or [`Role::ColumnHeader`]
+    /// for header cells, and belong to a node set up with
+    /// [`set_table_dimensions`](Self::set_table_dimensions).
+    ///
+    /// [`Role::Cell`]: accesskit::Role::Cell
+    /// [`Role::ColumnHeader`]: accesskit::Role::ColumnHeader
+    pub fn set_table_cell(
+        &mut self,
+        node: &mut Node,
+        row: usize,
+        column: usize,
+        row_span: usize,
+        column_span: usize,
+    ) {
+        node.set_row_index(row);
+        node.set_column_index(column);
+        node.set_row_span(row_span);
+        node.set_column_span(column_span);
+    }
 }
 
 // --- MARK: COMPUTE LENGTH
@@ -644,6 +741,28 @@ impl_context_method!(MeasureCtx<'_>, LayoutCtx<'_>, {
             cross_length,
         )
     }
+
+    /// Computes the size of `text` laid out with the given `styles`.
+    ///
+    /// This is meant for widgets which draw text themselves, e.g. the axis labels of a chart,
+    /// so they can size themselves without embedding a label widget per string.
+    /// The text is laid out the same way as `masonry::widgets::Label` lays out its text.
+    ///
+    /// If `max_width` is `Some`, the text is wrapped to fit in that width, where possible.
+    /// The returned size is in logical pixels.
+    ///
+    /// Note that the layout is not cached: widgets which paint the text
+    /// should keep their own [`Layout`](parley::Layout) instead.
+    pub fn measure_text(&mut self, text: &str, styles: &StyleSet, max_width: Option<f64>) -> Size {
+        let (font_ctx, layout_ctx) = self.text_contexts();
+        let mut builder = layout_ctx.ranged_builder(font_ctx, text, 1.0, true);
+        for prop in styles.inner().values() {
+            builder.push_default(prop.to_owned());
+        }
+        let mut layout = builder.build(text);
+        layout.break_all_lines(max_width.map(|width| width as f32));
+        Size::new(layout.width().into(), layout.height().into())
+    }
 });
 
 // --- MARK: MEASURE
@@ -1088,6 +1207,7 @@ impl LayoutCtx<'_> {
         // 2) An easy potential source of items not being visible when expected
         trace!("set_clip_path {path:?}");
         self.widget_state.clip_path = Some(path);
+        self.widget_state.clip_radii = None;
         // TODO - Updating the clip path may have
         // other knock-on effects we'd need to document.
         self.widget_state.request_accessibility = true;
@@ -1095,18 +1215,39 @@ impl LayoutCtx<'_> {
         self.widget_state.needs_paint = true;
     }
 
+    /// Sets the widget's clip path to a rounded rectangle in the widget's content-box coordinate space.
+    ///
+    /// This works like [`set_clip_path`](Self::set_clip_path), except that painting
+    /// is also clipped at the rounded corners.
+    /// Pointer events are still only tested against the rectangle.
+    pub fn set_rounded_clip_path(&mut self, path: RoundedRect) {
+        self.set_clip_path(path.rect());
+        self.widget_state.clip_radii = Some(path.radii());
+    }
+
     /// Removes the widget's clip path.
     ///
     /// See [`LayoutCtx::set_clip_path`] for details.
     pub fn clear_clip_path(&mut self) {
         trace!("clear_clip_path");
         self.widget_state.clip_path = None;
+        self.widget_state.clip_radii = None;
         // TODO - Updating the clip path may have
         // other knock-on effects we'd need to document.
         self.widget_state.request_accessibility = true;
         self.widget_state.needs_accessibility = true;
         self.widget_state.needs_paint = true;
     }
+
+    /// Requests an animation frame.
+    ///
+    /// This is useful for widgets that start animating because of their new size,
+    /// see [`UpdateCtx::request_anim_frame`] for details.
+    pub fn request_anim_frame(&mut self) {
+        trace!("request_anim_frame");
+        self.widget_state.request_anim = true;
+        self.widget_state.needs_anim = true;
+    }
 }
 
 impl ComposeCtx<'_> {
@@ -1177,6 +1318,35 @@ impl ComposeCtx<'_> {
             child.needs_compose = true;
         }
     }
+
+    /// Sets the local transform for the child widget.
+    ///
+    /// This replaces the transform the child was created with or set on itself,
+    /// and is meant for containers which own the transform of their children, like zoomable canvases.
+    /// The scroll translation and the position from [`LayoutCtx::place_child`]
+    /// are applied on top of it.
+    pub fn set_child_transform(
+        &mut self,
+        child: &mut WidgetPod<impl Widget + ?Sized>,
+        transform: Affine,
+    ) {
+        if !transform.is_finite() {
+            debug_panic!(
+                "Error in {}: trying to call 'set_child_transform' with child '{}' {} with invalid transform {:?}",
+                self.widget_id(),
+                self.get_child_dyn(child).short_type_name(),
+                child.id(),
+                transform,
+            );
+        }
+
+        let child = self.get_child_state_mut(child);
+        if transform != child.transform {
+            child.transform = transform;
+            child.transform_changed = true;
+            child.needs_compose = true;
+        }
+    }
 }
 
 // --- MARK: GET GEOMETRY
@@ -1459,6 +1629,13 @@ impl_context_method!(
             self.widget_state.is_stashed
         }
 
+        /// The opacity set with [`set_opacity`](UpdateCtx::set_opacity).
+        ///
+        /// This doesn't include the opacity of ancestors.
+        pub fn opacity(&self) -> f32 {
+            self.widget_state.opacity
+        }
+
         /// Returns whether the given child is [stashed].
         ///
         /// The result of this function is not affected by `set_stashed` until the next
@@ -1561,6 +1738,13 @@ impl_context_method!(
         }
 
         /// Requests an animation frame.
+        ///
+        /// The widget's [`on_anim_frame`] method will be called once, on the next frame.
+        /// The request is cleared right before that call, so a widget that wants to keep animating
+        /// must call this method again from [`on_anim_frame`].
+        /// Once no widget has an outstanding request, the app stops scheduling frames.
+        ///
+        /// [`on_anim_frame`]: crate::core::Widget::on_anim_frame
         pub fn request_anim_frame(&mut self) {
             trace!("request_anim_frame");
             self.widget_state.request_anim = true;
@@ -1632,6 +1816,12 @@ impl_context_method!(
                 }
             }
 
+            // A child that was added in the same mutation was never inserted in the arena.
+            if child.incomplete() {
+                self.children_changed();
+                return;
+            }
+
             let id = child.id();
             let node = self
                 .children
@@ -1667,6 +1857,21 @@ impl_context_method!(
             self.widget_state.needs_compose = true;
         }
 
+        /// Sets the opacity of this widget and all its descendants.
+        ///
+        /// The value is clamped to the `0.0..=1.0` range.
+        /// The widget's paint output is reused as-is, so this is cheap to animate.
+        ///
+        /// Opacity is ignored for widgets which are painted in their own visual layer,
+        /// and doesn't apply to descendants painted in their own visual layer.
+        pub fn set_opacity(&mut self, opacity: f32) {
+            let opacity = opacity.clamp(0., 1.);
+            if self.widget_state.opacity != opacity {
+                self.widget_state.opacity = opacity;
+                self.widget_state.needs_paint = true;
+            }
+        }
+
         /// Adds a string to this widget's [class set].
         ///
         /// Changes will be applied in the next update pass and may affect property resolution.
@@ -2180,4 +2385,15 @@ impl PaintCtx<'_> {
     pub fn debug_color(&self) -> Color {
         get_debug_color(self.widget_id().to_raw())
     }
+
+    /// Paints content offscreen, so that it can be drawn with a filter.
+    ///
+    /// The painter passed to `paint` uses the same coordinate space as [`Widget::paint`].
+    /// Only what `paint` draws is captured: children of this widget are painted as usual.
+    ///
+    /// The returned [`Offscreen`] is then drawn with [`Offscreen::draw`],
+    /// clipped to this widget's [border box](Self::border_box).
+    pub fn with_offscreen(&self, paint: impl FnOnce(&mut Painter<'_>)) -> Offscreen {
+        Offscreen::record(self.border_box(), paint)
+    }
 }
@@ -5,6 +5,7 @@
 
 use kurbo::Rect;
 use ui_events::keyboard::{Code, Key, KeyState, KeyboardEvent};
+use ui_events::pointer::PointerEvent;
 
 use crate::dpi::PhysicalSize;
 use crate::util::Duration;
@@ -40,6 +41,19 @@ pub enum TextEvent {
     ClipboardPaste(String),
 }
 
+/// An input event handled by a [`RenderRoot`](crate::app::RenderRoot).
+///
+/// See [`RenderRoot::pending_input_events`](crate::app::RenderRoot::pending_input_events).
+#[derive(Debug, Clone)]
+pub enum InputEvent {
+    /// A pointer event.
+    Pointer(PointerEvent),
+    /// A text event.
+    Text(TextEvent),
+    /// An accesskit event.
+    Access(accesskit::ActionRequest),
+}
+
 /// An accessibility event.
 ///
 /// This is generated by `accesskit` back-ends when the user interacts with
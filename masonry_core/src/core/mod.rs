@@ -3,11 +3,13 @@
 
 //! Basic types and traits Masonry is built on.
 
+mod accessibility;
 mod class_set;
 mod contexts;
 mod default_properties;
 mod events;
 mod layer;
+mod offscreen;
 mod paint_layer;
 mod properties_mut;
 mod properties_ref;
@@ -18,6 +20,7 @@ mod property_set;
 mod property_stack;
 mod selector;
 mod text;
+mod velocity_tracker;
 mod widget;
 mod widget_arena;
 mod widget_mut;
@@ -27,10 +30,12 @@ mod widget_ref;
 mod widget_state;
 mod widget_tag;
 
+pub use accessibility::*;
 pub use contexts::*;
 pub use default_properties::*;
 pub use events::*;
 pub use layer::*;
+pub use offscreen::*;
 pub use paint_layer::*;
 pub use properties_mut::*;
 pub use properties_ref::*;
@@ -41,6 +46,7 @@ pub use property_set::*;
 pub use property_stack::*;
 pub use selector::*;
 pub use text::*;
+pub use velocity_tracker::*;
 pub use widget::*;
 pub use widget_mut::*;
 pub use widget_paint::*;
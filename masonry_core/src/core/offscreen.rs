@@ -0,0 +1,77 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::imaging::record::Scene;
+use crate::imaging::{Composite, GroupRef, Painter};
+use crate::kurbo::Rect;
+use crate::peniko::{BlendMode, Color, Compose, Mix};
+
+/// Content painted offscreen by [`PaintCtx::with_offscreen`], to be composited with a filter.
+///
+/// The content is recorded rather than rasterized.
+/// When drawn, it's replayed inside an isolated group, so the filter applies to the content
+/// as a whole rather than to each of its shapes.
+///
+/// Only filters which can be expressed by compositing are supported, see [`OffscreenFilter`].
+/// Effects which need the rasterized content, like a blur, aren't supported.
+///
+/// [`PaintCtx::with_offscreen`]: crate::core::PaintCtx::with_offscreen
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Offscreen {
+    scene: Scene,
+    /// The border box of the widget which painted the content.
+    bounds: Rect,
+}
+
+/// An effect applied to [`Offscreen`] content when it's drawn.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OffscreenFilter {
+    /// Draws the content unchanged.
+    None,
+    /// Multiplies the content's alpha by the given value, between `0.0` and `1.0`.
+    Opacity(f32),
+    /// Multiplies the content's colors by the given color.
+    ///
+    /// Transparent parts of the content stay transparent.
+    Tint(Color),
+    /// Blends the content with what was painted below it.
+    Blend(BlendMode),
+}
+
+impl Offscreen {
+    /// Paints content offscreen, to be drawn within `bounds`.
+    pub(crate) fn record(bounds: Rect, paint: impl FnOnce(&mut Painter<'_>)) -> Self {
+        let mut scene = Scene::new();
+        paint(&mut Painter::new(&mut scene));
+        Self { scene, bounds }
+    }
+
+    /// Returns `true` if nothing was painted.
+    pub fn is_empty(&self) -> bool {
+        self.scene == Scene::new()
+    }
+
+    /// Draws the content with the given filter,
+    /// clipped to the border box of the widget which painted it.
+    pub fn draw(&self, painter: &mut Painter<'_>, filter: OffscreenFilter) {
+        let bounds = self.bounds;
+        let composite = match filter {
+            OffscreenFilter::Opacity(alpha) => Composite::new(BlendMode::default(), alpha),
+            OffscreenFilter::Blend(blend) => Composite::new(blend, 1.),
+            OffscreenFilter::None | OffscreenFilter::Tint(_) => Composite::default(),
+        };
+
+        painter.push_fill_clip(bounds);
+        painter.push_group(GroupRef::new().with_composite(composite));
+        painter.replay(&self.scene);
+        if let OffscreenFilter::Tint(color) = filter {
+            // Source-atop keeps the tint within the content's own coverage.
+            let tint = BlendMode::new(Mix::Multiply, Compose::SrcAtop);
+            painter.push_group(GroupRef::new().with_composite(Composite::new(tint, 1.)));
+            painter.fill(bounds, color).draw();
+            painter.pop_group();
+        }
+        painter.pop_group();
+        painter.pop_clip();
+    }
+}
@@ -50,7 +50,28 @@ pub fn render_text(
     // TODO: Should this be part of `BrushIndex` (i.e. `brushes`)?
     hint: bool,
 ) {
-    for line in layout.lines() {
+    render_text_with_line_offsets(painter, transform, layout, &[], brushes, hint);
+}
+
+/// A function that renders laid out glyphs through imaging's [`Painter`],
+/// moving each line horizontally by the matching entry of `line_offsets`.
+///
+/// Lines without a matching entry aren't moved.
+/// This is used to position lines differently from how Parley aligned them,
+/// e.g. for paragraphs with their own text direction.
+///
+/// The `BrushIndex` values of the runs are indices into `brushes`.
+pub fn render_text_with_line_offsets(
+    painter: &mut Painter<'_, impl PaintSink + ?Sized>,
+    transform: Affine,
+    layout: &Layout<BrushIndex>,
+    line_offsets: &[f32],
+    brushes: &[Brush],
+    hint: bool,
+) {
+    for (line_idx, line) in layout.lines().enumerate() {
+        let line_offset = line_offsets.get(line_idx).copied().unwrap_or(0.);
+        let transform = transform * Affine::translate((f64::from(line_offset), 0.));
         for item in line.items() {
             let PositionedLayoutItem::GlyphRun(glyph_run) = item else {
                 continue;
@@ -0,0 +1,88 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::VecDeque;
+
+use crate::kurbo::{Point, Vec2};
+
+/// Estimates pointer velocity from recent timestamped positions.
+///
+/// Masonry keeps one tracker for the latest pointer, which widgets can query
+/// through [`EventCtx::pointer_velocity`](crate::core::EventCtx::pointer_velocity).
+/// It is also usable on its own, e.g. by widgets tracking several touch points.
+///
+/// Only samples from the last [`WINDOW`](Self::WINDOW) nanoseconds are taken into account.
+#[derive(Clone, Debug, Default)]
+pub struct VelocityTracker {
+    samples: VecDeque<(u64, Point)>,
+}
+
+impl VelocityTracker {
+    /// How far back samples are kept, in nanoseconds.
+    pub const WINDOW: u64 = 100_000_000;
+
+    /// Creates an empty tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forgets all previous samples.
+    pub fn reset(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Records that the pointer was at `pos` at `time`, in nanoseconds.
+    ///
+    /// If `time` is earlier than the last sample, the tracker is reset first.
+    pub fn add_sample(&mut self, time: u64, pos: Point) {
+        if self.samples.back().is_some_and(|(last, _)| *last > time) {
+            self.reset();
+        }
+        self.samples.push_back((time, pos));
+        while self
+            .samples
+            .front()
+            .is_some_and(|(first, _)| time - first > Self::WINDOW)
+        {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Returns the estimated velocity, in pixels per second.
+    ///
+    /// Returns zero if there are fewer than two samples in the window,
+    /// or if they all share the same timestamp.
+    pub fn velocity(&self) -> Vec2 {
+        let (Some((t0, p0)), Some((t1, p1))) = (self.samples.front(), self.samples.back()) else {
+            return Vec2::ZERO;
+        };
+        if t1 <= t0 {
+            return Vec2::ZERO;
+        }
+        let secs = (t1 - t0) as f64 / 1e9;
+        (*p1 - *p0) / secs
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn old_samples_are_dropped() {
+        let mut tracker = VelocityTracker::new();
+        tracker.add_sample(0, Point::new(0., 0.));
+        assert_eq!(tracker.velocity(), Vec2::ZERO);
+
+        // Far outside the window: only the two last samples count.
+        tracker.add_sample(10_000_000, Point::new(500., 0.));
+        tracker.add_sample(500_000_000, Point::new(0., 0.));
+        tracker.add_sample(550_000_000, Point::new(0., 50.));
+        assert_eq!(tracker.velocity(), Vec2::new(0., 1000.));
+
+        // Going back in time resets the tracker.
+        tracker.add_sample(0, Point::new(0., 0.));
+        assert_eq!(tracker.velocity(), Vec2::ZERO);
+    }
+}
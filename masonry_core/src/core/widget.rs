@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::any::{Any, TypeId};
+use std::cell::Cell;
 use std::fmt::{Debug, Display};
 use std::num::NonZeroU64;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -176,6 +177,11 @@ pub trait Widget: AsDynWidget + Any {
     /// Handles an event from the platform's accessibility API.
     ///
     /// Accessibility events target a specific widget id, then bubble to each parent.
+    ///
+    /// Widgets with a context menu should open it on [`ShowContextMenu`] actions.
+    /// See [`declare_context_menu`](crate::core::declare_context_menu).
+    ///
+    /// [`ShowContextMenu`]: accesskit::Action::ShowContextMenu
     fn on_access_event(
         &mut self,
         ctx: &mut EventCtx<'_>,
@@ -242,6 +248,21 @@ pub trait Widget: AsDynWidget + Any {
     /// changes in the widget graph or in the state of your specific widget.
     fn update(&mut self, ctx: &mut UpdateCtx<'_>, props: &mut PropertiesMut<'_>, event: &Update) {}
 
+    /// Handles the widget gaining or losing [focus].
+    ///
+    /// This is called with `true` when the widget gains focus and `false` when it loses it,
+    /// right after the matching [`Update::FocusChanged`] is sent.
+    /// It's only called on transitions: focusing an already focused widget doesn't call it.
+    ///
+    /// [focus]: crate::doc::masonry_concepts#text-focus
+    fn on_focus_change(
+        &mut self,
+        ctx: &mut UpdateCtx<'_>,
+        props: &mut PropertiesMut<'_>,
+        focused: bool,
+    ) {
+    }
+
     // TODO - Remove default implementation
     /// Handles a property being added, changed, or removed.
     fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {}
@@ -442,6 +463,29 @@ pub trait Widget: AsDynWidget + Any {
     /// responsible for visiting all their children during `layout` and `register_children`.
     fn children_ids(&self) -> ChildrenIds;
 
+    /// Returns ids of this widget's children in reading order, for accessibility.
+    ///
+    /// By default this is the same as [`children_ids`](Self::children_ids).
+    /// Widgets whose reading order differs from the order of their children
+    /// can override it.
+    ///
+    /// The returned list must contain the same ids as `children_ids`, in any order.
+    fn accessibility_children_ids(&self) -> ChildrenIds {
+        self.children_ids()
+    }
+
+    /// Returns ids of this widget's children in the order they are painted.
+    ///
+    /// Later children are painted on top of earlier ones, and are hit-tested first.
+    /// By default this is the same as [`children_ids`](Self::children_ids).
+    /// Widgets that stack their children differently from their order, e.g. by a z-index,
+    /// can override it without changing focus or accessibility order.
+    ///
+    /// The returned list must contain the same ids as `children_ids`, in any order.
+    fn paint_order_ids(&self) -> ChildrenIds {
+        self.children_ids()
+    }
+
     /// Return `Some(self)` if the widget also implements [`Layer`].
     ///
     /// Default implementation returns `None`.
@@ -598,9 +642,9 @@ pub fn find_widget_under_pointer<'c>(
 
     // TEST CHILDREN
     if ctx.propagates_pointer_interaction() {
-        // Assumes `Self::children_ids` is in increasing "z-order", picking the last child in case
+        // Assumes `Self::paint_order_ids` is in increasing "z-order", picking the last child in case
         // of overlapping children.
-        for child_id in widget.children_ids().iter().rev() {
+        for child_id in widget.paint_order_ids().iter().rev() {
             let child_ref = ctx.get(*child_id);
             if let Some(child) = child_ref
                 .widget
@@ -638,6 +682,16 @@ pub fn find_widget_under_pointer<'c>(
 /// internal implementation detail of public widgets.
 pub trait AllowRawMut: Widget {}
 
+/// The first id allocated inside [`WidgetId::with_deterministic_ids`].
+///
+/// High enough that the global counter never reaches it.
+const DETERMINISTIC_ID_START: u64 = 1 << 48;
+
+thread_local! {
+    /// The next id to allocate, if this thread is inside [`WidgetId::with_deterministic_ids`].
+    static DETERMINISTIC_ID_COUNTER: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
 impl WidgetId {
     /// Allocates a new, unique `WidgetId`.
     ///
@@ -648,11 +702,47 @@ impl WidgetId {
     /// You must ensure that a given `WidgetId` is only ever used for one
     /// widget at a time.
     pub(crate) fn next() -> Self {
+        let scoped_id = DETERMINISTIC_ID_COUNTER.with(|counter| {
+            let id = counter.get()?;
+            counter.set(Some(id + 1));
+            Some(id)
+        });
+        if let Some(id) = scoped_id {
+            return Self(id.try_into().unwrap());
+        }
+
         static WIDGET_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
         let id = WIDGET_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
         Self(id.try_into().unwrap())
     }
 
+    /// Runs `f`, allocating the ids of widgets created on this thread from a fresh counter.
+    ///
+    /// Ids allocated inside `f` only depend on the order in which widgets are created,
+    /// so building the same tree in two separate calls assigns the same ids.
+    /// This is meant for tests which need reproducible ids, e.g. in snapshots.
+    ///
+    /// Ids allocated this way never collide with the ones allocated outside of this function,
+    /// but they do collide with the ones allocated in other calls.
+    /// Widgets created in separate calls must not be added to the same widget tree.
+    ///
+    /// This only exists to implement `TestHarness::create_with_deterministic_ids`,
+    /// which tests should use instead.
+    #[doc(hidden)]
+    pub fn with_deterministic_ids<R>(f: impl FnOnce() -> R) -> R {
+        /// Restores the previous counter, even if `f` panics.
+        struct Restore(Option<u64>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                DETERMINISTIC_ID_COUNTER.set(self.0);
+            }
+        }
+
+        let _restore = Restore(DETERMINISTIC_ID_COUNTER.replace(Some(DETERMINISTIC_ID_START)));
+        f()
+    }
+
     /// Returns the integer value of the `WidgetId`.
     pub fn to_raw(self) -> u64 {
         self.0.into()
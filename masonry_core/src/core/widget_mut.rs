@@ -19,6 +19,13 @@ use crate::properties::core_property_changed;
 /// You can create a `WidgetMut` from [`RenderRoot`], [`EventCtx`], [`UpdateCtx`],
 /// or from a parent `WidgetMut` with [`MutateCtx`].
 ///
+/// # Batching
+///
+/// Invalidations requested through a `WidgetMut` (e.g. with [`MutateCtx::request_layout`])
+/// only set flags on the widget.
+/// The passes which act on them run once the outermost mutation is done, so making many
+/// edits in a single callback is no more expensive than making one.
+///
 /// # `WidgetMut` as a Receiver
 ///
 /// Once the Receiver trait is stabilized, `WidgetMut` will implement it so that custom
@@ -101,6 +108,20 @@ impl<W: Widget + ?Sized> WidgetMut<'_, W> {
         value
     }
 
+    /// Edits property `P` in place, starting from its current value.
+    ///
+    /// The current value is resolved like in [`get_prop`](Self::get_prop),
+    /// so it may come from the default properties.
+    /// The edited value is then set locally, like with [`insert_prop`](Self::insert_prop).
+    ///
+    /// This is useful for generic code which only wants to change part of a property,
+    /// e.g. a single edge of [`Padding`](crate::properties::Padding).
+    pub fn edit_prop<P: Property>(&mut self, f: impl FnOnce(&mut P)) {
+        let mut value = self.get_prop::<P>().clone();
+        f(&mut value);
+        self.insert_prop(value);
+    }
+
     /// Removes property `T`. Returns the previous value if `T` was set locally.
     ///
     /// Does not affect default properties.
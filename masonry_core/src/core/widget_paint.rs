@@ -3,14 +3,19 @@
 
 use crate::core::{PaintCtx, PropertiesRef, PropertyCache};
 use crate::imaging::Painter;
-use crate::kurbo::{Affine, Join, Rect, Stroke};
-use crate::properties::{Background, BorderColor, BorderWidth, BoxShadow, CornerRadius};
+use crate::kurbo::{Affine, Join, Rect, Shape as _, Stroke};
+use crate::layout::Length;
+use crate::properties::{
+    Background, BorderColor, BorderWidth, BoxShadow, BoxShadows, CornerRadius,
+};
 
 /// References to common pre-paint properties.
 #[derive(Debug)]
 pub struct PrePaintProps<'a> {
     /// Box shadow.
     pub box_shadow: &'a BoxShadow,
+    /// Additional box shadows, painted after `box_shadow`.
+    pub box_shadows: &'a BoxShadows,
     /// Background.
     ///
     /// Considers disabled and active state.
@@ -29,6 +34,7 @@ impl<'a> PrePaintProps<'a> {
     /// Returns common pre-paint properties based on widget state.
     pub fn fetch(props: &'a PropertiesRef<'_>, cache: &mut PropertyCache) -> Self {
         let box_shadow = props.get::<BoxShadow>(cache);
+        let box_shadows = props.get::<BoxShadows>(cache);
         let background = props.get::<Background>(cache);
         let border_color = props.get::<BorderColor>(cache);
         let border_width = props.get::<BorderWidth>(cache);
@@ -36,6 +42,7 @@ impl<'a> PrePaintProps<'a> {
 
         Self {
             box_shadow,
+            box_shadows,
             background,
             border_width,
             border_color,
@@ -50,8 +57,9 @@ pub fn pre_paint(ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, painter: &mu
     let cache = ctx.property_cache();
     let p = PrePaintProps::fetch(props, cache);
 
-    paint_box_shadow(painter, bbox, p.box_shadow, p.corner_radius);
+    paint_box_shadow(painter, bbox, p.box_shadow, p.box_shadows, p.corner_radius);
     paint_background(painter, bbox, p.background, p.border_width, p.corner_radius);
+    paint_inset_shadow(painter, bbox, p.box_shadow, p.box_shadows, p.corner_radius);
     paint_border(
         painter,
         bbox,
@@ -61,18 +69,64 @@ pub fn pre_paint(ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, painter: &mu
     );
 }
 
-/// Paints the widget's box shadow.
+/// Paints the widget's drop shadows.
+///
+/// This paints `box_shadow` then `box_shadows`, skipping inset shadows.
+/// It should be painted before the background.
+/// See [`paint_inset_shadow`] for the other half.
 pub fn paint_box_shadow(
     painter: &mut Painter<'_>,
     border_box: Rect,
     box_shadow: &BoxShadow,
+    box_shadows: &BoxShadows,
     corner_radius: &CornerRadius,
 ) {
-    if !box_shadow.is_visible() {
-        return;
+    paint_shadows(
+        painter,
+        border_box,
+        box_shadow,
+        box_shadows,
+        corner_radius,
+        false,
+    );
+}
+
+/// Paints the widget's inset shadows.
+///
+/// This paints `box_shadow` then `box_shadows`, skipping drop shadows.
+/// It should be painted after the background.
+/// See [`paint_box_shadow`] for the other half.
+pub fn paint_inset_shadow(
+    painter: &mut Painter<'_>,
+    border_box: Rect,
+    box_shadow: &BoxShadow,
+    box_shadows: &BoxShadows,
+    corner_radius: &CornerRadius,
+) {
+    paint_shadows(
+        painter,
+        border_box,
+        box_shadow,
+        box_shadows,
+        corner_radius,
+        true,
+    );
+}
+
+fn paint_shadows(
+    painter: &mut Painter<'_>,
+    border_box: Rect,
+    box_shadow: &BoxShadow,
+    box_shadows: &BoxShadows,
+    corner_radius: &CornerRadius,
+    inset: bool,
+) {
+    let shadow_rect = border_box.to_rounded_rect(corner_radius.radius.get());
+    for shadow in std::iter::once(box_shadow).chain(&box_shadows.0) {
+        if shadow.inset == inset && shadow.is_visible() {
+            shadow.paint(painter, Affine::IDENTITY, shadow_rect);
+        }
     }
-    let box_shadow_rect = border_box.to_rounded_rect(corner_radius.radius.get());
-    box_shadow.paint(painter, Affine::IDENTITY, box_shadow_rect);
 }
 
 /// Paints the widget's background.
@@ -95,6 +149,9 @@ pub fn paint_background(
 }
 
 /// Paints the widget's border.
+///
+/// A [uniform](BorderWidth::is_uniform) border is stroked around the box.
+/// Otherwise each edge is filled with its own width, within the box's rounded corners.
 pub fn paint_border(
     painter: &mut Painter<'_>,
     border_box: Rect,
@@ -102,10 +159,31 @@ pub fn paint_border(
     border_width: &BorderWidth,
     corner_radius: &CornerRadius,
 ) {
-    let border_width_value = border_width.width.get();
-    if border_width_value == 0. || !border_color.is_visible() {
+    if border_width.max_width() == Length::ZERO || !border_color.is_visible() {
+        return;
+    }
+    if !border_width.is_uniform() {
+        let edges = [
+            border_box.with_size((border_box.width(), border_width.top.get())),
+            border_box
+                .with_origin((border_box.x1 - border_width.right.get(), border_box.y0))
+                .with_size((border_width.right.get(), border_box.height())),
+            border_box
+                .with_origin((border_box.x0, border_box.y1 - border_width.bottom.get()))
+                .with_size((border_box.width(), border_width.bottom.get())),
+            border_box.with_size((border_width.left.get(), border_box.height())),
+        ];
+        let clip = border_box
+            .to_rounded_rect(corner_radius.radius.get())
+            .to_path(0.1);
+        painter.with_fill_clip_transformed(clip, Affine::IDENTITY, |painter| {
+            for edge in edges.into_iter().filter(|edge| edge.area() > 0.) {
+                painter.fill(edge, border_color.color).draw();
+            }
+        });
         return;
     }
+    let border_width_value = border_width.left.get();
     let border_rect = border_width.border_rect(border_box, corner_radius);
     // Using Join::Miter avoids rounding corners when a widget has a wide border.
     let border_style = Stroke {
@@ -6,6 +6,7 @@ use std::{any::TypeId, collections::HashSet};
 use kurbo::Affine;
 
 use crate::core::{PropertySet, PropertyStackId, Widget, WidgetId, WidgetTag, WidgetTagInner};
+use crate::layout::UnitPoint;
 
 /// A container for one widget in the hierarchy.
 ///
@@ -78,15 +79,37 @@ impl<W: Widget + ?Sized> std::fmt::Debug for NewWidget<W> {
 
 // TODO - Remove this and merge it into NewWidget?
 /// The options a new widget will be created with.
-#[derive(Default, Debug)]
+#[derive(Debug)]
 pub struct WidgetOptions {
     /// Local transform used during the mapping of this widget's border-box coordinate space
     /// to the parent's border-box coordinate space.
     ///
     /// This transform is applied before `scroll_translation` and `origin`.
     pub transform: Affine,
+    /// The point of the widget's border-box around which `transform` is applied.
+    ///
+    /// Rotations and scales pivot around this point.
+    /// Defaults to [`UnitPoint::TOP_LEFT`].
+    pub transform_origin: UnitPoint,
     /// The disabled state the widget will be created with.
     pub disabled: bool,
+    /// Whether the widget can be reached by tab navigation.
+    ///
+    /// If `false`, the widget (but not its descendants) is skipped when moving focus
+    /// with the Tab key, but can still be focused by pointer or programmatically.
+    /// Defaults to `true`.
+    pub tab_stop: bool,
+}
+
+impl Default for WidgetOptions {
+    fn default() -> Self {
+        Self {
+            transform: Affine::IDENTITY,
+            transform_origin: UnitPoint::TOP_LEFT,
+            disabled: false,
+            tab_stop: true,
+        }
+    }
 }
 
 // TODO - This is a simple state machine that lets users create WidgetPods
@@ -178,6 +201,14 @@ impl<W: Widget + ?Sized> NewWidget<W> {
         self
     }
 
+    /// Sets the point around which this widget's transform is applied.
+    ///
+    /// See [`WidgetOptions::transform_origin`].
+    pub fn with_transform_origin(mut self, origin: UnitPoint) -> Self {
+        self.options.transform_origin = origin;
+        self
+    }
+
     /// Assigns a [`PropertyStack`](crate::core::PropertyStack) to this widget.
     pub fn with_property_stack(mut self, id: PropertyStackId) -> Self {
         self.property_stack_id = Some(id);
@@ -208,6 +239,14 @@ impl<W: Widget + ?Sized> NewWidget<W> {
         self
     }
 
+    /// Set whether the widget can be reached by tab navigation.
+    ///
+    /// See [`WidgetOptions::tab_stop`] for details.
+    pub fn with_tab_stop(mut self, tab_stop: bool) -> Self {
+        self.options.tab_stop = tab_stop;
+        self
+    }
+
     /// Creates a `WidgetPod` which will be added to the widget tree.
     pub fn to_pod(self) -> WidgetPod<W> {
         WidgetPod {
@@ -242,6 +281,17 @@ impl<W: Widget + ?Sized> WidgetPod<W> {
         }
     }
 
+    /// Returns a mutable reference to the widget, if it hasn't been added to the tree yet.
+    ///
+    /// This lets builder methods of container widgets modify a child they have already
+    /// wrapped in a pod.
+    pub fn pending_widget_mut(&mut self) -> Option<&mut W> {
+        match &mut self.inner {
+            WidgetPodInner::Create(new_widget) => Some(&mut new_widget.widget),
+            WidgetPodInner::Inserted => None,
+        }
+    }
+
     /// Returns the id of the widget.
     pub fn id(&self) -> WidgetId {
         self.id
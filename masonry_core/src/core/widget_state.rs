@@ -3,13 +3,13 @@
 
 use std::any::TypeId;
 
-use kurbo::{Affine, Insets, Point, Rect, Size, Vec2};
+use kurbo::{Affine, Insets, Point, Rect, RoundedRectRadii, Size, Vec2};
 use tracing::Span;
 
 use crate::core::{
     ClassSetDiff, PaintLayerMode, PropertyCache, PropertyStackId, WidgetId, WidgetOptions,
 };
-use crate::layout::MeasurementCache;
+use crate::layout::{MeasurementCache, UnitPoint};
 
 // TODO - Reduce WidgetState size.
 // See https://github.com/linebender/xilem/issues/706
@@ -134,12 +134,18 @@ pub(crate) struct WidgetState {
     /// This clips the painting of `Widget::paint` and all the painting of children.
     /// It does not clip this widget's `Widget::pre_paint` nor `Widget::post_paint`.
     pub(crate) clip_path: Option<Rect>,
+    /// The corner radii of the clip path, if it's a rounded rectangle.
+    pub(crate) clip_radii: Option<RoundedRectRadii>,
+    /// The opacity of this widget and all its descendants, from `0.0` to `1.0`.
+    pub(crate) opacity: f32,
 
     /// Local transform used during the mapping of this widget's border-box coordinate space
     /// to the parent's border-box coordinate space.
     ///
     /// This transform is applied before `scroll_translation` and `origin`.
     pub(crate) transform: Affine,
+    /// The point of the border-box around which `transform` is applied.
+    pub(crate) transform_origin: UnitPoint,
     /// Global transform mapping this widget's border-box coordinate space
     /// to the window's coordinate space.
     ///
@@ -168,6 +174,9 @@ pub(crate) struct WidgetState {
     /// Tracks whether widget gets text focus.
     /// Should be immutable after `WidgetAdded` event.
     pub(crate) accepts_focus: bool,
+    /// Whether tab navigation can focus this widget.
+    /// See [`WidgetOptions::tab_stop`](crate::core::WidgetOptions::tab_stop).
+    pub(crate) is_tab_stop: bool,
 
     /// Tracks whether widget is eligible for IME events.
     /// Should be immutable after `WidgetAdded` event.
@@ -221,7 +230,7 @@ pub(crate) struct WidgetState {
     /// This widget or a descendant changed its `is_explicitly_stashed` value
     pub(crate) needs_update_stashed: bool,
 
-    /// This widget or a descendant has `accepts_focus == true`
+    /// This widget or a descendant has `accepts_focus == true` and is a tab stop
     pub(crate) descendant_is_focusable: bool,
     /// A focusable widget was added, removed, stashed, disabled, etc.
     pub(crate) needs_update_focusable: bool,
@@ -274,6 +283,10 @@ pub(crate) struct WidgetState {
     /// Used in some guard rails to provide richer error messages when a parent forgets
     /// to iterate over some children.
     pub(crate) trace_span: Span,
+    /// How many times the `layout` method was called on this widget.
+    pub(crate) layout_count: u32,
+    /// How many times the `paint` method was called on this widget.
+    pub(crate) paint_count: u32,
     // TODO - Encapsulate this in WidgetStateDebugInfo struct.
     #[cfg(debug_assertions)]
     pub(crate) widget_name: &'static str,
@@ -302,7 +315,10 @@ impl WidgetState {
             first_baseline: f64::NAN,
             last_baseline: f64::NAN,
             clip_path: Option::default(),
+            clip_radii: None,
+            opacity: 1.,
             transform: options.transform,
+            transform_origin: options.transform_origin,
             window_transform: Affine::IDENTITY,
             scroll_translation: Vec2::ZERO,
             transform_changed: false,
@@ -311,6 +327,7 @@ impl WidgetState {
             accepts_pointer_interaction: true,
             propagates_pointer_interaction: true,
             accepts_focus: false,
+            is_tab_stop: options.tab_stop,
             accepts_text_input: false,
             ime_area: None,
 
@@ -353,6 +370,8 @@ impl WidgetState {
             property_cache: PropertyCache::default(),
 
             trace_span: Span::none(),
+            layout_count: 0,
+            paint_count: 0,
             #[cfg(debug_assertions)]
             widget_name,
             #[cfg(debug_assertions)]
@@ -433,12 +452,14 @@ impl WidgetState {
     ///
     /// This maps from this widget's border-box coordinate space into its
     /// parent's border-box coordinate space. It includes the widget's local
-    /// transform, scroll translation, and origin.
+    /// transform (pivoted around the transform origin), scroll translation, and origin.
     pub(crate) fn compose_local_transform(&self) -> Affine {
+        let pivot = self.transform_origin.resolve(self.border_box()).to_vec2();
+        let transform = self.transform.pre_translate(-pivot).then_translate(pivot);
         // The translation needs to be applied after the local transform so scrolling
         // and layout origin are in the transformed coordinate space, similar to CSS.
         let local_translation = self.scroll_translation + self.origin.to_vec2();
-        self.transform.then_translate(local_translation)
+        transform.then_translate(local_translation)
     }
 
     /// Returns the first baseline relative to the top of the widget's layout border-box.
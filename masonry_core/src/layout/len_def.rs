@@ -55,6 +55,62 @@ impl LenDef {
         }
     }
 
+    /// Returns [`LenDef`] without its minimum.
+    ///
+    /// [`Fixed`] becomes [`FitContent`] with the same value,
+    /// allowing the length to shrink below it while keeping it as the upper bound.
+    /// All other variants are returned as-is.
+    ///
+    /// [`Fixed`]: Self::Fixed
+    /// [`FitContent`]: Self::FitContent
+    pub fn loosen(self) -> Self {
+        match self {
+            Self::Fixed(val) => Self::FitContent(val),
+            Self::MinContent | Self::MaxContent | Self::FitContent(_) => self,
+        }
+    }
+
+    /// Returns [`LenDef`] with its available space as a fixed length.
+    ///
+    /// This is the inverse of [`loosen`](Self::loosen):
+    /// [`FitContent`] becomes [`Fixed`] with the same value,
+    /// forcing the length to fill the available space.
+    /// All other variants are returned as-is, because they have no space to fill.
+    ///
+    /// [`Fixed`]: Self::Fixed
+    /// [`FitContent`]: Self::FitContent
+    pub fn tighten(self) -> Self {
+        match self {
+            Self::FitContent(space) => Self::Fixed(space),
+            Self::Fixed(_) | Self::MinContent | Self::MaxContent => self,
+        }
+    }
+
+    /// Returns [`LenDef`] with its length expanded to the nearest whole logical pixel.
+    ///
+    /// [`Fixed`] and [`FitContent`] have their value rounded up, which is idempotent.
+    /// [`MinContent`] and [`MaxContent`] are returned as-is.
+    ///
+    /// [`Fixed`]: Self::Fixed
+    /// [`FitContent`]: Self::FitContent
+    /// [`MinContent`]: Self::MinContent
+    /// [`MaxContent`]: Self::MaxContent
+    pub fn round(self) -> Self {
+        match self {
+            Self::Fixed(val) => Self::Fixed(Length::px(val.get().ceil())),
+            Self::MinContent | Self::MaxContent => self,
+            Self::FitContent(space) => Self::FitContent(Length::px(space.get().ceil())),
+        }
+    }
+
+    /// Returns `true` if this [`LenDef`] is unchanged by [`round`](Self::round).
+    pub fn is_rounded(&self) -> bool {
+        match self {
+            Self::Fixed(val) | Self::FitContent(val) => val.get().fract() == 0.,
+            Self::MinContent | Self::MaxContent => true,
+        }
+    }
+
     /// Returns [`LenDef`] with `delta` subtracted from it.
     ///
     /// [`Fixed`] and [`FitContent`] will have their value reduced by `delta`, but clamped to zero.
@@ -71,4 +127,72 @@ impl LenDef {
             Self::FitContent(space) => Self::FitContent(space.saturating_sub(delta)),
         }
     }
+
+    /// Returns [`LenDef`] with `delta` added to it.
+    ///
+    /// This is the inverse of [`reduce`](Self::reduce), e.g. to add padding back.
+    /// [`Fixed`] and [`FitContent`] will have their value increased by `delta`,
+    /// saturating at the maximum length.
+    /// [`MinContent`] and [`MaxContent`] are returned as-is.
+    ///
+    /// [`Fixed`]: Self::Fixed
+    /// [`FitContent`]: Self::FitContent
+    /// [`MinContent`]: Self::MinContent
+    /// [`MaxContent`]: Self::MaxContent
+    pub fn expand(self, delta: Length) -> Self {
+        match self {
+            Self::Fixed(val) => Self::Fixed(val.saturating_add(delta)),
+            Self::MinContent | Self::MaxContent => self,
+            Self::FitContent(space) => Self::FitContent(space.saturating_add(delta)),
+        }
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reduce_clamps_to_zero() {
+        let delta = Length::px(10.);
+        assert_eq!(
+            LenDef::Fixed(Length::px(30.)).reduce(delta),
+            LenDef::Fixed(Length::px(20.))
+        );
+        assert_eq!(
+            LenDef::FitContent(Length::px(5.)).reduce(delta),
+            LenDef::FitContent(Length::ZERO)
+        );
+        assert_eq!(LenDef::MaxContent.reduce(delta), LenDef::MaxContent);
+    }
+
+    #[test]
+    fn expand_undoes_reduce() {
+        let delta = Length::px(10.);
+        for def in [
+            LenDef::Fixed(Length::px(30.)),
+            LenDef::FitContent(Length::px(30.)),
+            LenDef::MinContent,
+            LenDef::MaxContent,
+        ] {
+            assert_eq!(def.reduce(delta).expand(delta), def);
+        }
+        assert_eq!(
+            LenReq::FitContent(Length::px(30.))
+                .reduce(delta)
+                .expand(delta),
+            LenReq::FitContent(Length::px(30.))
+        );
+        assert_eq!(LenReq::MaxContent.expand(delta), LenReq::MaxContent);
+    }
+
+    #[test]
+    fn expand_saturates() {
+        let max = Length::px(f64::MAX);
+        assert_eq!(
+            LenDef::FitContent(max).expand(Length::px(10.)),
+            LenDef::FitContent(max)
+        );
+    }
 }
@@ -31,4 +31,20 @@ impl LenReq {
             Self::FitContent(space) => Self::FitContent(space.saturating_sub(delta)),
         }
     }
+
+    /// Returns [`LenReq`] with `delta` added to it.
+    ///
+    /// This is the inverse of [`reduce`](Self::reduce), e.g. to add padding back.
+    /// [`FitContent`] will have its value increased by `delta`, saturating at the maximum length.
+    /// [`MinContent`] and [`MaxContent`] are returned as-is.
+    ///
+    /// [`FitContent`]: Self::FitContent
+    /// [`MinContent`]: Self::MinContent
+    /// [`MaxContent`]: Self::MaxContent
+    pub fn expand(self, delta: Length) -> Self {
+        match self {
+            Self::MinContent | Self::MaxContent => self,
+            Self::FitContent(space) => Self::FitContent(space.saturating_add(delta)),
+        }
+    }
 }
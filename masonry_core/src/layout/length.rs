@@ -1,6 +1,8 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use std::ops::RangeInclusive;
+
 /// A value representing a width, height, or similar distance value.
 ///
 /// It is always finite and non-negative.
@@ -9,6 +11,31 @@ pub struct Length {
     value: f64,
 }
 
+/// The reason a value isn't a valid [`Length`].
+///
+/// Returned by [`Length::check`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LengthError {
+    /// The value is NaN.
+    NaN,
+    /// The value is infinite.
+    Infinite,
+    /// The value is negative.
+    Negative,
+}
+
+impl std::fmt::Display for LengthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Self::NaN => "length must not be NaN",
+            Self::Infinite => "length must be finite",
+            Self::Negative => "length must be non-negative",
+        })
+    }
+}
+
+impl std::error::Error for LengthError {}
+
 impl std::fmt::Debug for Length {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{self}")
@@ -38,10 +65,10 @@ impl Length {
     /// If debug assertions are off, this will return zero instead of panicking.
     #[track_caller]
     pub fn px(value: f64) -> Self {
-        if value < 0. || !value.is_finite() {
+        if let Err(err) = Self::check(value) {
             // TODO - Make const once const formatting is allowed.
             // (aka see you in 2030)
-            debug_panic!("Invalid length value '{value}'");
+            debug_panic!("Invalid length value '{value}': {err}");
             return Self::ZERO;
         }
         Self { value }
@@ -50,11 +77,28 @@ impl Length {
     /// Creates a length, in logical pixels.
     ///
     /// Returns `None` if the provided `value` is non-finite or negative.
+    /// Use [`check`](Self::check) to find out which.
     pub const fn try_px(value: f64) -> Option<Self> {
-        if value < 0. || !value.is_finite() {
-            return None;
+        match Self::check(value) {
+            Ok(()) => Some(Self { value }),
+            Err(_) => None,
+        }
+    }
+
+    /// Checks whether `value` is a valid length, in logical pixels.
+    ///
+    /// Unlike [`px`](Self::px), this never panics, so it can be used to reject bad input
+    /// at the boundary of an integration layer.
+    pub const fn check(value: f64) -> Result<(), LengthError> {
+        if value.is_nan() {
+            Err(LengthError::NaN)
+        } else if value.is_infinite() {
+            Err(LengthError::Infinite)
+        } else if value < 0. {
+            Err(LengthError::Negative)
+        } else {
+            Ok(())
         }
-        Some(Self { value })
     }
 
     /// Creates a length, in logical pixels.
@@ -114,6 +158,100 @@ impl Length {
         }
     }
 
+    /// Clamps this height so that the aspect ratio `width / height` stays within `aspect`,
+    /// and then within the `min..=max` height bounds.
+    ///
+    /// Only the height is adjusted, the `width` is treated as fixed.
+    /// This is meant for [`Widget::measure`] implementations which are given a width
+    /// as their cross length and need to pick a matching height.
+    ///
+    /// The height bounds are applied last, so they take precedence over the aspect ratio:
+    /// if no height within `min..=max` gives an aspect ratio within `aspect`,
+    /// the result is the bound closest to the allowed heights.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    ///
+    /// If debug assertions are on, also panics if `aspect` is empty or has negative or NaN bounds.
+    ///
+    /// [`Widget::measure`]: crate::core::Widget::measure
+    pub fn clamp_aspect_within(
+        self,
+        width: Self,
+        aspect: RangeInclusive<f64>,
+        min: Self,
+        max: Self,
+    ) -> Self {
+        let (min_ratio, max_ratio) = aspect.into_inner();
+        if !(0. <= min_ratio && min_ratio <= max_ratio) {
+            debug_panic!("invalid aspect ratio range {min_ratio}..={max_ratio}");
+            return self.clamp(min, max);
+        }
+        // A wider ratio means a smaller height, so the bounds swap.
+        // A zero width has a zero ratio with any height, so if zero is allowed,
+        // the `0 / 0` bounds of that case don't constrain the height at all.
+        let shortest = width.value / max_ratio;
+        let shortest = if shortest.is_nan() { 0. } else { shortest };
+        let tallest = width.value / min_ratio;
+        let tallest = if tallest.is_nan() {
+            f64::INFINITY
+        } else {
+            tallest
+        };
+        Self {
+            value: self.value.clamp(shortest, tallest),
+        }
+        .clamp(min, max)
+    }
+
+    /// Clamps this width so that the aspect ratio `width / height` stays within `aspect`,
+    /// and then within the `min..=max` width bounds.
+    ///
+    /// This is the transposed version of [`clamp_aspect_within`](Self::clamp_aspect_within):
+    /// only the width is adjusted, the `height` is treated as fixed.
+    /// This is meant for [`Widget::measure`] implementations which are given a height
+    /// as their cross length and need to pick a matching width.
+    ///
+    /// The width bounds are applied last, so they take precedence over the aspect ratio:
+    /// if no width within `min..=max` gives an aspect ratio within `aspect`,
+    /// the result is the bound closest to the allowed widths.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    ///
+    /// If debug assertions are on, also panics if `aspect` is empty or has negative or NaN bounds.
+    ///
+    /// [`Widget::measure`]: crate::core::Widget::measure
+    pub fn clamp_aspect_within_by_height(
+        self,
+        height: Self,
+        aspect: RangeInclusive<f64>,
+        min: Self,
+        max: Self,
+    ) -> Self {
+        let (min_ratio, max_ratio) = aspect.into_inner();
+        if !(0. <= min_ratio && min_ratio <= max_ratio) {
+            debug_panic!("invalid aspect ratio range {min_ratio}..={max_ratio}");
+            return self.clamp(min, max);
+        }
+        // A zero height has an infinite ratio with any width, so if infinity is allowed,
+        // the `0 * inf` bounds of that case don't constrain the width at all.
+        let narrowest = height.value * min_ratio;
+        let narrowest = if narrowest.is_nan() { 0. } else { narrowest };
+        let widest = height.value * max_ratio;
+        let widest = if widest.is_nan() {
+            f64::INFINITY
+        } else {
+            widest
+        };
+        Self {
+            value: self.value.clamp(narrowest, widest),
+        }
+        .clamp(min, max)
+    }
+
     /// Adds the `other` value but the result doesn't go above the maximum value.
     pub const fn saturating_add(self, other: Self) -> Self {
         Self {
@@ -128,3 +266,172 @@ impl Length {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_valid() {
+        assert_eq!(Length::check(0.), Ok(()));
+        assert_eq!(Length::check(12.5), Ok(()));
+        assert_eq!(Length::check(-0.), Ok(()));
+    }
+
+    #[test]
+    fn check_errors() {
+        assert_eq!(Length::check(f64::NAN), Err(LengthError::NaN));
+        assert_eq!(Length::check(f64::INFINITY), Err(LengthError::Infinite));
+        assert_eq!(Length::check(f64::NEG_INFINITY), Err(LengthError::Infinite));
+        assert_eq!(Length::check(-1.), Err(LengthError::Negative));
+        assert_eq!(Length::try_px(-1.), None);
+    }
+
+    #[test]
+    fn clamp_aspect_within() {
+        let width = Length::px(100.);
+        let aspect = 1.0..=2.0;
+        let bounds = (Length::ZERO, Length::px(1000.));
+
+        // A height of 20px would be too wide, so it's clamped up to a 2:1 ratio.
+        let height = Length::px(20.).clamp_aspect_within(width, aspect.clone(), bounds.0, bounds.1);
+        assert_eq!(height, Length::px(50.));
+
+        // A height of 300px would be too tall, so it's clamped down to a 1:1 ratio.
+        let height =
+            Length::px(300.).clamp_aspect_within(width, aspect.clone(), bounds.0, bounds.1);
+        assert_eq!(height, Length::px(100.));
+
+        // Heights within the ratio range are kept.
+        let height = Length::px(80.).clamp_aspect_within(width, aspect.clone(), bounds.0, bounds.1);
+        assert_eq!(height, Length::px(80.));
+
+        // The height bounds take precedence over the ratio.
+        let height = Length::px(300.).clamp_aspect_within(width, aspect, bounds.0, Length::px(40.));
+        assert_eq!(height, Length::px(40.));
+    }
+
+    #[test]
+    fn clamp_aspect_within_degenerate() {
+        let bounds = (Length::ZERO, Length::px(1000.));
+
+        // A zero width with a range starting at zero allows any height.
+        let height =
+            Length::px(30.).clamp_aspect_within(Length::ZERO, 0.0..=2.0, bounds.0, bounds.1);
+        assert_eq!(height, Length::px(30.));
+        let height =
+            Length::px(30.).clamp_aspect_within(Length::ZERO, 0.0..=0.0, bounds.0, bounds.1);
+        assert_eq!(height, Length::px(30.));
+
+        // A zero width with a positive range only allows a zero height.
+        let height =
+            Length::px(30.).clamp_aspect_within(Length::ZERO, 1.0..=2.0, bounds.0, bounds.1);
+        assert_eq!(height, Length::ZERO);
+
+        // A zero ratio can't be reached with a positive width, so the height goes to its maximum.
+        let height =
+            Length::px(30.).clamp_aspect_within(Length::px(10.), 0.0..=0.0, bounds.0, bounds.1);
+        assert_eq!(height, bounds.1);
+
+        // An unbounded range allows any height.
+        let height = Length::px(30.).clamp_aspect_within(
+            Length::ZERO,
+            0.0..=f64::INFINITY,
+            bounds.0,
+            bounds.1,
+        );
+        assert_eq!(height, Length::px(30.));
+    }
+
+    #[test]
+    fn clamp_aspect_within_by_height() {
+        let height = Length::px(100.);
+        let aspect = 1.0..=2.0;
+        let bounds = (Length::ZERO, Length::px(1000.));
+
+        // A width of 20px would be too tall, so it's clamped up to a 1:1 ratio.
+        let width = Length::px(20.).clamp_aspect_within_by_height(
+            height,
+            aspect.clone(),
+            bounds.0,
+            bounds.1,
+        );
+        assert_eq!(width, Length::px(100.));
+
+        // A width of 300px would be too wide, so it's clamped down to a 2:1 ratio.
+        let width = Length::px(300.).clamp_aspect_within_by_height(
+            height,
+            aspect.clone(),
+            bounds.0,
+            bounds.1,
+        );
+        assert_eq!(width, Length::px(200.));
+
+        // Widths within the ratio range are kept.
+        let width = Length::px(150.).clamp_aspect_within_by_height(
+            height,
+            aspect.clone(),
+            bounds.0,
+            bounds.1,
+        );
+        assert_eq!(width, Length::px(150.));
+
+        // The width bounds take precedence over the ratio.
+        let width = Length::px(300.).clamp_aspect_within_by_height(
+            height,
+            aspect,
+            bounds.0,
+            Length::px(40.),
+        );
+        assert_eq!(width, Length::px(40.));
+    }
+
+    #[test]
+    fn clamp_aspect_within_by_height_degenerate() {
+        let bounds = (Length::ZERO, Length::px(1000.));
+        let infinite = f64::INFINITY;
+
+        // A zero height with a range ending at infinity allows any width.
+        let width = Length::px(30.).clamp_aspect_within_by_height(
+            Length::ZERO,
+            1.0..=infinite,
+            bounds.0,
+            bounds.1,
+        );
+        assert_eq!(width, Length::px(30.));
+        let width = Length::px(30.).clamp_aspect_within_by_height(
+            Length::ZERO,
+            infinite..=infinite,
+            bounds.0,
+            bounds.1,
+        );
+        assert_eq!(width, Length::px(30.));
+
+        // A zero height with a finite range only allows a zero width.
+        let width = Length::px(30.).clamp_aspect_within_by_height(
+            Length::ZERO,
+            1.0..=2.0,
+            bounds.0,
+            bounds.1,
+        );
+        assert_eq!(width, Length::ZERO);
+
+        // An infinite ratio can't be reached with a positive height, so the width goes to its maximum.
+        let width = Length::px(30.).clamp_aspect_within_by_height(
+            Length::px(10.),
+            infinite..=infinite,
+            bounds.0,
+            bounds.1,
+        );
+        assert_eq!(width, bounds.1);
+
+        // A range starting at zero allows any width below the maximum ratio.
+        let width = Length::px(30.).clamp_aspect_within_by_height(
+            Length::ZERO,
+            0.0..=infinite,
+            bounds.0,
+            bounds.1,
+        );
+        assert_eq!(width, Length::px(30.));
+    }
+}
@@ -1,7 +1,7 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use kurbo::{Axis, Size};
+use kurbo::{Axis, Point, Rect, Size};
 
 use crate::layout::{LenDef, Length};
 use crate::util::Sanitize;
@@ -78,6 +78,32 @@ impl SizeDef {
         )
     }
 
+    /// Creates a new [fixed](Self::fixed) [`SizeDef`] for the size of `rect`,
+    /// and returns it together with the `rect`'s origin.
+    ///
+    /// This is meant for placing a child into a known area:
+    /// compute the child's size with the returned [`SizeDef`],
+    /// then place it at the returned origin.
+    ///
+    /// See [`to_rect`](Self::to_rect) for the inverse.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rect` has a non-finite or negative size and debug assertions are enabled.
+    pub fn fixed_for_rect(rect: Rect) -> (Self, Point) {
+        (Self::fixed(rect.size()), rect.origin())
+    }
+
+    /// Returns the [`Rect`] at `origin` with this [`SizeDef`]'s size,
+    /// or `None` if either axis is not [`LenDef::Fixed`].
+    ///
+    /// This is the inverse of [`fixed_for_rect`](Self::fixed_for_rect).
+    pub fn to_rect(&self, origin: Point) -> Option<Rect> {
+        let width = self.width.fixed()?.get();
+        let height = self.height.fixed()?.get();
+        Some(Rect::from_origin_size(origin, (width, height)))
+    }
+
     /// Creates a new [`SizeDef`] with `axis` set to [`LenDef`].
     ///
     /// The other axis will be [`LenDef::MaxContent`].
@@ -114,6 +140,46 @@ impl SizeDef {
         self
     }
 
+    /// Returns the [`SizeDef`] with only the width [loosened](LenDef::loosen).
+    ///
+    /// The height is preserved as-is.
+    pub fn loosen_width(self) -> Self {
+        self.with_width(self.width.loosen())
+    }
+
+    /// Returns the [`SizeDef`] with only the height [loosened](LenDef::loosen).
+    ///
+    /// The width is preserved as-is.
+    pub fn loosen_height(self) -> Self {
+        self.with_height(self.height.loosen())
+    }
+
+    /// Returns the [`SizeDef`] with only the width [tightened](LenDef::tighten).
+    ///
+    /// This makes a child fill the available width, while the height is preserved as-is.
+    pub fn tighten_width(self) -> Self {
+        self.with_width(self.width.tighten())
+    }
+
+    /// Returns the [`SizeDef`] with only the height [tightened](LenDef::tighten).
+    ///
+    /// This makes a child fill the available height, while the width is preserved as-is.
+    pub fn tighten_height(self) -> Self {
+        self.with_height(self.height.tighten())
+    }
+
+    /// Returns the [`SizeDef`] with both axes [rounded](LenDef::round).
+    ///
+    /// Use this to normalize a [`SizeDef`] built from possibly fractional sizes.
+    pub fn round(self) -> Self {
+        Self::new(self.width.round(), self.height.round())
+    }
+
+    /// Returns `true` if both axes are [rounded](LenDef::is_rounded).
+    pub fn is_rounded(&self) -> bool {
+        self.width.is_rounded() && self.height.is_rounded()
+    }
+
     /// Returns the [`LenDef`] of the given `axis`.
     pub const fn dim(&self, axis: Axis) -> LenDef {
         match axis {
@@ -122,3 +188,105 @@ impl SizeDef {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use kurbo::{Axis, Point, Rect, Size};
+
+    use super::SizeDef;
+    use crate::layout::{LenDef, Length};
+
+    #[test]
+    fn loosen_width_preserves_height() {
+        let def = SizeDef::fixed(Size::new(40., 20.)).loosen_width();
+        assert_eq!(
+            def.dim(Axis::Horizontal),
+            LenDef::FitContent(Length::px(40.))
+        );
+        assert_eq!(def.dim(Axis::Vertical), LenDef::Fixed(Length::px(20.)));
+    }
+
+    #[test]
+    fn loosen_height_preserves_width() {
+        let def = SizeDef::fixed(Size::new(40., 20.)).loosen_height();
+        assert_eq!(def.dim(Axis::Horizontal), LenDef::Fixed(Length::px(40.)));
+        assert_eq!(def.dim(Axis::Vertical), LenDef::FitContent(Length::px(20.)));
+    }
+
+    #[test]
+    fn loosen_keeps_content_sizes() {
+        let def = SizeDef::MIN.loosen_width().loosen_height();
+        assert_eq!(def, SizeDef::MIN);
+    }
+
+    #[test]
+    fn tighten_width_preserves_height() {
+        let def = SizeDef::fit(Size::new(40., 20.)).tighten_width();
+        assert_eq!(def.dim(Axis::Horizontal), LenDef::Fixed(Length::px(40.)));
+        assert_eq!(def.dim(Axis::Vertical), LenDef::FitContent(Length::px(20.)));
+    }
+
+    #[test]
+    fn tighten_height_preserves_width() {
+        let def = SizeDef::fit(Size::new(40., 20.)).tighten_height();
+        assert_eq!(
+            def.dim(Axis::Horizontal),
+            LenDef::FitContent(Length::px(40.))
+        );
+        assert_eq!(def.dim(Axis::Vertical), LenDef::Fixed(Length::px(20.)));
+    }
+
+    #[test]
+    fn tighten_keeps_content_sizes() {
+        let def = SizeDef::MAX.tighten_width().tighten_height();
+        assert_eq!(def, SizeDef::MAX);
+    }
+
+    #[test]
+    fn tighten_undoes_loosen() {
+        let def = SizeDef::fixed(Size::new(40., 20.));
+        assert_eq!(def.loosen_width().tighten_width(), def);
+        assert!(def.loosen_height().tighten_height().is_rounded());
+    }
+
+    #[test]
+    fn round_is_idempotent() {
+        let def = SizeDef::new(
+            LenDef::Fixed(Length::px(40.25)),
+            LenDef::FitContent(Length::px(20.75)),
+        );
+        let rounded = def.round();
+        assert_eq!(
+            rounded.dim(Axis::Horizontal),
+            LenDef::Fixed(Length::px(41.))
+        );
+        assert_eq!(
+            rounded.dim(Axis::Vertical),
+            LenDef::FitContent(Length::px(21.))
+        );
+        assert_eq!(rounded.round(), rounded);
+    }
+
+    #[test]
+    fn is_rounded() {
+        assert!(SizeDef::fixed(Size::new(40., 20.)).is_rounded());
+        assert!(SizeDef::fit(Size::new(40., 20.)).is_rounded());
+        assert!(SizeDef::MIN.is_rounded());
+        assert!(SizeDef::MAX.is_rounded());
+
+        assert!(!SizeDef::fixed(Size::new(40.5, 20.)).is_rounded());
+        assert!(!SizeDef::fit(Size::new(40., 20.1)).is_rounded());
+        assert!(SizeDef::fit(Size::new(40., 20.1)).round().is_rounded());
+    }
+
+    #[test]
+    fn rect_round_trip() {
+        let rect = Rect::new(10., 20., 50., 35.);
+        let (def, origin) = SizeDef::fixed_for_rect(rect);
+        assert_eq!(def, SizeDef::fixed(Size::new(40., 15.)));
+        assert_eq!(origin, Point::new(10., 20.));
+        assert_eq!(def.to_rect(origin), Some(rect));
+
+        assert_eq!(SizeDef::fit(rect.size()).to_rect(origin), None);
+    }
+}
@@ -111,7 +111,7 @@ fn build_access_node(
 
     node.set_children(
         widget
-            .children_ids()
+            .accessibility_children_ids()
             .iter()
             .copied()
             .filter(|id| !is_child_stashed(ctx, *id))
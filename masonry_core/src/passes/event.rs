@@ -11,6 +11,7 @@ use crate::core::{
     WidgetId,
 };
 use crate::dpi::{LogicalPosition, PhysicalPosition};
+use crate::kurbo::Point;
 use crate::passes::update::find_next_focusable;
 use crate::passes::{enter_span, merge_state_up};
 
@@ -159,6 +160,27 @@ pub(crate) fn run_on_pointer_event_pass(root: &mut RenderRoot, event: &PointerEv
     if event_pos != root.global_state.last_mouse_pos {
         root.global_state.last_mouse_pos = event_pos;
     }
+    if let PointerEvent::Down(PointerButtonEvent { pointer, state, .. })
+    | PointerEvent::Up(PointerButtonEvent { pointer, state, .. })
+    | PointerEvent::Move(PointerUpdate {
+        pointer,
+        current: state,
+        ..
+    }) = event
+    {
+        let LogicalPosition { x, y } = state.position.to_logical(root.global_state.scale_factor);
+        let global_state = &mut root.global_state;
+        // Samples from different pointers can't be mixed, so we only track the latest one.
+        if matches!(event, PointerEvent::Down(..))
+            || global_state.pointer_velocity_id != pointer.pointer_id
+        {
+            global_state.pointer_velocity.reset();
+            global_state.pointer_velocity_id = pointer.pointer_id;
+        }
+        global_state
+            .pointer_velocity
+            .add_sample(state.time, Point::new(x, y));
+    }
     root.global_state.needs_pointer_pass = true;
 
     if root.global_state.inspector_state.is_picking_widget
@@ -324,6 +346,12 @@ pub(crate) fn run_on_text_event_pass(root: &mut RenderRoot, event: &TextEvent) -
             let forward = !key.modifiers.shift();
             let next_focused_widget = find_next_focusable(root, forward);
             root.global_state.next_focused_widget = next_focused_widget;
+            // Keyboard focus may land on a widget outside the visible area of a scroll container,
+            // so we ask its ancestors to scroll it into view.
+            if let Some(id) = next_focused_widget {
+                let rect = root.widget_arena.get_state(id).border_box();
+                root.global_state.scroll_request_targets.push((id, rect));
+            }
             handled = Handled::Yes;
         }
 
@@ -17,9 +17,9 @@ use crate::core::{
     WidgetState,
 };
 use crate::kurbo::{Axis, Insets, Point, Size};
-use crate::layout::{LayoutSize, LenDef, LenReq, Length, MeasurementInputs, SizeDef};
+use crate::layout::{LayoutSize, LenDef, LenReq, Length, MeasurementInputs, SizeDef, UnitPoint};
 use crate::passes::{enter_span_if, recurse_on_children};
-use crate::properties::{BorderWidth, BoxShadow, Dimensions, Padding};
+use crate::properties::{BorderWidth, BoxShadow, BoxShadows, Dimensions, Padding};
 use crate::util::Sanitize;
 
 // --- MARK: COMPUTE SIZE
@@ -423,11 +423,14 @@ pub(crate) fn run_layout_on(
 
     // Run the widget's layout
     widget.layout(&mut ctx, &props, content_box_size);
+    ctx.widget_state.layout_count += 1;
 
     // Make sure the paint insets cover the shadow insets
     let shadow = props.get::<BoxShadow>(&mut state.property_cache);
-    if shadow.is_visible() {
-        let shadow_insets = shadow.get_insets();
+    let shadow_insets = shadow.is_visible().then(|| shadow.get_insets());
+    let shadows = props.get::<BoxShadows>(&mut state.property_cache);
+    let shadows_insets = shadows.is_visible().then(|| shadows.get_insets());
+    for shadow_insets in [shadow_insets, shadows_insets].into_iter().flatten() {
         state.paint_box_insets = Insets {
             x0: state.paint_box_insets.x0.max(shadow_insets.x0),
             y0: state.paint_box_insets.y0.max(shadow_insets.y0),
@@ -520,6 +523,12 @@ pub(crate) fn place_widget(child_state: &mut WidgetState, origin: Point) {
         child_state.transform_changed = true;
         child_state.needs_compose = true;
     }
+    // The transform origin is resolved against the border-box size.
+    let size_changed = end_point - origin != child_state.end_point - child_state.origin;
+    if size_changed && child_state.transform_origin != UnitPoint::TOP_LEFT {
+        child_state.transform_changed = true;
+        child_state.needs_compose = true;
+    }
     child_state.origin = origin;
     child_state.end_point = end_point;
 
@@ -10,7 +10,7 @@
 use tracing::span::EnteredSpan;
 use tree_arena::{ArenaMut, ArenaMutList};
 
-use crate::core::{Widget, WidgetArena, WidgetArenaNode, WidgetId, WidgetState};
+use crate::core::{ChildrenIds, Widget, WidgetArena, WidgetArenaNode, WidgetId, WidgetState};
 
 pub(crate) mod accessibility;
 pub(crate) mod action;
@@ -35,13 +35,33 @@ pub(crate) fn enter_span(state: &WidgetState) -> EnteredSpan {
 pub(crate) fn recurse_on_children(
     id: WidgetId,
     widget: &dyn Widget,
+    children: ArenaMutList<'_, WidgetArenaNode>,
+    callback: impl FnMut(ArenaMut<'_, WidgetArenaNode>),
+) {
+    recurse_on_child_ids(id, widget, widget.children_ids(), children, callback);
+}
+
+/// Like [`recurse_on_children`], but visits the children in [`Widget::paint_order_ids`] order.
+pub(crate) fn recurse_on_children_in_paint_order(
+    id: WidgetId,
+    widget: &dyn Widget,
+    children: ArenaMutList<'_, WidgetArenaNode>,
+    callback: impl FnMut(ArenaMut<'_, WidgetArenaNode>),
+) {
+    recurse_on_child_ids(id, widget, widget.paint_order_ids(), children, callback);
+}
+
+fn recurse_on_child_ids(
+    id: WidgetId,
+    widget: &dyn Widget,
+    child_ids: ChildrenIds,
     mut children: ArenaMutList<'_, WidgetArenaNode>,
     mut callback: impl FnMut(ArenaMut<'_, WidgetArenaNode>),
 ) {
     let parent_name = widget.short_type_name();
     let parent_id = id;
 
-    for child_id in widget.children_ids() {
+    for child_id in child_ids {
         let Some(node) = children.item_mut(child_id) else {
             panic!(
                 "Error in '{parent_name}' {parent_id}: cannot find child {child_id} returned by children_ids()"
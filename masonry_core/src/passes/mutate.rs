@@ -1,33 +1,67 @@
 // Copyright 2024 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use tracing::info_span;
+use tracing::{debug, info_span};
 
 use crate::app::RenderRoot;
 use crate::core::{MutateCtx, PropertiesMut, Widget, WidgetId, WidgetMut};
 use crate::passes::merge_state_up;
 
+/// Runs `mutate_fn` on the widget with the given id, then merges state changes up to the root.
+///
+/// # Panics
+///
+/// Panics if the widget isn't in the tree. See [`try_mutate_widget`] for a non-panicking version.
 pub(crate) fn mutate_widget<R>(
     root: &mut RenderRoot,
     id: WidgetId,
     mutate_fn: impl FnOnce(WidgetMut<'_, dyn Widget>) -> R,
 ) -> R {
-    // TODO - This panics if id can't be found.
-    // Should it return Option instead?
+    let result = mutate_widget_unmerged(root, id, mutate_fn);
+    merge_state_to_root(root, id);
+    result
+}
+
+/// Runs `mutate_fn` on the widget with the given id, if it's in the tree.
+///
+/// Returns `None` without calling `mutate_fn` if there is no widget with the given id,
+/// e.g. because it was removed since its id was obtained.
+///
+/// Unlike [`RenderRoot::edit_widget`], this doesn't run the rewrite passes.
+/// The changes are applied by the next call to [`RenderRoot::redraw`]
+/// or to one of the event handling methods.
+pub fn try_mutate_widget<R>(
+    root: &mut RenderRoot,
+    id: WidgetId,
+    mutate_fn: impl FnOnce(WidgetMut<'_, dyn Widget>) -> R,
+) -> Option<R> {
+    if !root.widget_arena.has(id) {
+        return None;
+    }
+    Some(mutate_widget(root, id, mutate_fn))
+}
+
+/// Like [`mutate_widget`], but doesn't merge state changes up to the root.
+///
+/// Callers must run [`merge_state_to_root`] on `id` before running any other pass.
+pub(crate) fn mutate_widget_unmerged<R>(
+    root: &mut RenderRoot,
+    id: WidgetId,
+    mutate_fn: impl FnOnce(WidgetMut<'_, dyn Widget>) -> R,
+) -> R {
     let node = root.widget_arena.get_node_mut(id);
     let children = node.children;
     let widget = &mut *node.item.widget;
     let state = &mut node.item.state;
     let properties = &mut node.item.properties;
     let class_set = &node.item.class_set;
-    let id = state.id;
     let stack = root
         .property_arena
         .get(state.property_stack_id, widget.type_id());
 
     let _span = info_span!("mutate_widget", name = widget.short_type_name()).entered();
 
-    // NOTE - we can set parent_widget_state to None here, because the loop below will merge the
+    // NOTE - we can set parent_widget_state to None here, because the caller will merge the
     // states up to the root.
 
     let root_widget = WidgetMut {
@@ -50,17 +84,17 @@ pub(crate) fn mutate_widget<R>(
         widget,
     };
 
-    let result = mutate_fn(root_widget);
+    mutate_fn(root_widget)
+}
 
-    // Merge all state changes up to the root.
+/// Merges all state changes of the given widget up to the root.
+pub(crate) fn merge_state_to_root(root: &mut RenderRoot, id: WidgetId) {
     let mut current_id = Some(id);
     while let Some(id) = current_id {
         let parent_id = root.widget_arena.parent_of(id);
         merge_state_up(&mut root.widget_arena, id);
         current_id = parent_id;
     }
-
-    result
 }
 
 /// Apply any deferred mutations (created using `...Ctx::mutate_later`)
@@ -69,10 +103,10 @@ pub(crate) fn mutate_widget<R>(
 pub(crate) fn run_mutate_pass(root: &mut RenderRoot) {
     let callbacks = std::mem::take(&mut root.global_state.mutate_callbacks);
     for callback in callbacks {
+        let id = callback.id;
         // Skip callbacks whose target was removed since they were emitted.
-        if !root.widget_arena.has(callback.id) {
-            continue;
+        if try_mutate_widget(root, id, callback.callback).is_none() {
+            debug!("Skipping deferred mutation of {id}: widget was removed");
         }
-        mutate_widget(root, callback.id, callback.callback);
     }
 }
@@ -3,8 +3,8 @@
 
 use std::collections::HashMap;
 
-use kurbo::{Affine, Line, Stroke};
-use peniko::{Color, Fill};
+use kurbo::{Affine, Line, RoundedRect, Stroke};
+use peniko::{BlendMode, Color, Fill};
 use tracing::{info_span, trace};
 use tree_arena::ArenaMut;
 
@@ -14,8 +14,9 @@ use crate::core::{
     WidgetId,
 };
 use crate::imaging::record::{Clip, Geometry, Scene};
-use crate::imaging::{PaintSink, Painter};
-use crate::passes::{enter_span_if, recurse_on_children};
+use crate::imaging::{Composite, GroupRef, PaintSink, Painter};
+use crate::passes::{enter_span_if, recurse_on_children_in_paint_order};
+use crate::properties::Opacity;
 use crate::util::get_debug_color;
 
 struct LayerCollector {
@@ -128,6 +129,7 @@ fn paint_widget(
             let sink_dyn: &mut dyn PaintSink = scene;
             let mut painter = Painter::new(sink_dyn);
             widget.paint(&mut ctx, &props, &mut painter);
+            ctx.widget_state.paint_count += 1;
         }
         if ctx.widget_state.request_post_paint {
             post_scene.clear();
@@ -166,6 +168,25 @@ fn paint_widget(
         border_box_to_layer_transform.pre_translate(state.border_box_translation());
     let has_clip = state.clip_path.is_some();
     let paint_as_external = paint_layer_mode == PaintLayerMode::External;
+    let opacity = {
+        let props = PropertiesRef {
+            local: properties,
+            default_map: default_properties.for_widget(widget.type_id()),
+            stack: property_arena.get(state.property_stack_id, widget.type_id()),
+            class_set,
+        };
+        state.opacity * props.get::<Opacity>(&mut state.property_cache).alpha()
+    };
+    // Opacity applies to the widget and its descendants, so we paint all of them in a group.
+    let has_opacity = !is_stashed && paint_layer_mode == PaintLayerMode::Inline && opacity < 1.;
+
+    if has_opacity {
+        let mut painter = Painter::new(layer_collector.scene_mut());
+        painter.push_fill_clip(window_to_layer_transform.transform_rect_bbox(state.bounding_box));
+        painter.push_group(
+            GroupRef::new().with_composite(Composite::new(BlendMode::default(), opacity)),
+        );
+    }
 
     if !is_stashed && !paint_as_external {
         let Some((pre_scene, scene, _)) = &mut scene_cache.get(&id) else {
@@ -180,10 +201,14 @@ fn paint_widget(
             .append_transformed(pre_scene, content_box_to_layer_transform);
 
         if let Some(clip) = state.clip_path {
+            let shape = match state.clip_radii {
+                Some(radii) => Geometry::RoundedRect(RoundedRect::from_rect(clip, radii)),
+                None => Geometry::Rect(clip),
+            };
             // The clip path is stored in border-box space, so need to use that transform.
             layer_collector.scene_mut().push_clip(Clip::Fill {
                 transform: border_box_to_layer_transform,
-                shape: Geometry::Rect(clip),
+                shape,
                 fill_rule: Fill::NonZero,
             });
         }
@@ -194,7 +219,7 @@ fn paint_widget(
     }
 
     let parent_state = &mut *state;
-    recurse_on_children(id, widget, children, |mut node| {
+    recurse_on_children_in_paint_order(id, widget, children, |mut node| {
         // TODO: We could skip painting children outside the parent clip path.
         // There's a few things to consider if we do:
         // - Some widgets can paint outside of their border-box.
@@ -265,6 +290,12 @@ fn paint_widget(
         }
     }
 
+    if has_opacity {
+        let mut painter = Painter::new(layer_collector.scene_mut());
+        painter.pop_group();
+        painter.pop_clip();
+    }
+
     if paint_as_external {
         layer_collector.push_external_layer(id, state.border_box());
     }
@@ -484,7 +484,7 @@ fn update_focusable_for_widget(node: ArenaMut<'_, WidgetArenaNode>) {
 
     state.descendant_is_focusable = false;
 
-    if state.accepts_focus && !state.is_disabled && !state.is_stashed {
+    if state.accepts_focus && state.is_tab_stop && !state.is_disabled && !state.is_stashed {
         state.descendant_is_focusable = true;
     }
 
@@ -560,7 +560,7 @@ fn find_first_focusable(
     let item = root.widget_arena.get_node_mut(node);
     let widget = &mut *item.item.widget;
     let state = &mut item.item.state;
-    let accepts_focus = state.accepts_focus;
+    let accepts_focus = state.accepts_focus && state.is_tab_stop;
 
     if !state.descendant_is_focusable {
         return None;
@@ -751,14 +751,19 @@ pub(crate) fn run_update_focus_pass(root: &mut RenderRoot) {
         // We also request accessibility, because build_access_node() depends on the focus state.
         run_single_update_pass(root, prev_focused, |widget, ctx, props| {
             widget.update(ctx, props, &Update::FocusChanged(false));
+            widget.on_focus_change(ctx, props, false);
             ctx.widget_state.request_accessibility = true;
             ctx.widget_state.needs_accessibility = true;
         });
         run_single_update_pass(root, next_focused, |widget, ctx, props| {
             widget.update(ctx, props, &Update::FocusChanged(true));
+            widget.on_focus_change(ctx, props, true);
             ctx.widget_state.request_accessibility = true;
             ctx.widget_state.needs_accessibility = true;
         });
+        // The focus is part of every tree update, so we make sure one is sent
+        // even if neither widget is still in the tree, e.g. when the focused widget was removed.
+        root.root_state_mut().needs_accessibility = true;
 
         if let Some(next_focused) = next_focused {
             let widget_state = root.widget_arena.get_state(next_focused);
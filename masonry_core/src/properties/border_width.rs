@@ -10,10 +10,19 @@ use crate::properties::CornerRadius;
 impl<W: Widget> UsesProperty<BorderWidth> for W {}
 
 /// The width of a widget's border.
-#[expect(missing_docs, reason = "field names are self-descriptive")]
+///
+/// Each edge can have a different width, e.g. to draw only an underline with
+/// [`BorderWidth::bottom`]. The content box is inset by the width of each edge.
 #[derive(Default, Clone, Copy, Debug, PartialEq)]
 pub struct BorderWidth {
-    pub width: Length,
+    /// The width of the border at the left edge.
+    pub left: Length,
+    /// The width of the border at the right edge.
+    pub right: Length,
+    /// The width of the border at the top edge.
+    pub top: Length,
+    /// The width of the border at the bottom edge.
+    pub bottom: Length,
 }
 
 // TODO - To match CSS, we should use a non-zero default width
@@ -21,25 +30,53 @@ pub struct BorderWidth {
 
 impl Property for BorderWidth {
     fn static_default() -> &'static Self {
-        static DEFAULT: BorderWidth = BorderWidth {
-            width: Length::ZERO,
-        };
+        static DEFAULT: BorderWidth = BorderWidth::all(Length::ZERO);
         &DEFAULT
     }
 }
 
 impl BorderWidth {
+    /// Creates new `BorderWidth` with the given width for each edge, in CSS order.
+    pub const fn new(top: Length, right: Length, bottom: Length, left: Length) -> Self {
+        Self {
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+
     /// Creates new `BorderWidth` with given value.
     pub const fn all(width: Length) -> Self {
-        Self { width }
+        Self::new(width, width, width, width)
+    }
+
+    /// Creates new `BorderWidth` with a border only at the bottom edge.
+    pub const fn bottom(width: Length) -> Self {
+        Self::new(Length::ZERO, Length::ZERO, width, Length::ZERO)
+    }
+
+    /// Returns `true` if all edges have the same width.
+    pub fn is_uniform(&self) -> bool {
+        self.left == self.right && self.left == self.top && self.left == self.bottom
+    }
+
+    /// Returns the width of the widest edge.
+    ///
+    /// This is meant for widgets which can only draw a border of uniform width.
+    pub fn max_width(&self) -> Length {
+        self.left.max(self.right).max(self.top).max(self.bottom)
     }
 
     /// Returns the total [`Length`] of this border on the given `axis`.
     ///
     /// For [`Axis::Horizontal`] it will return the sum of the left and right border width.
     /// For [`Axis::Vertical`] it will return the sum of the top and bottom border height.
-    pub fn length(&self, _axis: Axis) -> Length {
-        self.width.saturating_add(self.width)
+    pub fn length(&self, axis: Axis) -> Length {
+        match axis {
+            Axis::Horizontal => self.left.saturating_add(self.right),
+            Axis::Vertical => self.top.saturating_add(self.bottom),
+        }
     }
 
     /// Expands the `size` by the border width.
@@ -50,9 +87,8 @@ impl BorderWidth {
     ///
     /// Helper function to be called in [`Widget::layout`].
     pub fn size_up(&self, size: Size) -> Size {
-        let border_width = self.width.get();
-        let width = size.width + border_width * 2.;
-        let height = size.height + border_width * 2.;
+        let width = size.width + self.left.get() + self.right.get();
+        let height = size.height + self.top.get() + self.bottom.get();
         Size::new(width, height)
     }
 
@@ -64,9 +100,8 @@ impl BorderWidth {
     ///
     /// Helper function to be called in [`Widget::layout`].
     pub fn size_down(&self, size: Size) -> Size {
-        let border_width = self.width.get();
-        let width = (size.width - border_width * 2.).max(0.);
-        let height = (size.height - border_width * 2.).max(0.);
+        let width = (size.width - self.left.get() - self.right.get()).max(0.);
+        let height = (size.height - self.top.get() - self.bottom.get()).max(0.);
         Size::new(width, height)
     }
 
@@ -76,12 +111,11 @@ impl BorderWidth {
     ///
     /// The provided `insets` must be in logical pixels.
     pub fn insets_up(&self, insets: Insets) -> Insets {
-        let border_width = self.width.get();
         Insets {
-            x0: insets.x0 + border_width,
-            y0: insets.y0 + border_width,
-            x1: insets.x1 + border_width,
-            y1: insets.y1 + border_width,
+            x0: insets.x0 + self.left.get(),
+            y0: insets.y0 + self.top.get(),
+            x1: insets.x1 + self.right.get(),
+            y1: insets.y1 + self.bottom.get(),
         }
     }
 
@@ -93,7 +127,7 @@ impl BorderWidth {
     ///
     /// Helper function to be called in [`Widget::layout`].
     pub fn baseline_up(&self, baseline: f64) -> f64 {
-        baseline + self.width.get()
+        baseline + self.bottom.get()
     }
 
     /// Lowers the `baseline` by the border width.
@@ -104,7 +138,7 @@ impl BorderWidth {
     ///
     /// Helper function to be called in [`Widget::layout`].
     pub fn baseline_down(&self, baseline: f64) -> f64 {
-        baseline - self.width.get()
+        baseline - self.bottom.get()
     }
 
     /// Lowers the position by the border width.
@@ -115,31 +149,39 @@ impl BorderWidth {
     ///
     /// Helper function to be called in [`Widget::layout`].
     pub fn origin_down(&self, origin: Point) -> Point {
-        let border_width = self.width.get();
-        origin + Vec2::new(border_width, border_width)
+        origin + Vec2::new(self.left.get(), self.top.get())
     }
 
     /// Creates a rounded rectangle that is inset by the border width.
     ///
     /// Use to display a box's background.
     ///
+    /// The corner radius is reduced by the widest edge.
+    ///
     /// Helper function to be called in [`Widget::paint`].
     pub fn bg_rect(&self, border_box: Rect, border_radius: &CornerRadius) -> RoundedRect {
-        let border_width = self.width.get();
         border_box
-            .inset(-border_width)
-            .to_rounded_rect(border_radius.radius.saturating_sub(self.width).get())
+            .inset(-self.insets_up(Insets::ZERO))
+            .to_rounded_rect(border_radius.radius.saturating_sub(self.max_width()).get())
     }
 
     /// Creates a rounded rectangle that is inset by half the border width.
     ///
-    /// Use to display a box's border.
+    /// Use to display a box's border with a uniform stroke.
+    /// For borders which aren't [uniform](Self::is_uniform), the widest edge is used.
     ///
     /// Helper function to be called in [`Widget::paint`].
     pub fn border_rect(&self, border_box: Rect, border_radius: &CornerRadius) -> RoundedRect {
-        let border_width = self.width.get();
+        let border_width = self.max_width().get();
         border_box
             .inset(-border_width / 2.0)
             .to_rounded_rect(border_radius.radius.get())
     }
 }
+
+impl From<Length> for BorderWidth {
+    /// Converts the value to a `BorderWidth` with that width on all edges.
+    fn from(value: Length) -> Self {
+        Self::all(value)
+    }
+}
@@ -2,23 +2,24 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::core::{Property, UsesProperty, Widget};
-use crate::imaging::{BlurredRoundedRect, Composite, Painter};
+use crate::imaging::{BlurredRoundedRect, Composite, GroupRef, Painter};
 use crate::kurbo::{Affine, BezPath, Insets, Point, RoundedRect, Shape as _};
 use crate::layout::Length;
 use crate::peniko::color::{AlphaColor, Srgb};
+use crate::peniko::{BlendMode, Compose, Mix};
 
 // TODO - This is a first implementation of box shadows. A full version would need
 // to address the following points:
-// - Inset shadows: CSS shadows can be either drop shadows (behind element) or inset
-// shadows (inside element). We should implement both and add an `inset` attribute.
-// - Spread radius: CSS shadow can change size without changing the blur level using
-// a "spread radius" value. We should implement it and add a `spread_radius` value.
 // - Corner radius: Right now take our widget's corner radii, and average them to draw a shadow with a single corner radius. Ideally we'd like to match individual values.
 
 // Every widget has a box shadow.
 impl<W: Widget> UsesProperty<BoxShadow> for W {}
+impl<W: Widget> UsesProperty<BoxShadows> for W {}
 
-/// The drop shadow of a widget.
+/// The drop shadow or inset shadow of a widget.
+///
+/// Drop shadows are painted behind the widget's background,
+/// while inset shadows are painted inside the widget, on top of its background.
 ///
 /// Will be invisible if default values are kept.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -33,6 +34,14 @@ pub struct BoxShadow {
     ///
     /// A value of zero means the shadow's edge will be sharp.
     pub blur_radius: Length,
+
+    /// How much the shadow is grown before blurring, in logical pixels.
+    ///
+    /// Negative values shrink it. The corner radius grows or shrinks by the same amount.
+    pub spread_radius: f64,
+
+    /// Whether the shadow is cast inside the widget instead of behind it.
+    pub inset: bool,
 }
 
 impl Property for BoxShadow {
@@ -41,6 +50,8 @@ impl Property for BoxShadow {
             color: AlphaColor::TRANSPARENT,
             offset: Point::ZERO,
             blur_radius: Length::ZERO,
+            spread_radius: 0.,
+            inset: false,
         };
         &DEFAULT
     }
@@ -59,6 +70,8 @@ impl BoxShadow {
             color,
             offset: offset.into(),
             blur_radius: Length::ZERO,
+            spread_radius: 0.,
+            inset: false,
         }
     }
 
@@ -70,6 +83,19 @@ impl BoxShadow {
         }
     }
 
+    /// Builder method to change the shadow's spread radius.
+    pub const fn spread(self, spread_radius: f64) -> Self {
+        Self {
+            spread_radius,
+            ..self
+        }
+    }
+
+    /// Builder method to make the shadow an inset shadow.
+    pub const fn inset(self, inset: bool) -> Self {
+        Self { inset, ..self }
+    }
+
     /// Returns `false` if the shadow can be safely treated as non-existent.
     ///
     /// May have false positives.
@@ -79,25 +105,40 @@ impl BoxShadow {
     }
 
     /// Helper function to paint the shadow through imaging's [`Painter`].
+    ///
+    /// Painting several shadows one after another composes them,
+    /// with later shadows painted on top of earlier ones.
     pub fn paint(&self, painter: &mut Painter<'_>, transform: Affine, rect: RoundedRect) {
         if !self.is_visible() {
             return;
         }
 
-        let transform = transform.pre_translate(self.offset.to_vec2());
-        let blur_radius = self.blur_radius.get();
-
         let radius = (rect.radii().bottom_left
             + rect.radii().bottom_right
             + rect.radii().top_left
             + rect.radii().top_right)
             / 4.;
 
+        if self.inset {
+            self.paint_inset(painter, transform, rect, radius);
+            return;
+        }
+
+        let transform = transform.pre_translate(self.offset.to_vec2());
+        let blur_radius = self.blur_radius.get();
+        let spread_radius = self.spread_radius;
+
+        let shadow_rect = rect.rect().inflate(spread_radius, spread_radius);
+        if shadow_rect.width() <= 0. || shadow_rect.height() <= 0. {
+            return;
+        }
+        let shadow_radius = (radius + spread_radius).max(0.);
+
         let std_dev = blur_radius;
 
         let kernel_size = 2.5 * std_dev;
         let carve_out_rect = rect - self.offset.to_vec2();
-        let big_rect = rect.rect().inflate(kernel_size, kernel_size);
+        let big_rect = shadow_rect.inflate(kernel_size, kernel_size);
         let clip_shape = BezPath::from_iter(
             big_rect
                 .path_elements(0.1)
@@ -107,25 +148,124 @@ impl BoxShadow {
         painter.with_fill_clip_transformed(clip_shape, transform, |painter| {
             painter.blurred_rounded_rect(BlurredRoundedRect {
                 transform,
-                rect: rect.rect(),
+                rect: shadow_rect,
                 color: self.color,
-                radius,
+                radius: shadow_radius,
                 std_dev: blur_radius,
                 composite: Composite::default(),
             });
         });
     }
 
+    /// Paints an inset shadow.
+    ///
+    /// The shadow is cast by the edges of the box onto its inside, so we fill the box with
+    /// the shadow color and then erase a blurred copy of the box, shifted by the offset.
+    fn paint_inset(
+        &self,
+        painter: &mut Painter<'_>,
+        transform: Affine,
+        rect: RoundedRect,
+        radius: f64,
+    ) {
+        let spread_radius = self.spread_radius;
+        let hole = rect.rect().inflate(-spread_radius, -spread_radius) + self.offset.to_vec2();
+        let hole_radius = (radius - spread_radius).max(0.);
+        let erase = Composite::new(BlendMode::new(Mix::Normal, Compose::DestOut), 1.);
+
+        painter.with_fill_clip_transformed(rect, transform, |painter| {
+            painter.push_group(GroupRef::new());
+            painter
+                .fill(rect.rect(), self.color)
+                .transform(transform)
+                .draw();
+            painter.blurred_rounded_rect(BlurredRoundedRect {
+                transform,
+                rect: hole,
+                color: AlphaColor::BLACK,
+                radius: hole_radius,
+                std_dev: self.blur_radius.get(),
+                composite: erase,
+            });
+            painter.pop_group();
+        });
+    }
+
     /// Helper function that returns how much a given shadow expands the paint rect.
     ///
     /// The returned [`Insets`] are guaranteed to be non-negative.
+    /// Inset shadows never expand the paint rect.
     pub fn get_insets(&self) -> Insets {
-        let blur_radius = self.blur_radius.get();
+        if self.inset {
+            return Insets::ZERO;
+        }
+        let extent = self.blur_radius.get() + self.spread_radius;
         Insets {
-            x0: (blur_radius - self.offset.x).max(0.),
-            y0: (blur_radius - self.offset.y).max(0.),
-            x1: (blur_radius + self.offset.x).max(0.),
-            y1: (blur_radius + self.offset.y).max(0.),
+            x0: (extent - self.offset.x).max(0.),
+            y0: (extent - self.offset.y).max(0.),
+            x1: (extent + self.offset.x).max(0.),
+            y1: (extent + self.offset.y).max(0.),
         }
     }
 }
+
+/// A list of shadows of a widget, painted in addition to its [`BoxShadow`].
+///
+/// `BoxShadow` stays the property for a widget's single shadow, this adds any number
+/// of further ones. Both are painted together by the same helpers, so a widget with
+/// both properties set gets every shadow.
+///
+/// Shadows are painted in order, after the widget's `BoxShadow`,
+/// so later shadows are drawn on top of earlier ones.
+/// Like with `BoxShadow`, drop shadows go behind the background and inset shadows on top of it.
+///
+/// This is useful for layered shadows, e.g. a sharp contact shadow and a soft ambient one.
+///
+/// Widgets with a custom pre-paint should paint these through
+/// [`paint_box_shadow`](crate::core::paint_box_shadow) and
+/// [`paint_inset_shadow`](crate::core::paint_inset_shadow), which handle both properties.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BoxShadows(pub Vec<BoxShadow>);
+
+impl Property for BoxShadows {
+    fn static_default() -> &'static Self {
+        static DEFAULT: BoxShadows = BoxShadows(Vec::new());
+        &DEFAULT
+    }
+}
+
+impl From<Vec<BoxShadow>> for BoxShadows {
+    fn from(shadows: Vec<BoxShadow>) -> Self {
+        Self(shadows)
+    }
+}
+
+impl BoxShadows {
+    /// Creates a new list from the given shadows.
+    pub fn new(shadows: impl IntoIterator<Item = BoxShadow>) -> Self {
+        Self(shadows.into_iter().collect())
+    }
+
+    /// Returns `false` if all shadows can be safely treated as non-existent.
+    ///
+    /// May have false positives.
+    pub fn is_visible(&self) -> bool {
+        self.0.iter().any(BoxShadow::is_visible)
+    }
+
+    /// Helper function that returns how much the visible shadows expand the paint rect.
+    ///
+    /// The returned [`Insets`] are guaranteed to be non-negative.
+    pub fn get_insets(&self) -> Insets {
+        self.0
+            .iter()
+            .filter(|shadow| shadow.is_visible())
+            .map(BoxShadow::get_insets)
+            .fold(Insets::ZERO, |acc, insets| Insets {
+                x0: acc.x0.max(insets.x0),
+                y0: acc.y0.max(insets.y0),
+                x1: acc.x1.max(insets.x1),
+                y1: acc.y1.max(insets.y1),
+            })
+    }
+}
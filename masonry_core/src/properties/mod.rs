@@ -9,6 +9,7 @@ mod border_width;
 mod box_shadow;
 mod corner_radius;
 mod dimensions;
+mod opacity;
 mod padding;
 
 pub mod types;
@@ -21,6 +22,7 @@ pub use border_width::*;
 pub use box_shadow::*;
 pub use corner_radius::*;
 pub use dimensions::*;
+pub use opacity::*;
 pub use padding::*;
 
 use crate::core::{Property, UpdateCtx};
@@ -31,6 +33,7 @@ pub(crate) fn core_property_changed(ctx: &mut UpdateCtx<'_>, property_type: Type
     //       However, it affects the size of the paint rect, which is handled in layout.
     if Dimensions::matches(property_type)
         || BoxShadow::matches(property_type)
+        || BoxShadows::matches(property_type)
         || BorderWidth::matches(property_type)
         || CornerRadius::matches(property_type)
         || Padding::matches(property_type)
@@ -38,5 +41,7 @@ pub(crate) fn core_property_changed(ctx: &mut UpdateCtx<'_>, property_type: Type
         ctx.request_layout();
     } else if Background::matches(property_type) || BorderColor::matches(property_type) {
         ctx.request_pre_paint();
+    } else if Opacity::matches(property_type) {
+        ctx.request_paint_only();
     }
 }
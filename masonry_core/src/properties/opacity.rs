@@ -0,0 +1,53 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::core::{Property, UsesProperty, Widget};
+
+// Every widget can be faded.
+impl<W: Widget> UsesProperty<Opacity> for W {}
+
+/// The opacity of a widget and all its descendants, from `0.0` to `1.0`.
+///
+/// This is applied by the paint pass, so widgets don't need to handle it themselves.
+/// Values outside the `0.0..=1.0` range are clamped.
+///
+/// This combines with the opacity set with [`UpdateCtx::set_opacity`],
+/// and the same limitations regarding visual layers apply.
+///
+/// [`UpdateCtx::set_opacity`]: crate::core::UpdateCtx::set_opacity
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Opacity(pub f64);
+
+impl Property for Opacity {
+    fn static_default() -> &'static Self {
+        static DEFAULT: Opacity = Opacity(1.);
+        &DEFAULT
+    }
+}
+
+impl Default for Opacity {
+    fn default() -> Self {
+        *Self::static_default()
+    }
+}
+
+impl Opacity {
+    /// Fully opaque.
+    pub const OPAQUE: Self = Self(1.);
+
+    /// Creates new `Opacity` with the given value, clamped to `0.0..=1.0`.
+    pub fn new(opacity: f64) -> Self {
+        Self(opacity.clamp(0., 1.))
+    }
+
+    /// Returns the opacity clamped to `0.0..=1.0`.
+    ///
+    /// A NaN value is treated as fully opaque.
+    pub fn alpha(&self) -> f32 {
+        if self.0.is_nan() {
+            1.
+        } else {
+            self.0.clamp(0., 1.) as f32
+        }
+    }
+}
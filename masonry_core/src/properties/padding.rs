@@ -9,6 +9,13 @@ use crate::layout::Length;
 impl<W: Widget> UsesProperty<Padding> for W {}
 
 /// The width of padding between a widget's border and its contents.
+///
+/// The layout pass derives a widget's content box from its border box by first
+/// insetting it by the [`BorderWidth`], and then by the padding.
+/// Both insets add up, so a container's children are placed inside the padding,
+/// which is itself inside the border.
+///
+/// [`BorderWidth`]: crate::properties::BorderWidth
 #[derive(Default, Clone, Copy, Debug, PartialEq)]
 pub struct Padding {
     /// The amount of padding for the left edge.
@@ -39,6 +46,16 @@ impl Padding {
     /// A padding of zero for all edges.
     pub const ZERO: Self = Self::all(Length::ZERO);
 
+    /// Creates a new `Padding` with the given amount of padding for each edge, in CSS order.
+    pub const fn new(top: Length, right: Length, bottom: Length, left: Length) -> Self {
+        Self {
+            top,
+            bottom,
+            left,
+            right,
+        }
+    }
+
     /// Creates a new `Padding` with equal amount of padding for all edges.
     pub const fn all(padding: Length) -> Self {
         Self {
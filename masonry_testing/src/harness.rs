@@ -22,7 +22,7 @@ use std::collections::VecDeque;
 use std::fs::File;
 use std::io::{BufReader, Cursor};
 use std::marker::PhantomData;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, mpsc};
 use std::time::UNIX_EPOCH;
 
@@ -36,22 +36,24 @@ use tracing::debug;
 use masonry_core::accesskit::{Action, ActionRequest, Node, Role, Tree, TreeId, TreeUpdate};
 use masonry_core::anymore::AnyDebug;
 use masonry_core::app::{
-    RenderRoot, RenderRootOptions, RenderRootSignal, VisualLayerKind, VisualLayerPlan,
+    RenderRoot, RenderRootOptions, RenderRootSignal, TreeOp, VisualLayerKind, VisualLayerPlan,
     WindowSizePolicy, try_init_test_tracing,
 };
 use masonry_core::core::keyboard::{Code, Key, KeyState, NamedKey};
+use masonry_core::core::pointer::PointerOrientation;
 use masonry_core::core::{
-    CursorIcon, DefaultProperties, ErasedAction, FromDynWidget, Handled, Ime, KeyboardEvent,
-    Modifiers, NewWidget, PointerButton, PointerButtonEvent, PointerEvent, PointerId, PointerInfo,
-    PointerScrollEvent, PointerState, PointerType, PointerUpdate, ScrollDelta, TextEvent, Widget,
-    WidgetId, WidgetMut, WidgetRef, WidgetTag, WindowEvent,
+    CollectionWidget, CursorIcon, DefaultProperties, ErasedAction, FromDynWidget, Handled, Ime,
+    InputEvent, KeyboardEvent, Modifiers, NewWidget, PointerButton, PointerButtonEvent,
+    PointerEvent, PointerId, PointerInfo, PointerScrollEvent, PointerState, PointerType,
+    PointerUpdate, ScrollDelta, TextEvent, Widget, WidgetId, WidgetMut, WidgetRef, WidgetTag,
+    WindowEvent,
 };
 use masonry_core::dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize};
 use masonry_core::kurbo::{Affine, Point, Rect, Vec2};
 use masonry_core::peniko::{Blob, Color};
 use masonry_core::util::Duration;
 
-use crate::screenshots::get_image_diff;
+use crate::screenshots::{get_image_diff, log_referenced_screenshot};
 use crate::{Record, Recorder};
 
 /// A [`PointerInfo`] for a primary mouse, for testing.
@@ -222,6 +224,11 @@ pub struct TestHarnessParams {
 ///
 /// You can also run tests with the `MASONRY_TEST_BLESS` flag set to `1` to assume all
 /// differences are intended and overwrite all the screenshots with new values.
+///
+/// To find screenshots which are no longer used by any test, run the tests with the
+/// `MASONRY_TEST_LOG_SCREENSHOTS` flag set to `1`, then call [`unreferenced_screenshots`].
+///
+/// [`unreferenced_screenshots`]: crate::unreferenced_screenshots
 #[macro_export]
 macro_rules! assert_render_snapshot {
     ($test_harness:expr, $name:expr) => {
@@ -229,6 +236,25 @@ macro_rules! assert_render_snapshot {
     };
 }
 
+/// Assert snapshots of a sequence of rendered frames of your app.
+///
+/// This renders `steps` frames, running an animation pass of `dt` (a [`Duration`])
+/// before each frame but the first, like [`TestHarness::render_sequence`].
+/// Frame `i` is compared against `<CRATE-ROOT>/screenshots/<NAME>/frame_<i>.png`,
+/// following the same rules as [`assert_render_snapshot`].
+///
+/// The assert fails on the first frame which doesn't match its screenshot.
+///
+/// [`Duration`]: std::time::Duration
+/// [`TestHarness::render_sequence`]: crate::TestHarness::render_sequence
+/// [`assert_render_snapshot`]: crate::assert_render_snapshot
+#[macro_export]
+macro_rules! assert_render_snapshot_sequence {
+    ($test_harness:expr, $name:expr, $steps:expr, $dt:expr) => {
+        $test_harness.check_render_snapshot_sequence(env!("CARGO_MANIFEST_DIR"), $name, $steps, $dt)
+    };
+}
+
 /// Assert a snapshot of a rendered frame of your app, expecting it to fail.
 ///
 /// This macro does essentially the same thing as [`assert_render_snapshot`], but
@@ -364,6 +390,20 @@ impl<W: Widget> TestHarness<W> {
         )
     }
 
+    /// Builds harness with the root widget returned by `build`, with reproducible widget ids.
+    ///
+    /// Widgets created by `build` and during the harness setup get ids which only depend
+    /// on the order in which they are created, so building the same tree in two harnesses
+    /// assigns the same ids to its widgets.
+    /// Widgets created later, e.g. in [`edit_root_widget`](Self::edit_root_widget),
+    /// get regular ids.
+    pub fn create_with_deterministic_ids(
+        default_props: DefaultProperties,
+        build: impl FnOnce() -> NewWidget<W>,
+    ) -> Self {
+        WidgetId::with_deterministic_ids(|| Self::create(default_props, build()))
+    }
+
     /// Builds harness with given root widget and additional parameters.
     pub fn create_with(
         default_props: DefaultProperties,
@@ -558,8 +598,10 @@ impl<W: Widget> TestHarness<W> {
             let mut painter = Painter::new(&mut full_scene);
             painter.fill_rect(Rect::new(0.0, 0.0, width, height), self.background_color);
 
+            // Layers are painted in logical pixels, so we scale them to the physical image.
             let padding_transform =
-                Affine::translate((f64::from(self.root_padding), f64::from(self.root_padding)));
+                Affine::translate((f64::from(self.root_padding), f64::from(self.root_padding)))
+                    * Affine::scale(self.render_root.scale_factor());
 
             for layer in &visual_layers.layers {
                 if let VisualLayerKind::Scene(scene) = &layer.kind {
@@ -579,6 +621,21 @@ impl<W: Widget> TestHarness<W> {
         RgbaImage::from_vec(image.width, image.height, image.data).expect("failed to create image")
     }
 
+    /// Renders `steps` frames, running an animation pass of `dt` before each frame but the first.
+    ///
+    /// This is useful to check the progress of animations.
+    /// See [`Self::render`] for details about each frame.
+    pub fn render_sequence(&mut self, steps: usize, dt: Duration) -> Vec<RgbaImage> {
+        let mut frames = Vec::with_capacity(steps);
+        for frame in 0..steps {
+            if frame > 0 {
+                self.animate(dt);
+            }
+            frames.push(self.render());
+        }
+        frames
+    }
+
     /// Redraws the window.
     ///
     /// If you want to get a bitmap image of the contents, use [`Self::render`] instead.
@@ -615,6 +672,41 @@ impl<W: Widget> TestHarness<W> {
 
     // --- MARK: EVENT HELPERS
 
+    /// Sets the keyboard modifiers held down by the simulated user.
+    ///
+    /// These modifiers stay held until this method is called again, and apply to
+    /// the pointer and key events sent by the harness's event helpers,
+    /// e.g. [`mouse_button_press`](Self::mouse_button_press) and [`keyboard_key`](Self::keyboard_key).
+    /// Text typed with [`keyboard_type_chars`](Self::keyboard_type_chars) is sent as IME commits,
+    /// which don't carry modifiers.
+    ///
+    /// This is useful to test interactions like shift-clicking, without having
+    /// to pass modifiers to every helper call.
+    pub fn set_modifiers(&mut self, modifiers: Modifiers) {
+        self.mouse_state.modifiers = modifiers;
+    }
+
+    /// Returns the keyboard modifiers set with [`set_modifiers`](Self::set_modifiers).
+    pub fn modifiers(&self) -> Modifiers {
+        self.mouse_state.modifiers
+    }
+
+    /// Sets the normalized pressure of the simulated pointer, from `0.0` to `1.0`.
+    ///
+    /// Like [`set_modifiers`](Self::set_modifiers), this persists across events,
+    /// and is useful to simulate a stylus.
+    pub fn set_pointer_pressure(&mut self, pressure: f32) {
+        self.mouse_state.pressure = pressure;
+    }
+
+    /// Sets the tilt of the simulated pointer, as altitude and azimuth angles.
+    ///
+    /// Like [`set_modifiers`](Self::set_modifiers), this persists across events,
+    /// and is useful to simulate a stylus.
+    pub fn set_pointer_orientation(&mut self, orientation: PointerOrientation) {
+        self.mouse_state.orientation = orientation;
+    }
+
     /// Move an internal mouse state, and send a [`Move`](PointerEvent::Move) event to the window.
     pub fn mouse_move(&mut self, pos: impl Into<Point>) {
         // FIXME - Account for scaling
@@ -632,6 +724,15 @@ impl<W: Widget> TestHarness<W> {
         }));
     }
 
+    /// Like [`mouse_move`](Self::mouse_move), but with an explicit event timestamp.
+    ///
+    /// `time` is measured from an arbitrary origin, and should increase from one call to the next.
+    /// It persists for later events, and is used by Masonry to compute pointer velocity.
+    pub fn mouse_move_at_time(&mut self, pos: impl Into<Point>, time: Duration) {
+        self.mouse_state.time = time.as_nanos().try_into().unwrap_or(u64::MAX);
+        self.mouse_move(pos);
+    }
+
     /// Sends a [`Down`](PointerEvent::Down) event to the window.
     pub fn mouse_button_press(&mut self, button: Option<PointerButton>) {
         if let Some(button) = button {
@@ -656,11 +757,21 @@ impl<W: Widget> TestHarness<W> {
         }));
     }
 
-    /// Sends a [`Scroll`](PointerEvent::Scroll) event to the window.
+    /// Sends a [`Scroll`](PointerEvent::Scroll) event to the window, with a delta in pixels.
+    ///
+    /// See [`mouse_scroll`](Self::mouse_scroll) for other units.
     pub fn mouse_wheel(&mut self, Vec2 { x, y }: Vec2) {
+        self.mouse_scroll(ScrollDelta::PixelDelta(PhysicalPosition { x, y }));
+    }
+
+    /// Sends a [`Scroll`](PointerEvent::Scroll) event to the window, at the current mouse position.
+    ///
+    /// Unlike [`mouse_wheel`](Self::mouse_wheel), this accepts deltas in lines or pages,
+    /// like those sent by most physical mouse wheels.
+    pub fn mouse_scroll(&mut self, delta: ScrollDelta) {
         self.process_pointer_event(PointerEvent::Scroll(PointerScrollEvent {
             pointer: PRIMARY_MOUSE,
-            delta: ScrollDelta::PixelDelta(PhysicalPosition { x, y }),
+            delta,
             state: self.mouse_state.clone(),
         }));
     }
@@ -780,6 +891,7 @@ impl<W: Widget> TestHarness<W> {
             target_node: id.to_raw().into(),
             data: None,
         });
+        self.process_signals();
     }
 
     // TODO - Handle complicated IME
@@ -794,13 +906,43 @@ impl<W: Widget> TestHarness<W> {
         self.process_signals();
     }
 
+    /// Sends a [`TextEvent`] for each character in the given string, to the focused widget.
+    ///
+    /// Unlike [`keyboard_type_chars`](Self::keyboard_type_chars), this checks that some widget
+    /// will receive the text, so that a test can't silently type into the void.
+    ///
+    /// To send individual keys, such as `Backspace` or `Ctrl+A`,
+    /// use [`keyboard_key`](Self::keyboard_key).
+    ///
+    /// # Panics
+    ///
+    /// If no widget is focused and there is no [focus fallback](Self::set_focus_fallback).
+    #[track_caller]
+    pub fn text_input(&mut self, text: &str) {
+        self.assert_has_focus_target(&format!("type {text:?}"));
+        self.keyboard_type_chars(text);
+    }
+
+    #[track_caller]
+    fn assert_has_focus_target(&self, action: &str) {
+        if self.render_root.focused_widget().is_none()
+            && self.render_root.focus_fallback().is_none()
+        {
+            panic!(
+                "Cannot {action}: no widget is focused and no focus fallback is set. \
+                Use TestHarness::focus_on to focus a widget first."
+            );
+        }
+    }
+
     /// Sends a [`TextEvent`] representing the user pressing the `Tab` key, either with or without the `Shift` key pressed.
+    ///
+    /// Modifiers set with [`set_modifiers`](Self::set_modifiers) are also applied.
     pub fn press_tab_key(&mut self, shift: bool) {
-        let modifiers = if shift {
-            Modifiers::SHIFT
-        } else {
-            Modifiers::empty()
-        };
+        let mut modifiers = self.modifiers();
+        if shift {
+            modifiers.insert(Modifiers::SHIFT);
+        }
         let event = TextEvent::Keyboard(KeyboardEvent {
             state: KeyState::Down,
             key: Key::Named(NamedKey::Tab),
@@ -812,6 +954,29 @@ impl<W: Widget> TestHarness<W> {
         self.process_signals();
     }
 
+    /// Sends a [`TextEvent`] representing the user pressing the given key, to the focused widget.
+    ///
+    /// The key is pressed with the given `modifiers`, which lets tests simulate keyboard
+    /// shortcuts like `Ctrl+C`.
+    /// Modifiers set with [`set_modifiers`](Self::set_modifiers) are also applied.
+    ///
+    /// # Panics
+    ///
+    /// If no widget is focused and there is no [focus fallback](Self::set_focus_fallback).
+    #[track_caller]
+    pub fn keyboard_key(&mut self, key: Key, modifiers: Modifiers) {
+        self.assert_has_focus_target(&format!("press {key:?}"));
+        let event = TextEvent::Keyboard(KeyboardEvent {
+            state: KeyState::Down,
+            key,
+            code: Code::Unidentified,
+            modifiers: self.modifiers() | modifiers,
+            ..KeyboardEvent::default()
+        });
+        self.render_root.handle_text_event(event);
+        self.process_signals();
+    }
+
     /// Sets the [focused widget](masonry_core::doc::masonry_concepts#text-focus)
     /// and the [focus anchor](masonry_core::doc::masonry_concepts#focus-anchor).
     ///
@@ -851,11 +1016,30 @@ impl<W: Widget> TestHarness<W> {
 
     /// Runs an animation pass on the widget tree.
     pub fn animate_ms(&mut self, ms: u64) {
+        self.animate(Duration::from_millis(ms));
+    }
+
+    /// Runs an animation pass on the widget tree, with `dt` as the elapsed time.
+    pub fn animate(&mut self, dt: Duration) {
         self.render_root
-            .handle_window_event(WindowEvent::AnimFrame(Duration::from_millis(ms)));
+            .handle_window_event(WindowEvent::AnimFrame(dt));
         self.process_signals();
     }
 
+    /// Changes the scale factor of the simulated window, as if it was moved to a screen with a different DPI.
+    ///
+    /// Like a platform would, this keeps the window's logical size, and scales its physical size
+    /// (and that of the images returned by [`render`](Self::render)) accordingly.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        let logical_size = self
+            .window_size
+            .to_logical::<f64>(self.render_root.scale_factor());
+        let physical_size = logical_size.to_physical(scale_factor);
+        self.process_window_event(WindowEvent::Rescale(scale_factor));
+        self.window_size = physical_size;
+        self.process_window_event(WindowEvent::Resize(physical_size));
+    }
+
     /// Helper method to directly enable/disable a widget.
     pub fn set_disabled(&mut self, widget: WidgetTag<impl Widget>, disabled: bool) {
         self.edit_widget(widget, |mut target| {
@@ -882,6 +1066,13 @@ impl<W: Widget> TestHarness<W> {
         self.render_root.set_default_properties(default_properties);
     }
 
+    /// Forces a complete relayout and repaint of the entire widget tree.
+    ///
+    /// Mirrors [`RenderRoot::invalidate_all`].
+    pub fn invalidate_all(&mut self) {
+        self.render_root.invalidate_all();
+    }
+
     /// Returns a [`WidgetRef`] to the widget with the given id.
     ///
     /// # Panics
@@ -929,6 +1120,57 @@ impl<W: Widget> TestHarness<W> {
         self.get_widget(tag).inner().recording().clear();
     }
 
+    /// Returns the border-box of the widget with the given id, in window coordinates.
+    ///
+    /// This is the widget's laid-out origin and size, after layout.
+    /// If the widget is transformed, this is the bounding box of its transformed border-box.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no widget with this id can be found.
+    #[track_caller]
+    pub fn widget_layout_rect(&self, id: WidgetId) -> Rect {
+        let widget = self
+            .render_root
+            .get_widget(id)
+            .unwrap_or_else(|| panic!("could not find widget {id} in the widget tree"));
+        let ctx = widget.ctx();
+        ctx.window_transform().transform_rect_bbox(ctx.border_box())
+    }
+
+    /// Returns how many times the `layout` method of the widget with the given id was called.
+    ///
+    /// This can be used to check that a change only re-runs layout on the widgets it affects.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no widget with this id can be found.
+    #[track_caller]
+    pub fn layout_count(&self, id: WidgetId) -> u32 {
+        self.render_root
+            .get_widget(id)
+            .unwrap_or_else(|| panic!("could not find widget {id} in the widget tree"))
+            .ctx()
+            .layout_count()
+    }
+
+    /// Returns how many times the `paint` method of the widget with the given id was called.
+    ///
+    /// Paint only runs when the harness redraws, e.g. in [`Self::render`] or [`Self::redraw`].
+    /// This can be used to check that a change only repaints the widgets it affects.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no widget with this id can be found.
+    #[track_caller]
+    pub fn paint_count(&self, id: WidgetId) -> u32 {
+        self.render_root
+            .get_widget(id)
+            .unwrap_or_else(|| panic!("could not find widget {id} in the widget tree"))
+            .ctx()
+            .paint_count()
+    }
+
     /// Tries to return a [`WidgetRef`] to the widget with the given id.
     pub fn try_get_widget(&self, id: WidgetId) -> Option<WidgetRef<'_, dyn Widget>> {
         self.render_root.get_widget(id)
@@ -1012,6 +1254,18 @@ impl<W: Widget> TestHarness<W> {
         ret
     }
 
+    /// Applies a batch of structural changes to the widget tree.
+    ///
+    /// See [`RenderRoot::apply_tree_ops`] for details.
+    #[track_caller]
+    pub fn apply_tree_ops<C: CollectionWidget<Params>, Params>(
+        &mut self,
+        ops: impl IntoIterator<Item = TreeOp<Params>>,
+    ) {
+        self.render_root.apply_tree_ops::<C, Params>(ops);
+        self.process_signals();
+    }
+
     /// Pops the oldest [`ErasedAction`] emitted by the widget tree, downcasting it to `T`.
     ///
     /// # Panics
@@ -1043,6 +1297,28 @@ impl<W: Widget> TestHarness<W> {
         self.render_root.cursor_icon()
     }
 
+    /// Returns whether any widget is waiting for an animation frame.
+    ///
+    /// When this is false, a real event loop would stop requesting frames until
+    /// a widget calls [`request_anim_frame`](masonry_core::core::UpdateCtx::request_anim_frame) again.
+    pub fn needs_anim(&self) -> bool {
+        self.render_root.needs_anim()
+    }
+
+    /// Returns the input events processed since the last [`redraw`](Self::redraw).
+    ///
+    /// See [`RenderRoot::pending_input_events`] for details.
+    pub fn pending_input_events(&self) -> &[InputEvent] {
+        self.render_root.pending_input_events()
+    }
+
+    /// Returns the number of input events processed between the last two redraws.
+    ///
+    /// See [`RenderRoot::handled_input_events_last_frame`] for details.
+    pub fn handled_input_events_last_frame(&self) -> usize {
+        self.render_root.handled_input_events_last_frame()
+    }
+
     /// Returns whether the app has an IME session in progress.
     ///
     /// This usually means that a widget which [accepts text input](Widget::accepts_text_input) is focused.
@@ -1112,12 +1388,80 @@ impl<W: Widget> TestHarness<W> {
         test_name: &str,
         expect_failure: bool,
     ) {
+        let screenshots_folder = PathBuf::from(manifest_dir).join("screenshots");
+        if std::env::var_os("MASONRY_TEST_LOG_SCREENSHOTS").is_some_and(|it| !it.is_empty()) {
+            log_referenced_screenshot(&screenshots_folder, test_name);
+        }
+
         if std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty()) {
             // We still redraw to get some coverage in the paint code.
             let _ = self.render_root.redraw();
 
             return;
         }
+
+        let bless_test = std::env::var_os("MASONRY_TEST_BLESS").is_some_and(|it| !it.is_empty());
+        self.check_render_snapshot_in(&screenshots_folder, test_name, expect_failure, bless_test);
+    }
+
+    /// Method used by [`assert_render_snapshot_sequence`]. Use this macro, not this method.
+    ///
+    /// Renders `steps` frames like [`Self::render_sequence`], and compares frame `i` against
+    /// the snapshot stored in `<CRATE ROOT>/screenshots/<test_name>/frame_<i>.png`.
+    ///
+    /// [`assert_render_snapshot_sequence`]: crate::assert_render_snapshot_sequence
+    #[doc(hidden)]
+    #[track_caller]
+    pub fn check_render_snapshot_sequence(
+        &mut self,
+        manifest_dir: &str,
+        test_name: &str,
+        steps: usize,
+        dt: Duration,
+    ) {
+        let root_folder = PathBuf::from(manifest_dir).join("screenshots");
+        let screenshots_folder = root_folder.join(test_name);
+        let log_screenshots =
+            std::env::var_os("MASONRY_TEST_LOG_SCREENSHOTS").is_some_and(|it| !it.is_empty());
+        let skip_render = std::env::var("SKIP_RENDER_TESTS").is_ok_and(|it| !it.is_empty());
+        let bless_test = std::env::var_os("MASONRY_TEST_BLESS").is_some_and(|it| !it.is_empty());
+
+        for frame in 0..steps {
+            if frame > 0 {
+                self.animate(dt);
+            }
+            let frame_name = format!("frame_{frame}");
+            if log_screenshots {
+                // Frames are logged in the top-level log, relative to the screenshots folder.
+                log_referenced_screenshot(&root_folder, &format!("{test_name}/{frame_name}"));
+            }
+            if skip_render {
+                // We still redraw to get some coverage in the paint code.
+                let _ = self.render_root.redraw();
+                continue;
+            }
+            self.check_render_snapshot_in(&screenshots_folder, &frame_name, false, bless_test);
+        }
+    }
+
+    /// Renders the current widget tree to a pixmap, and compares the pixmap against the
+    /// snapshot stored in `<screenshots_folder>/<test_name>.png`.
+    ///
+    /// This is what [`assert_render_snapshot`] uses under the hood, with `bless_test`
+    /// set from the `MASONRY_TEST_BLESS` environment variable.
+    /// If `bless_test` is true, a missing or different snapshot is overwritten
+    /// with the new rendering instead of failing.
+    ///
+    /// [`assert_render_snapshot`]: crate::assert_render_snapshot
+    #[doc(hidden)]
+    #[track_caller]
+    pub fn check_render_snapshot_in(
+        &mut self,
+        screenshots_folder: &Path,
+        test_name: &str,
+        expect_failure: bool,
+        bless_test: bool,
+    ) {
         let max_size = Some(usize::try_from(self.max_screenshot_size).unwrap());
 
         #[track_caller]
@@ -1146,15 +1490,12 @@ impl<W: Widget> TestHarness<W> {
 
         let new_image: DynamicImage = self.render().into();
 
-        let screenshots_folder = PathBuf::from(manifest_dir).join("screenshots");
-        std::fs::create_dir_all(&screenshots_folder).unwrap();
+        std::fs::create_dir_all(screenshots_folder).unwrap();
 
         let reference_path = screenshots_folder.join(format!("{test_name}.png"));
         let new_path = screenshots_folder.join(format!("{test_name}.new.png"));
         let diff_path = screenshots_folder.join(format!("{test_name}.diff.png"));
 
-        let bless_test = std::env::var_os("MASONRY_TEST_BLESS").is_some_and(|it| !it.is_empty());
-
         let Ok(reference_file) = File::open(&reference_path) else {
             if bless_test && !expect_failure {
                 let _ = std::fs::remove_file(&new_path);
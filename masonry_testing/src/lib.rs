@@ -35,6 +35,8 @@
 //!
 //! Masonry Testing will update the reference file when the `MASONRY_TEST_BLESS` environment variable has a value of `1`.
 //! This can be used if the file doesn't exist, or there's an expected difference.
+//! Running tests with the `MASONRY_TEST_LOG_SCREENSHOTS` environment variable set to `1` records which
+//! screenshots are used, so that stale ones can be found with [`unreferenced_screenshots`].
 //! The screenshots are losslessly compressed (using [Oxipng][oxipng]) and limited to a small maximum file size (this
 //! limit has an escape hatch).
 //! This ensures that the screenshots are small enough to embed in a git repository with limited risk
@@ -76,6 +78,7 @@ pub use debug_name::DebugName;
 pub use harness::{PRIMARY_MOUSE, ROBOTO, TestHarness, TestHarnessParams};
 pub use modular_widget::ModularWidget;
 pub use recorder_widget::{Record, Recorder, Recording};
+pub use screenshots::{REFERENCED_SCREENSHOTS_LOG, unreferenced_screenshots};
 pub use wrapper_widget::WrapperWidget;
 
 use masonry_core::core::Widget;
@@ -32,6 +32,8 @@ pub(crate) type ActionFn<S> =
 pub(crate) type RegisterChildrenFn<S> = dyn FnMut(&mut S, &mut RegisterCtx<'_>);
 pub(crate) type UpdateFn<S> =
     dyn FnMut(&mut S, &mut UpdateCtx<'_>, &mut PropertiesMut<'_>, &Update);
+pub(crate) type FocusChangeFn<S> =
+    dyn FnMut(&mut S, &mut UpdateCtx<'_>, &mut PropertiesMut<'_>, bool);
 pub(crate) type PropertyChangeFn<S> = dyn FnMut(&mut S, &mut UpdateCtx<'_>, TypeId);
 pub(crate) type MeasureFn<S> = dyn FnMut(
     &mut S,
@@ -67,6 +69,7 @@ pub struct ModularWidget<S> {
     on_action: Option<Box<ActionFn<S>>>,
     register_children: Option<Box<RegisterChildrenFn<S>>>,
     update: Option<Box<UpdateFn<S>>>,
+    focus_change: Option<Box<FocusChangeFn<S>>>,
     property_change: Option<Box<PropertyChangeFn<S>>>,
     measure: Option<Box<MeasureFn<S>>>,
     layout: Option<Box<LayoutFn<S>>>,
@@ -77,6 +80,7 @@ pub struct ModularWidget<S> {
     role: Option<Box<RoleFn<S>>>,
     access: Option<Box<AccessFn<S>>>,
     children: Option<Box<ChildrenFn<S>>>,
+    access_children: Option<Box<ChildrenFn<S>>>,
 }
 
 impl<S> ModularWidget<S> {
@@ -99,6 +103,7 @@ impl<S> ModularWidget<S> {
             on_action: None,
             register_children: None,
             update: None,
+            focus_change: None,
             property_change: None,
             measure: None,
             layout: None,
@@ -109,6 +114,7 @@ impl<S> ModularWidget<S> {
             role: None,
             access: None,
             children: None,
+            access_children: None,
         }
     }
 }
@@ -293,6 +299,15 @@ impl<S> ModularWidget<S> {
         self
     }
 
+    /// See [`Widget::on_focus_change`]
+    pub fn focus_change_fn(
+        mut self,
+        f: impl FnMut(&mut S, &mut UpdateCtx<'_>, &mut PropertiesMut<'_>, bool) + 'static,
+    ) -> Self {
+        self.focus_change = Some(Box::new(f));
+        self
+    }
+
     /// See [`Widget::property_changed`]
     pub fn property_change_fn(
         mut self,
@@ -381,6 +396,12 @@ impl<S> ModularWidget<S> {
         self.children = Some(Box::new(children));
         self
     }
+
+    /// See [`Widget::accessibility_children_ids`]
+    pub fn access_children_fn(mut self, children: impl Fn(&S) -> ChildrenIds + 'static) -> Self {
+        self.access_children = Some(Box::new(children));
+        self
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -455,6 +476,17 @@ impl<S: 'static> Widget for ModularWidget<S> {
         }
     }
 
+    fn on_focus_change(
+        &mut self,
+        ctx: &mut UpdateCtx<'_>,
+        props: &mut PropertiesMut<'_>,
+        focused: bool,
+    ) {
+        if let Some(f) = self.focus_change.as_mut() {
+            f(&mut self.state, ctx, props, focused);
+        }
+    }
+
     fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
         if let Some(f) = self.property_change.as_mut() {
             f(&mut self.state, ctx, property_type);
@@ -550,6 +582,18 @@ impl<S: 'static> Widget for ModularWidget<S> {
         }
     }
 
+    fn paint_order_ids(&self) -> ChildrenIds {
+        self.children_ids()
+    }
+
+    fn accessibility_children_ids(&self) -> ChildrenIds {
+        if let Some(f) = self.access_children.as_ref() {
+            f(&self.state)
+        } else {
+            self.children_ids()
+        }
+    }
+
     fn as_layer(&mut self) -> Option<&mut dyn Layer> {
         None
     }
@@ -82,6 +82,8 @@ pub enum Record {
     RegisterChildren,
     /// Update
     Update(Update),
+    /// Focus change.
+    FocusChange(bool),
     /// Property change.
     PropertyChange(TypeId),
     /// Measure. Records the length returned by the measure method.
@@ -224,6 +226,16 @@ impl<W: Widget> Widget for Recorder<W> {
         self.child.update(ctx, props, event);
     }
 
+    fn on_focus_change(
+        &mut self,
+        ctx: &mut UpdateCtx<'_>,
+        props: &mut PropertiesMut<'_>,
+        focused: bool,
+    ) {
+        self.recording.push(Record::FocusChange(focused));
+        self.child.on_focus_change(ctx, props, focused);
+    }
+
     fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
         self.recording.push(Record::PropertyChange(property_type));
         self.child.property_changed(ctx, property_type);
@@ -300,6 +312,14 @@ impl<W: Widget> Widget for Recorder<W> {
         self.child.children_ids()
     }
 
+    fn accessibility_children_ids(&self) -> ChildrenIds {
+        self.child.accessibility_children_ids()
+    }
+
+    fn paint_order_ids(&self) -> ChildrenIds {
+        self.child.paint_order_ids()
+    }
+
     fn as_layer(&mut self) -> Option<&mut dyn Layer> {
         None
     }
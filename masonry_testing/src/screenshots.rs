@@ -3,8 +3,19 @@
 
 //! Helper functions for writing snapshot tests and comparing images.
 
+use std::collections::HashSet;
+use std::fs::OpenOptions;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+
 use image::{GenericImageView as _, Pixel as _, Rgb, RgbImage};
 
+/// The file, in the screenshots folder, listing the snapshots used by tests.
+///
+/// It is written to when running tests with `MASONRY_TEST_LOG_SCREENSHOTS` set,
+/// and read by [`unreferenced_screenshots`].
+pub const REFERENCED_SCREENSHOTS_LOG: &str = ".referenced_screenshots";
+
 // Copy-pasted from kompari
 fn pixel_min_max_distance(left: Rgb<u8>, right: Rgb<u8>) -> (u8, u8) {
     left.channels()
@@ -65,3 +76,71 @@ pub(crate) fn get_image_diff(
 
     Some(diff_image)
 }
+
+/// Appends `test_name` to the [log of referenced screenshots](REFERENCED_SCREENSHOTS_LOG).
+pub(crate) fn log_referenced_screenshot(screenshots_folder: &Path, test_name: &str) {
+    std::fs::create_dir_all(screenshots_folder).unwrap();
+    let mut log = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(screenshots_folder.join(REFERENCED_SCREENSHOTS_LOG))
+        .unwrap();
+    // A single small write, so that concurrent tests don't interleave their lines.
+    log.write_all(format!("{test_name}\n").as_bytes()).unwrap();
+}
+
+/// Returns the reference screenshots in `screenshots_folder` which no test has used.
+///
+/// This reads the [log of referenced screenshots](REFERENCED_SCREENSHOTS_LOG),
+/// so the full test suite must first be run with the `MASONRY_TEST_LOG_SCREENSHOTS`
+/// environment variable set to `1`.
+/// Delete the log once you're done, so that later runs start from a clean slate.
+///
+/// Subfolders, such as the ones holding the frames of a snapshot sequence, are searched too.
+/// Their screenshots are logged by their path relative to `screenshots_folder`,
+/// e.g. `spinner_sequence/frame_0`.
+///
+/// Pending `.new.png` and `.diff.png` files are ignored.
+///
+/// # Errors
+///
+/// Returns an error if the folder or the log can't be read.
+pub fn unreferenced_screenshots(screenshots_folder: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let log = std::fs::read_to_string(screenshots_folder.join(REFERENCED_SCREENSHOTS_LOG))?;
+    let referenced: HashSet<&str> = log.lines().collect();
+
+    let mut unreferenced = Vec::new();
+    collect_unreferenced(screenshots_folder, "", &referenced, &mut unreferenced)?;
+    unreferenced.sort();
+    Ok(unreferenced)
+}
+
+fn collect_unreferenced(
+    folder: &Path,
+    prefix: &str,
+    referenced: &HashSet<&str>,
+    unreferenced: &mut Vec<PathBuf>,
+) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(folder)? {
+        let entry = entry?;
+        let path = entry.path();
+        let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if entry.file_type()?.is_dir() {
+            let prefix = format!("{prefix}{file_name}/");
+            collect_unreferenced(&path, &prefix, referenced, unreferenced)?;
+            continue;
+        }
+        let Some(test_name) = file_name.strip_suffix(".png") else {
+            continue;
+        };
+        if test_name.ends_with(".new") || test_name.ends_with(".diff") {
+            continue;
+        }
+        if !referenced.contains(format!("{prefix}{test_name}").as_str()) {
+            unreferenced.push(path);
+        }
+    }
+    Ok(())
+}
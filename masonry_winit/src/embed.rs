@@ -0,0 +1,219 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Rendering a [`RenderRoot`] into a caller-owned WGPU texture.
+//!
+//! This is meant for embedding Masonry into an existing WGPU application, such as
+//! a game or an editor, which already owns its device, queue and surfaces.
+//! The host drives the [`RenderRoot`] itself (forwarding events, resizing, etc.), and
+//! uses an [`EmbeddedRenderer`] to draw it into a texture with the host's device and queue.
+
+use masonry_core::accesskit::TreeUpdate;
+use masonry_core::app::{RenderRoot, VisualLayerKind, VisualLayerPlan};
+use masonry_core::peniko::Color;
+use masonry_imaging::texture_render::Renderer as ImagingRenderer;
+use masonry_imaging::{Layer as ImagingLayer, PreparedFrame};
+
+pub use masonry_imaging::texture_render::{Error, RenderTarget};
+
+/// Renders a [`RenderRoot`] into textures owned by the caller.
+///
+/// The underlying backend renderer is created lazily on first use, with the device and queue
+/// of the given [`RenderTarget`], and reused as long as later targets share them.
+#[derive(Debug, Default)]
+pub struct EmbeddedRenderer {
+    renderer: ImagingRenderer,
+}
+
+impl EmbeddedRenderer {
+    /// Stable backend name for diagnostics.
+    pub const BACKEND_NAME: &str = ImagingRenderer::BACKEND_NAME;
+
+    /// Creates a new `EmbeddedRenderer`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Repaints `render_root` and renders it into `target`.
+    ///
+    /// The target texture must be at least as large as [`RenderRoot::size`], and use a format
+    /// supported by the selected backend (e.g. `Rgba8Unorm` for Vello).
+    ///
+    /// Returns the accessibility tree update produced by the repaint, if any,
+    /// which the host should forward to its accessibility adapter.
+    pub fn render(
+        &mut self,
+        render_root: &mut RenderRoot,
+        target: RenderTarget<'_>,
+        background_color: Color,
+    ) -> Result<Option<TreeUpdate>, Error> {
+        let (visual_layers, tree_update) = render_root.redraw();
+        let size = render_root.size();
+        with_prepared_frame(
+            &visual_layers,
+            size.width,
+            size.height,
+            render_root.scale_factor(),
+            background_color,
+            |frame| self.renderer.render_to_texture(target, frame),
+        )?;
+        Ok(tree_update)
+    }
+}
+
+/// Flattens `visual_layers` into a [`PreparedFrame`] and passes it to `f`.
+pub(crate) fn with_prepared_frame<R>(
+    visual_layers: &VisualLayerPlan,
+    width: u32,
+    height: u32,
+    scale_factor: f64,
+    background_color: Color,
+    f: impl FnOnce(PreparedFrame<'_>) -> R,
+) -> R {
+    let overlays: Vec<_> = visual_layers
+        .overlay_layers()
+        .map(|layer| {
+            let VisualLayerKind::Scene(scene) = &layer.kind else {
+                unreachable!("overlay_layers only returns scene layers");
+            };
+            ImagingLayer {
+                scene,
+                transform: layer.transform,
+            }
+        })
+        .collect();
+    let root_layer = visual_layers
+        .root_layer()
+        .expect("paint should always produce a root layer");
+    let VisualLayerKind::Scene(root_scene) = &root_layer.kind else {
+        unreachable!("root_layer always returns a scene layer");
+    };
+    f(PreparedFrame::new(
+        width,
+        height,
+        scale_factor,
+        background_color,
+        root_scene,
+        &overlays,
+    ))
+}
+
+// --- MARK: TESTS
+#[cfg(all(test, feature = "imaging_vello"))]
+mod tests {
+    use masonry::app::{RenderRoot, RenderRootOptions, WindowSizePolicy};
+    use masonry::core::NewWidget;
+    use masonry::dpi::PhysicalSize;
+    use masonry::palette;
+    use masonry::properties::Background;
+    use masonry::theme::default_property_set;
+    use masonry::widgets::SizedBox;
+
+    use super::*;
+
+    const WIDTH: u32 = 64;
+    const HEIGHT: u32 = 32;
+
+    // Run with `cargo test -- --ignored` on a machine with a GPU.
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn render_into_caller_texture() {
+        let instance = wgpu::Instance::default();
+        let adapter =
+            pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+                .expect("no GPU adapter available");
+        let (device, queue) =
+            pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default())).unwrap();
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("embedding target"),
+            size: wgpu::Extent3d {
+                width: WIDTH,
+                height: HEIGHT,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::STORAGE_BINDING
+                | wgpu::TextureUsages::TEXTURE_BINDING
+                | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let root_widget =
+            NewWidget::new(SizedBox::empty()).with_props(Background::Color(palette::css::RED));
+        let mut render_root = RenderRoot::new(
+            root_widget,
+            |_| {},
+            RenderRootOptions {
+                default_properties: default_property_set().into(),
+                use_system_fonts: false,
+                size_policy: WindowSizePolicy::User,
+                size: PhysicalSize::new(WIDTH, HEIGHT),
+                scale_factor: 1.0,
+                test_font: None,
+            },
+        );
+
+        let mut renderer = EmbeddedRenderer::new();
+        renderer
+            .render(
+                &mut render_root,
+                RenderTarget {
+                    adapter: &adapter,
+                    device: &device,
+                    queue: &queue,
+                    texture: &texture,
+                    view: &view,
+                },
+                palette::css::BLUE,
+            )
+            .unwrap();
+
+        // Read the center pixel back, which should be covered by the red root widget.
+        let bytes_per_row = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("embedding readback"),
+            size: u64::from(bytes_per_row),
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        encoder.copy_texture_to_buffer(
+            wgpu::TexelCopyTextureInfo {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x: WIDTH / 2,
+                    y: HEIGHT / 2,
+                    z: 0,
+                },
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyBufferInfo {
+                buffer: &buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(bytes_per_row),
+                    rows_per_image: None,
+                },
+            },
+            wgpu::Extent3d {
+                width: 1,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+        );
+        queue.submit([encoder.finish()]);
+
+        let slice = buffer.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |result| result.unwrap());
+        device.poll(wgpu::PollType::wait_indefinitely()).unwrap();
+        let pixel = slice.get_mapped_range()[..4].to_vec();
+
+        assert_eq!(pixel, [255, 0, 0, 255]);
+    }
+}
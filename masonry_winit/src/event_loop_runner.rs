@@ -9,19 +9,17 @@ use std::sync::{Arc, mpsc};
 use accesskit_winit::Adapter;
 use copypasta::nop_clipboard::NopClipboardContext;
 use copypasta::{ClipboardContext, ClipboardProvider};
-use masonry_core::app::{
-    RenderRoot, RenderRootOptions, RenderRootSignal, VisualLayerKind, WindowSizePolicy,
-};
+use masonry_core::app::{RenderRoot, RenderRootOptions, RenderRootSignal, WindowSizePolicy};
 use masonry_core::core::keyboard::{Key, KeyState};
 use masonry_core::core::{
     DefaultProperties, ErasedAction, NewWidget, TextEvent, Widget, WindowEvent,
 };
 use masonry_core::peniko::Color;
 use masonry_core::util::Instant;
+use masonry_imaging::PreparedFrame;
 use masonry_imaging::texture_render::{
     RenderTarget as ImagingRenderTarget, Renderer as ImagingRenderer,
 };
-use masonry_imaging::{Layer as ImagingLayer, PreparedFrame};
 use tracing::{info, info_span, trace};
 use ui_events_winit::{WindowEventReducer, WindowEventTranslation};
 use winit::application::ApplicationHandler;
@@ -36,6 +34,7 @@ use crate::app::{
     winit_ime_to_masonry,
 };
 use crate::app_driver::WindowId;
+use crate::embed::with_prepared_frame;
 use crate::vello_util::{RenderContext, RenderSurface};
 
 /// The custom event type that we inject into winit's [`EventLoop`](winit::event_loop::EventLoop).
@@ -669,34 +668,15 @@ impl MasonryState<'_> {
         }
 
         let (visual_layers, tree_update) = window.render_root.redraw();
-        let overlays: Vec<_> = visual_layers
-            .overlay_layers()
-            .map(|layer| {
-                let VisualLayerKind::Scene(scene) = &layer.kind else {
-                    unreachable!("overlay_layers only returns scene layers");
-                };
-                ImagingLayer {
-                    scene,
-                    transform: layer.transform,
-                }
-            })
-            .collect();
         let size = window.render_root.size();
-        let root_layer = visual_layers
-            .root_layer()
-            .expect("paint should always produce a root layer");
-        let VisualLayerKind::Scene(root_scene) = &root_layer.kind else {
-            unreachable!("root_layer always returns a scene layer");
-        };
-        let frame = PreparedFrame::new(
+        with_prepared_frame(
+            &visual_layers,
             size.width,
             size.height,
             window.handle.scale_factor(),
             window.base_color,
-            root_scene,
-            &overlays,
+            |frame| Self::render(surface, window, frame, &self.render_cx, &mut self.renderer),
         );
-        Self::render(surface, window, frame, &self.render_cx, &mut self.renderer);
         #[cfg(feature = "tracy")]
         drop(self.frame.take());
         if let Some(tree_update) = tree_update {
@@ -90,6 +90,7 @@ use vello as _;
 
 mod app_driver;
 mod convert_winit_event;
+pub mod embed;
 mod event_loop_runner;
 mod vello_util;
 
@@ -8,7 +8,7 @@ use masonry::layout::{Dim, Length};
 use masonry::peniko::Color;
 pub use masonry::properties::types::{Gradient, GradientShape};
 pub use masonry::properties::{
-    Background, BorderColor, BorderWidth, BoxShadow, CornerRadius, Padding,
+    Background, BorderColor, BorderWidth, BoxShadow, BoxShadows, CornerRadius, Padding,
 };
 use masonry::properties::{ContentColor, Dimensions, Gap, LineBreaking};
 
@@ -95,7 +95,8 @@ pub trait Style<State: 'static, Action: 'static>: WidgetView<State, Action> + Si
     where
         Self::Widget: UsesProperty<BorderColor> + UsesProperty<BorderWidth>,
     {
-        self.prop(BorderColor { color }).prop(BorderWidth { width })
+        self.prop(BorderColor { color })
+            .prop(BorderWidth::all(width))
     }
 
     /// Sets the element's border color.
@@ -111,7 +112,7 @@ pub trait Style<State: 'static, Action: 'static>: WidgetView<State, Action> + Si
     where
         Self::Widget: UsesProperty<BorderWidth>,
     {
-        self.prop(BorderWidth { width })
+        self.prop(BorderWidth::all(width))
     }
 
     /// Sets the element's box shadow.
@@ -122,6 +123,17 @@ pub trait Style<State: 'static, Action: 'static>: WidgetView<State, Action> + Si
         self.prop(box_shadow)
     }
 
+    /// Sets additional box shadows for the element, painted after its [`BoxShadow`].
+    fn box_shadows(
+        self,
+        box_shadows: impl Into<BoxShadows>,
+    ) -> Prop<BoxShadows, Self, State, Action>
+    where
+        Self::Widget: UsesProperty<BoxShadows>,
+    {
+        self.prop(box_shadows.into())
+    }
+
     /// Sets the element's corner radius.
     fn corner_radius(self, radius: Length) -> Prop<CornerRadius, Self, State, Action>
     where
@@ -1,13 +1,17 @@
 // Copyright 2024 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use std::any::type_name;
 use std::marker::PhantomData;
 
-use masonry::widgets;
+use masonry::widgets::{self, PortalScrolled};
 
-use crate::core::{MessageCtx, MessageResult, Mut, View, ViewMarker};
+use crate::core::{MessageCtx, MessageResult, Mut, View, ViewId, ViewMarker, ViewPathTracker};
 use crate::{Pod, ViewCtx, WidgetView};
 
+type ScrollCallback<State, Action> =
+    Box<dyn Fn(&mut State, PortalScrolled) -> Action + Send + Sync>;
+
 /// A view which puts `child` into a scrollable region.
 ///
 /// This corresponds to the Masonry [`Portal`](masonry::widgets::Portal) widget.
@@ -21,6 +25,7 @@ where
         constrain_horizontal: false,
         constrain_vertical: false,
         must_fill: false,
+        on_scroll: None,
         phantom: PhantomData,
     }
 }
@@ -32,6 +37,7 @@ pub struct Portal<V, State, Action> {
     constrain_horizontal: bool,
     constrain_vertical: bool,
     must_fill: bool,
+    on_scroll: Option<ScrollCallback<State, Action>>,
     phantom: PhantomData<fn(State) -> Action>,
 }
 
@@ -69,8 +75,24 @@ impl<V, State, Action> Portal<V, State, Action> {
         self.must_fill = must_fill;
         self
     }
+
+    /// Sets a callback that will be run when the user scrolls the portal.
+    ///
+    /// The callback receives the new scroll position along with the viewport and content sizes,
+    /// which can be used e.g. to load more content when nearing the bottom.
+    pub fn on_scroll<F>(mut self, on_scroll: F) -> Self
+    where
+        F: Fn(&mut State, PortalScrolled) -> Action + Send + Sync + 'static,
+    {
+        self.on_scroll = Some(Box::new(on_scroll));
+        self
+    }
 }
 
+// Use a distinctive number here, to be able to catch bugs.
+/// This is a randomly generated 32 bit number - 1436069227 in decimal.
+const PORTAL_CONTENT_VIEW_ID: ViewId = ViewId::new(0x5598e26b);
+
 impl<V, State, Action> ViewMarker for Portal<V, State, Action> {}
 impl<Child, State, Action> View<State, Action, ViewCtx> for Portal<Child, State, Action>
 where
@@ -82,15 +104,18 @@ where
     type ViewState = Child::ViewState;
 
     fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
-        // The Portal `View` doesn't get any messages directly (yet - scroll events?), so doesn't need to
-        // use ctx.with_id.
-        let (child, child_state) = self.child.build(ctx, app_state);
-        let widget_pod = ctx.create_pod(
-            widgets::Portal::new(child.new_widget)
-                .constrain_horizontal(self.constrain_horizontal)
-                .constrain_vertical(self.constrain_vertical)
-                .content_must_fill(self.must_fill),
-        );
+        let (child, child_state) = ctx.with_id(PORTAL_CONTENT_VIEW_ID, |ctx| {
+            self.child.build(ctx, app_state)
+        });
+        let widget_pod = ctx.with_action_widget(|ctx| {
+            ctx.create_pod(
+                widgets::Portal::new(child.new_widget)
+                    .constrain_horizontal(self.constrain_horizontal)
+                    .constrain_vertical(self.constrain_vertical)
+                    .content_must_fill(self.must_fill)
+                    .scroll_actions(self.on_scroll.is_some()),
+            )
+        });
         (widget_pod, child_state)
     }
 
@@ -111,10 +136,15 @@ where
         if self.must_fill != prev.must_fill {
             widgets::Portal::set_content_must_fill(&mut element, self.must_fill);
         }
+        if self.on_scroll.is_some() != prev.on_scroll.is_some() {
+            widgets::Portal::set_scroll_actions(&mut element, self.on_scroll.is_some());
+        }
 
-        let child_element = widgets::Portal::child_mut(&mut element);
-        self.child
-            .rebuild(&prev.child, view_state, ctx, child_element, app_state);
+        ctx.with_id(PORTAL_CONTENT_VIEW_ID, |ctx| {
+            let child_element = widgets::Portal::child_mut(&mut element);
+            self.child
+                .rebuild(&prev.child, view_state, ctx, child_element, app_state);
+        });
     }
 
     fn teardown(
@@ -123,8 +153,11 @@ where
         ctx: &mut ViewCtx,
         mut element: Mut<'_, Self::Element>,
     ) {
-        let child_element = widgets::Portal::child_mut(&mut element);
-        self.child.teardown(view_state, ctx, child_element);
+        ctx.with_id(PORTAL_CONTENT_VIEW_ID, |ctx| {
+            let child_element = widgets::Portal::child_mut(&mut element);
+            self.child.teardown(view_state, ctx, child_element);
+        });
+        ctx.teardown_action_source(element);
     }
 
     fn message(
@@ -134,8 +167,110 @@ where
         mut element: Mut<'_, Self::Element>,
         app_state: &mut State,
     ) -> MessageResult<Action> {
-        let child_element = widgets::Portal::child_mut(&mut element);
-        self.child
-            .message(view_state, message, child_element, app_state)
+        match message.take_first() {
+            Some(PORTAL_CONTENT_VIEW_ID) => {
+                let child_element = widgets::Portal::child_mut(&mut element);
+                self.child
+                    .message(view_state, message, child_element, app_state)
+            }
+            None => match message.take_message::<PortalScrolled>() {
+                Some(scrolled) => match &self.on_scroll {
+                    Some(on_scroll) => MessageResult::Action(on_scroll(app_state, *scrolled)),
+                    None => MessageResult::Nop,
+                },
+                None => {
+                    tracing::error!(
+                        "Wrong message type in Portal::message: {message:?} expected {}",
+                        type_name::<PortalScrolled>()
+                    );
+                    MessageResult::Stale
+                }
+            },
+            _ => {
+                tracing::warn!(?message, "Got unexpected id path in `Portal::message`.");
+                MessageResult::Stale
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+    use std::sync::Arc;
+
+    use masonry::kurbo::Vec2;
+    use masonry::layout::AsUnit;
+    use masonry::testing::TestHarness;
+    use masonry::theme::default_property_set;
+
+    use super::*;
+    use crate::core::{DynMessage, Environment, ProxyError, RawProxy, SendMessage};
+    use crate::view::{Label, SizedBox, label, sized_box};
+
+    type Scrolls = Vec<PortalScrolled>;
+
+    #[derive(Debug)]
+    struct NoProxy;
+
+    impl RawProxy for NoProxy {
+        fn send_message(&self, _: Arc<[ViewId]>, _: SendMessage) -> Result<(), ProxyError> {
+            Ok(())
+        }
+
+        fn dyn_debug(&self) -> &dyn Debug {
+            self
+        }
+    }
+
+    fn tall_portal() -> Portal<SizedBox<Label, Scrolls, ()>, Scrolls, ()> {
+        portal(sized_box(label("")).fixed_height(1000.px()))
+    }
+
+    #[test]
+    fn on_scroll_enables_actions() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let mut ctx = ViewCtx::new(Arc::new(NoProxy), Arc::new(runtime));
+        let mut scrolls = Scrolls::new();
+
+        let view = tall_portal();
+        let (element, mut view_state) = view.build(&mut ctx, &mut scrolls);
+        let portal_id = element.new_widget.id();
+        let mut harness =
+            TestHarness::create_with_size(default_property_set(), element.new_widget, (200, 200));
+
+        // Without `on_scroll`, the widget doesn't emit actions.
+        harness.mouse_move((100., 100.));
+        harness.mouse_wheel(Vec2::new(0., -100.));
+        assert!(harness.root_widget().get_viewport_pos().y > 0.);
+        assert!(harness.pop_action::<PortalScrolled>().is_none());
+
+        let next = tall_portal().on_scroll(|scrolls: &mut Scrolls, scrolled| {
+            scrolls.push(scrolled);
+        });
+        harness.edit_root_widget(|portal| {
+            next.rebuild(&view, &mut view_state, &mut ctx, portal, &mut scrolls);
+        });
+        harness.mouse_wheel(Vec2::new(0., -100.));
+        let (scrolled, id) = harness.pop_action::<PortalScrolled>().unwrap();
+        assert_eq!(id, portal_id);
+
+        // The action is routed to the callback.
+        let path = ctx.get_id_path(portal_id).unwrap().clone();
+        let mut message = MessageCtx::new(Environment::new(), path, DynMessage::new(scrolled));
+        let result = harness.edit_root_widget(|portal| {
+            next.message(&mut view_state, &mut message, portal, &mut scrolls)
+        });
+        assert!(matches!(result, MessageResult::Action(())));
+        assert_eq!(scrolls, [scrolled]);
+
+        // Removing the callback disables the actions again.
+        harness.edit_root_widget(|portal| {
+            view.rebuild(&next, &mut view_state, &mut ctx, portal, &mut scrolls);
+        });
+        harness.mouse_wheel(Vec2::new(0., -100.));
+        assert!(harness.pop_action::<PortalScrolled>().is_none());
     }
 }
@@ -1,6 +1,9 @@
 // Copyright 2026 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use std::sync::Arc;
+
+use crate::core::anymore::AnyDebug;
 use crate::core::{MessageCtx, MessageResult, Mut, View, ViewMarker};
 use crate::{Pod, ViewCtx};
 
@@ -51,6 +54,7 @@ where
         callback,
         checked,
         disabled: false,
+        value: None,
     }
 }
 
@@ -63,6 +67,7 @@ pub struct RadioButton<F> {
     checked: bool,
     callback: F,
     disabled: bool,
+    value: Option<Arc<dyn AnyDebug + Send + Sync>>,
 }
 
 impl<F> RadioButton<F> {
@@ -71,6 +76,13 @@ impl<F> RadioButton<F> {
         self.disabled = disabled;
         self
     }
+
+    /// Set the value reported to the enclosing [`radio_group`](crate::view::radio_group)'s
+    /// [`on_change`](crate::view::RadioGroup::on_change) callback when this button is selected.
+    pub fn value(mut self, value: impl AnyDebug + Send + Sync) -> Self {
+        self.value = Some(Arc::new(value));
+        self
+    }
 }
 
 impl<F> ViewMarker for RadioButton<F> {}
@@ -85,8 +97,11 @@ where
 
     fn build(&self, ctx: &mut ViewCtx, _: &mut State) -> (Self::Element, Self::ViewState) {
         let element = ctx.with_action_widget(|ctx| {
-            let mut pod =
-                ctx.create_pod(widgets::RadioButton::new(self.checked, self.label.clone()));
+            let mut widget = widgets::RadioButton::new(self.checked, self.label.clone());
+            if let Some(value) = &self.value {
+                widget = widget.with_value(value.clone());
+            }
+            let mut pod = ctx.create_pod(widget);
             pod.new_widget.options.disabled = self.disabled;
             pod
         });
@@ -110,6 +125,14 @@ where
         if prev.checked != self.checked {
             widgets::RadioButton::set_checked(&mut element, self.checked);
         }
+        let same_value = match (&prev.value, &self.value) {
+            (Some(prev_value), Some(value)) => Arc::ptr_eq(prev_value, value),
+            (None, None) => true,
+            _ => false,
+        };
+        if !same_value {
+            widgets::RadioButton::set_value(&mut element, self.value.clone());
+        }
     }
 
     fn teardown(
@@ -1,10 +1,12 @@
 // Copyright 2026 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::core::{MessageCtx, MessageResult, Mut, View, ViewMarker};
+use std::any::type_name;
+
+use crate::core::{MessageCtx, MessageResult, Mut, View, ViewId, ViewMarker, ViewPathTracker};
 use crate::{Pod, ViewCtx, WidgetView};
 
-use masonry::widgets;
+use masonry::widgets::{self, RadioGroupChanged};
 
 /// An element which holds radio buttons.
 ///
@@ -37,25 +39,53 @@ use masonry::widgets;
 ///    }),
 /// )))
 /// ```
-pub fn radio_group<State, Action, V>(child: V) -> RadioGroup<V>
+///
+/// To also be told which button the group selected, e.g. when the user moves the
+/// selection with the arrow keys, set the [`on_change`](RadioGroup::on_change) callback.
+pub fn radio_group<State, Action, V>(child: V) -> RadioGroup<V, State, Action>
 where
     State: 'static,
     Action: 'static,
     V: WidgetView<State, Action>,
 {
-    RadioGroup { child }
+    RadioGroup {
+        child,
+        on_change: None,
+    }
 }
 
-/// The [`View`] created by [`radio_group`] from a bool value and a callback.
+type Callback<State, Action> =
+    Box<dyn Fn(&mut State, RadioGroupChanged) -> Action + Send + Sync + 'static>;
+
+/// The [`View`] created by [`radio_group`] from a child view.
 ///
 /// See `radio_group` documentation for more context.
 #[must_use = "View values do nothing unless provided to Xilem."]
-pub struct RadioGroup<V> {
+pub struct RadioGroup<V, State, Action> {
     child: V,
+    on_change: Option<Callback<State, Action>>,
 }
 
-impl<V> ViewMarker for RadioGroup<V> {}
-impl<State, Action, V> View<State, Action, ViewCtx> for RadioGroup<V>
+impl<V, State, Action> RadioGroup<V, State, Action> {
+    /// Set a callback that will be run when the user selects a different radio button.
+    ///
+    /// The [`RadioGroupChanged`] holds the value given to the selected button
+    /// with [`radio_button(...).value(...)`](crate::view::RadioButton::value), if any.
+    pub fn on_change<F>(mut self, on_change: F) -> Self
+    where
+        F: Fn(&mut State, RadioGroupChanged) -> Action + Send + Sync + 'static,
+    {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+}
+
+// Use a distinctive number here, to be able to catch bugs.
+/// This is a randomly generated 32 bit number - 2764716347 in decimal.
+const RADIO_GROUP_CONTENT_VIEW_ID: ViewId = ViewId::new(0xa4ca8b3b);
+
+impl<V, State, Action> ViewMarker for RadioGroup<V, State, Action> {}
+impl<State, Action, V> View<State, Action, ViewCtx> for RadioGroup<V, State, Action>
 where
     State: 'static,
     Action: 'static,
@@ -65,10 +95,15 @@ where
     type ViewState = V::ViewState;
 
     fn build(&self, ctx: &mut ViewCtx, app_state: &mut State) -> (Self::Element, Self::ViewState) {
-        let (child, child_state) = self.child.build(ctx, app_state);
+        let (child, child_state) = ctx.with_id(RADIO_GROUP_CONTENT_VIEW_ID, |ctx| {
+            self.child.build(ctx, app_state)
+        });
         let widget = widgets::RadioGroup::new(child.new_widget);
 
-        (ctx.create_pod(widget), child_state)
+        (
+            ctx.with_action_widget(|ctx| ctx.create_pod(widget)),
+            child_state,
+        )
     }
 
     fn rebuild(
@@ -79,9 +114,11 @@ where
         mut element: Mut<'_, Self::Element>,
         app_state: &mut State,
     ) {
-        let mut child = widgets::RadioGroup::child_mut(&mut element);
-        self.child
-            .rebuild(&prev.child, view_state, ctx, child.downcast(), app_state);
+        ctx.with_id(RADIO_GROUP_CONTENT_VIEW_ID, |ctx| {
+            let mut child = widgets::RadioGroup::child_mut(&mut element);
+            self.child
+                .rebuild(&prev.child, view_state, ctx, child.downcast(), app_state);
+        });
     }
 
     fn teardown(
@@ -90,8 +127,11 @@ where
         ctx: &mut ViewCtx,
         mut element: Mut<'_, Self::Element>,
     ) {
-        let mut child = widgets::RadioGroup::child_mut(&mut element);
-        self.child.teardown(view_state, ctx, child.downcast());
+        ctx.with_id(RADIO_GROUP_CONTENT_VIEW_ID, |ctx| {
+            let mut child = widgets::RadioGroup::child_mut(&mut element);
+            self.child.teardown(view_state, ctx, child.downcast());
+        });
+        ctx.teardown_action_source(element);
     }
 
     fn message(
@@ -101,8 +141,29 @@ where
         mut element: Mut<'_, Self::Element>,
         app_state: &mut State,
     ) -> MessageResult<Action> {
-        let mut child = widgets::RadioGroup::child_mut(&mut element);
-        self.child
-            .message(view_state, message, child.downcast(), app_state)
+        match message.take_first() {
+            Some(RADIO_GROUP_CONTENT_VIEW_ID) => {
+                let mut child = widgets::RadioGroup::child_mut(&mut element);
+                self.child
+                    .message(view_state, message, child.downcast(), app_state)
+            }
+            None => match message.take_message::<RadioGroupChanged>() {
+                Some(changed) => match &self.on_change {
+                    Some(on_change) => MessageResult::Action(on_change(app_state, *changed)),
+                    None => MessageResult::Nop,
+                },
+                None => {
+                    tracing::error!(
+                        "Wrong message type in RadioGroup::message: {message:?} expected {}",
+                        type_name::<RadioGroupChanged>()
+                    );
+                    MessageResult::Stale
+                }
+            },
+            _ => {
+                tracing::warn!(?message, "Got unexpected id path in `RadioGroup::message`.");
+                MessageResult::Stale
+            }
+        }
     }
 }